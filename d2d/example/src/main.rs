@@ -3,17 +3,15 @@
 use ::d2d::{brushes::SolidColorBrush, Color, D2DFactory, RenderTarget};
 use ::std::rc::Rc;
 use ::win32::{
+    event_loop::EventLoop,
     proc::ComLibraryHandle,
     types::ResourceId,
     {
         errors::Result,
-        window::{Theme, Window},
+        window::{Event, Theme, Window},
     },
 };
 use ::win_geom::d2::{Point2D, Rect2D, Size2D};
-use ::windows::Win32::UI::WindowsAndMessaging::{
-    DispatchMessageW, GetMessageW, PostQuitMessage, TranslateMessage, MSG,
-};
 
 pub fn main() {
     // Ensure COM library is loaded
@@ -21,14 +19,11 @@ pub fn main() {
 
     // Use dimensions which are divisible by 8 to work well on 100%, 125%
     // and 150% DPI.
-    let size = Size2D {
-        width: 720,
-        height: 640,
-    };
+    let size = Size2D::new(720, 640);
 
     // Start our example program and pump the message loop
-    let mut example = ExampleApp::new(size);
-    example.run_message_loop().unwrap();
+    let example = ExampleApp::new(size);
+    example.run().unwrap();
 }
 
 /// A simple structure which holds our Direct2D device dependent resources.
@@ -99,69 +94,62 @@ impl ExampleApp {
     /// blue lines * A large outline of a rectangle in the center * A smaller
     /// filled rounded rectangle within the larger rect * A small circle within
     /// the rounded rectangle
-    fn draw(&mut self) {
+    fn draw(render_target: &mut RenderTarget, resources: &mut Resources) {
         // Drawing must always begin with a `begin_draw` call. All drawing is
         // done via the returned `Context`, and our render target is held locked
         // until the corresponding `end_draw` call.
-        let mut ctx = self.render_target.begin_draw();
+        let mut ctx = render_target.begin_draw();
         // Erase the last contents by paining the client area white.
-        ctx.clear(self.resources.background_color);
+        ctx.clear(resources.background_color);
 
-        // Cache our main window dimensions both as i32 and f32 values.
-        let dimensions = self.main_window.size().cast::<f32>();
+        // Cache the render target's current dimensions both as i32 and f32
+        // values, so drawing stays laid out correctly after a resize.
+        let size = ctx.target_size();
+        let dimensions = size.cast::<f32>();
 
         // Draw grid with 8px squares
         let stroke_width = 0.5;
-        for x in (0..self.main_window.size().width)
-            .step_by(8)
-            .map(|u| u as f32)
-        {
+        for x in (0..size.width).step_by(8).map(|u| u as f32) {
             ctx.draw_line(
-                Point2D { x, y: 0.0 },
-                Point2D {
-                    x,
-                    y: dimensions.height,
-                },
+                Point2D::new(x, 0.0),
+                Point2D::new(x, dimensions.height),
                 stroke_width,
-                &mut self.resources.grid_brush,
+                &mut resources.grid_brush,
+                None,
             );
         }
-        for y in (0..self.main_window.size().height)
-            .step_by(8)
-            .map(|u| u as f32)
-        {
+        for y in (0..size.height).step_by(8).map(|u| u as f32) {
             ctx.draw_line(
-                Point2D { x: 0.0, y },
-                Point2D {
-                    x: dimensions.width,
-                    y,
-                },
+                Point2D::new(0.0, y),
+                Point2D::new(dimensions.width, y),
                 stroke_width,
-                &mut self.resources.grid_brush,
+                &mut resources.grid_brush,
+                None,
             );
         }
 
         // Draw two rectangles, one inner filled rectangle and one stroked
         // larger rectangle.
         ctx.fill_rect(
-            Rect2D {
-                left: (dimensions.width / 2.0 - 56.0),
-                right: (dimensions.width / 2.0 + 56.0),
-                top: (dimensions.height / 2.0 - 56.0),
-                bottom: (dimensions.height / 2.0 + 56.0),
-            },
-            &mut self.resources.rect_fill_brush,
+            Rect2D::new(
+                dimensions.width / 2.0 - 56.0,
+                dimensions.height / 2.0 - 56.0,
+                dimensions.width / 2.0 + 56.0,
+                dimensions.height / 2.0 + 56.0,
+            ),
+            &mut resources.rect_fill_brush,
         );
         let stroke_width = 1.0;
         ctx.stroke_rect(
-            Rect2D {
-                left: (dimensions.width / 2.0 - 104.0),
-                right: (dimensions.width / 2.0 + 104.0),
-                top: (dimensions.height / 2.0 - 104.0),
-                bottom: (dimensions.height / 2.0 + 104.0),
-            },
-            &mut self.resources.rect_stroke_brush,
+            Rect2D::new(
+                dimensions.width / 2.0 - 104.0,
+                dimensions.height / 2.0 - 104.0,
+                dimensions.width / 2.0 + 104.0,
+                dimensions.height / 2.0 + 104.0,
+            ),
+            &mut resources.rect_stroke_brush,
             stroke_width,
+            None,
         );
 
         // Drawing must end with `end_draw`. This causes the batched changes to
@@ -171,26 +159,22 @@ impl ExampleApp {
         ctx.end_draw();
     }
 
-    /// Pump our Win32 message loop. The inner `main_window` will handle most
-    /// aspects, we just need to test for any pending close or redraw flags and
-    /// action them accordingly.
-    pub fn run_message_loop(&mut self) -> Result<()> {
-        let mut msg = MSG::default();
-        while unsafe { GetMessageW(&mut msg, None, 0, 0) }.as_bool() {
-            unsafe { TranslateMessage(&msg) };
-            unsafe { DispatchMessageW(&msg) };
-
-            if self.main_window.clear_redraw_request() {
-                self.draw();
-            }
-
-            if self.main_window.clear_close_request() {
-                unsafe {
-                    PostQuitMessage(0);
-                }
-            }
-        }
-
-        Ok(())
+    /// Runs the example via a typed [`EventLoop`], reacting to [`Event`]s
+    /// instead of polling `main_window`'s `clear_*_request` methods.
+    pub fn run(self) -> Result<()> {
+        let Self {
+            main_window,
+            _factory,
+            mut render_target,
+            mut resources,
+        } = self;
+
+        let event_loop = EventLoop::new(main_window);
+
+        event_loop.run(|event| match event {
+            Event::Resized(size) => render_target.resize(size),
+            Event::RedrawRequested => Self::draw(&mut render_target, &mut resources),
+            _ => (),
+        })
     }
 }
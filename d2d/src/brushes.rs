@@ -2,8 +2,12 @@
 
 use crate::{color::Color, RenderTarget};
 use ::std::fmt::{self, Debug};
-use ::windows::Win32::Graphics::Direct2D::ID2D1SolidColorBrush;
-use windows::Win32::Graphics::Direct2D::ID2D1Brush;
+use ::win_geom::d2::Point2D;
+use ::windows::Win32::Graphics::Direct2D::{
+    ID2D1LinearGradientBrush, ID2D1RadialGradientBrush, ID2D1SolidColorBrush,
+    D2D1_EXTEND_MODE_CLAMP, D2D1_EXTEND_MODE_MIRROR, D2D1_EXTEND_MODE_WRAP,
+};
+use windows::Win32::Graphics::Direct2D::{D2D1_EXTEND_MODE, ID2D1Brush};
 
 /// A trait shared in common with all device-specific resources. A
 /// device-specific resource is a Direct2D resource which must be re-created if
@@ -84,3 +88,263 @@ impl Debug for SolidColorBrush {
         // TODO: needs-recreation?
     }
 }
+
+/// A single color stop within a gradient brush, at a normalized `position`
+/// along the gradient's axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientStop {
+    /// Normalized position along the gradient axis, in `[0.0, 1.0]`.
+    pub position: f32,
+    /// The color at this stop.
+    pub color: Color,
+}
+
+/// How a gradient brush paints the area beyond its first and last
+/// [`GradientStop`]s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ExtendMode {
+    /// The color at the nearest edge stop is extended indefinitely.
+    #[default]
+    Clamp,
+    /// The gradient repeats from the start once it reaches the end.
+    Wrap,
+    /// The gradient repeats in alternating, flipped copies once it reaches
+    /// the end.
+    Mirror,
+}
+
+impl From<ExtendMode> for D2D1_EXTEND_MODE {
+    fn from(val: ExtendMode) -> Self {
+        match val {
+            ExtendMode::Clamp => D2D1_EXTEND_MODE_CLAMP,
+            ExtendMode::Wrap => D2D1_EXTEND_MODE_WRAP,
+            ExtendMode::Mirror => D2D1_EXTEND_MODE_MIRROR,
+        }
+    }
+}
+
+/// A brush which paints a linear gradient between `start` and `end`.
+///
+/// This is a device-specific resource and is tied to the [`RenderTarget`] by
+/// which it was created. Cache the brush and re-use on subsequent draw calls
+/// for best performance.
+pub struct LinearGradientBrush {
+    /// A copy of the gradient stops from which the brush was created. Used
+    /// to re-create the brush if our device specific resources are lost.
+    stops: Vec<GradientStop>,
+    /// The start of the gradient's axis.
+    start: Point2D<f32>,
+    /// The end of the gradient's axis.
+    end: Point2D<f32>,
+    /// How the area beyond the first/last stop is painted.
+    extend_mode: ExtendMode,
+    /// A cached Direct2D device-specific linear gradient brush. May become
+    /// invalidated if the corresponding render target is re-created.
+    device_brush: ID2D1LinearGradientBrush,
+    /// The generation of the render target for which this brush was created. If
+    /// the two generations no longer agree, the brush mush be re-created.
+    generation: usize,
+}
+
+impl LinearGradientBrush {
+    /// A crate-private constructor. Only a [`RenderTarget`] should be able to
+    /// create brushes.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        stops: Vec<GradientStop>,
+        start: Point2D<f32>,
+        end: Point2D<f32>,
+        extend_mode: ExtendMode,
+        device_brush: ID2D1LinearGradientBrush,
+        generation: usize,
+    ) -> Self {
+        Self {
+            stops,
+            start,
+            end,
+            extend_mode,
+            device_brush,
+            generation,
+        }
+    }
+
+    /// The gradient stops the brush was created from.
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// The start of the gradient's axis.
+    pub fn start(&self) -> Point2D<f32> {
+        self.start
+    }
+
+    /// The end of the gradient's axis.
+    pub fn end(&self) -> Point2D<f32> {
+        self.end
+    }
+
+    /// How the area beyond the first/last stop is painted.
+    pub fn extend_mode(&self) -> ExtendMode {
+        self.extend_mode
+    }
+}
+
+impl Brush for LinearGradientBrush {
+    fn device_brush(&self) -> &'_ ID2D1Brush {
+        (&self.device_brush).into()
+    }
+}
+
+impl DeviceResource for LinearGradientBrush {
+    fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn recreate_if_needed(&mut self, render_target: &mut RenderTarget) {
+        if self.generation() != render_target.generation() {
+            *self = render_target.make_linear_gradient_brush(
+                self.stops.clone(),
+                self.start,
+                self.end,
+                self.extend_mode,
+            );
+        }
+    }
+}
+
+impl Debug for LinearGradientBrush {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LinearGradientBrush")
+            .field("stops", &self.stops)
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("extend_mode", &self.extend_mode)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+/// A brush which paints a radial gradient, centered at `center` (offset by
+/// `gradient_origin_offset`) and extending out to the ellipse described by
+/// `radius_x`/`radius_y`.
+///
+/// This is a device-specific resource and is tied to the [`RenderTarget`] by
+/// which it was created. Cache the brush and re-use on subsequent draw calls
+/// for best performance.
+pub struct RadialGradientBrush {
+    /// A copy of the gradient stops from which the brush was created. Used
+    /// to re-create the brush if our device specific resources are lost.
+    stops: Vec<GradientStop>,
+    /// The center of the gradient ellipse.
+    center: Point2D<f32>,
+    /// The offset of the gradient's origin from `center`.
+    gradient_origin_offset: Point2D<f32>,
+    /// The x-radius of the gradient ellipse.
+    radius_x: f32,
+    /// The y-radius of the gradient ellipse.
+    radius_y: f32,
+    /// How the area beyond the first/last stop is painted.
+    extend_mode: ExtendMode,
+    /// A cached Direct2D device-specific radial gradient brush. May become
+    /// invalidated if the corresponding render target is re-created.
+    device_brush: ID2D1RadialGradientBrush,
+    /// The generation of the render target for which this brush was created. If
+    /// the two generations no longer agree, the brush mush be re-created.
+    generation: usize,
+}
+
+impl RadialGradientBrush {
+    /// A crate-private constructor. Only a [`RenderTarget`] should be able to
+    /// create brushes.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        stops: Vec<GradientStop>,
+        center: Point2D<f32>,
+        gradient_origin_offset: Point2D<f32>,
+        radius_x: f32,
+        radius_y: f32,
+        extend_mode: ExtendMode,
+        device_brush: ID2D1RadialGradientBrush,
+        generation: usize,
+    ) -> Self {
+        Self {
+            stops,
+            center,
+            gradient_origin_offset,
+            radius_x,
+            radius_y,
+            extend_mode,
+            device_brush,
+            generation,
+        }
+    }
+
+    /// The gradient stops the brush was created from.
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// The center of the gradient ellipse.
+    pub fn center(&self) -> Point2D<f32> {
+        self.center
+    }
+
+    /// The offset of the gradient's origin from [`Self::center`].
+    pub fn gradient_origin_offset(&self) -> Point2D<f32> {
+        self.gradient_origin_offset
+    }
+
+    /// The x-radius of the gradient ellipse.
+    pub fn radius_x(&self) -> f32 {
+        self.radius_x
+    }
+
+    /// The y-radius of the gradient ellipse.
+    pub fn radius_y(&self) -> f32 {
+        self.radius_y
+    }
+
+    /// How the area beyond the first/last stop is painted.
+    pub fn extend_mode(&self) -> ExtendMode {
+        self.extend_mode
+    }
+}
+
+impl Brush for RadialGradientBrush {
+    fn device_brush(&self) -> &'_ ID2D1Brush {
+        (&self.device_brush).into()
+    }
+}
+
+impl DeviceResource for RadialGradientBrush {
+    fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn recreate_if_needed(&mut self, render_target: &mut RenderTarget) {
+        if self.generation() != render_target.generation() {
+            *self = render_target.make_radial_gradient_brush(
+                self.stops.clone(),
+                self.center,
+                self.gradient_origin_offset,
+                self.radius_x,
+                self.radius_y,
+                self.extend_mode,
+            );
+        }
+    }
+}
+
+impl Debug for RadialGradientBrush {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RadialGradientBrush")
+            .field("stops", &self.stops)
+            .field("center", &self.center)
+            .field("gradient_origin_offset", &self.gradient_origin_offset)
+            .field("radius_x", &self.radius_x)
+            .field("radius_y", &self.radius_y)
+            .field("extend_mode", &self.extend_mode)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
@@ -1,3 +1,4 @@
+use ::std::str::FromStr;
 use ::windows::Win32::Graphics::Direct2D::Common::D2D1_COLOR_F;
 
 /// Color representation in RGBA format.
@@ -44,15 +45,67 @@ pub struct Color {
 }
 
 impl From<Color> for D2D1_COLOR_F {
+    /// `Color` stores linear light, but every render target in this crate
+    /// uses the non-gamma-aware `DXGI_FORMAT_B8G8R8A8_UNORM`, so Direct2D
+    /// expects sRGB-encoded channels. Re-encode via [`Color::to_srgb`] before
+    /// handing the bytes off, or colors built from the byte/hex constructors
+    /// would render too dark.
     fn from(c: Color) -> Self {
+        let srgb = c.to_srgb();
+
         // SAFETY: `D2D1_COLOR_F` and `Color` share the same memory
         // representation.
-        unsafe { ::std::mem::transmute::<_, _>(c) }
+        unsafe { ::std::mem::transmute::<_, _>(srgb) }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Parses a color from a CSS-style hex string (`#RRGGBB`, `#RRGGBBAA`,
+    /// `#RGB`) or a bare Microsoft UI color name. Equivalent to
+    /// [`Color::from_str_rgba`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_rgba(s)
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ColorParseError;
+
+    /// Parses a color from a hex/CSS string or predefined color name.
+    /// Equivalent to [`Color::from_str_rgba`] and [`Color`]'s
+    /// [`FromStr`](::std::str::FromStr) impl; provided so colors can be
+    /// parsed with `TryFrom`/`TryInto` as well as `str::parse`.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
     }
 }
 
+/// Returned when a string fails to parse as a [`Color`] via
+/// [`Color::from_str_rgba`], [`Color::from_str_argb`], or [`Color`]'s
+/// [`FromStr`](::std::str::FromStr) impl.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ::thiserror::Error)]
+pub enum ColorParseError {
+    /// The hex digits following `#` were not 3, 4, 6 or 8 characters long.
+    #[error("hex color string has an invalid length (expected 3, 4, 6 or 8 hex digits)")]
+    InvalidLength,
+
+    /// One of the characters following `#` was not a valid hex digit.
+    #[error("hex color string contains a non-hexadecimal digit")]
+    InvalidDigit,
+
+    /// The string didn't start with `#` and didn't match any predefined
+    /// color name.
+    #[error("no predefined color matches this name")]
+    UnknownName,
+}
+
 impl Color {
-    /// Construct a new color from byte color values (0 - 255).
+    /// Construct a new color from byte color values (0 - 255), treating the
+    /// channels as sRGB-encoded (the usual convention for colors authored by
+    /// designers or sourced from hex codes) and decoding them to linear
+    /// light for storage. See [`Color::to_linear`].
     pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self {
             red: r as f32 / 255.0,
@@ -60,9 +113,12 @@ impl Color {
             blue: b as f32 / 255.0,
             alpha: a as f32 / 255.0,
         }
+        .to_linear()
     }
 
-    /// Construct a new color from a hex value (0xRRGGBBAA).
+    /// Construct a new color from a hex value (0xRRGGBBAA), treating the
+    /// channels as sRGB-encoded and decoding them to linear light for
+    /// storage. See [`Color::to_linear`].
     pub fn new_rgba(val: u32) -> Self {
         Self {
             red: ((val >> 24) & 0xFF) as f32 / 255.0,
@@ -70,10 +126,13 @@ impl Color {
             blue: ((val >> 8) & 0xFF) as f32 / 255.0,
             alpha: (val & 0xFF) as f32 / 255.0,
         }
+        .to_linear()
     }
 
     /// Construct a new color from a hex value (0xAARRGGBB). ARGBA is the format
-    /// commonly used by Win UI frameworks (but not by DirectX).
+    /// commonly used by Win UI frameworks (but not by DirectX). Treats the
+    /// channels as sRGB-encoded and decodes them to linear light for
+    /// storage. See [`Color::to_linear`].
     pub fn new_argb(val: u32) -> Self {
         Self {
             alpha: ((val >> 24) & 0xFF) as f32 / 255.0,
@@ -81,6 +140,33 @@ impl Color {
             green: ((val >> 8) & 0xFF) as f32 / 255.0,
             blue: (val & 0xFF) as f32 / 255.0,
         }
+        .to_linear()
+    }
+
+    /// Construct a new color from a hex value (0xRRGGBBAA), treating the
+    /// channels as already being in linear light rather than sRGB, and
+    /// storing them unmodified. For sRGB-encoded input (the common case),
+    /// use [`Color::new_rgba`] instead.
+    pub fn new_rgba_linear(val: u32) -> Self {
+        Self {
+            red: ((val >> 24) & 0xFF) as f32 / 255.0,
+            green: ((val >> 16) & 0xFF) as f32 / 255.0,
+            blue: ((val >> 8) & 0xFF) as f32 / 255.0,
+            alpha: (val & 0xFF) as f32 / 255.0,
+        }
+    }
+
+    /// Construct a new color from a hex value (0xAARRGGBB), treating the
+    /// channels as already being in linear light rather than sRGB, and
+    /// storing them unmodified. For sRGB-encoded input (the common case),
+    /// use [`Color::new_argb`] instead.
+    pub fn new_argb_linear(val: u32) -> Self {
+        Self {
+            alpha: ((val >> 24) & 0xFF) as f32 / 255.0,
+            red: ((val >> 16) & 0xFF) as f32 / 255.0,
+            green: ((val >> 8) & 0xFF) as f32 / 255.0,
+            blue: (val & 0xFF) as f32 / 255.0,
+        }
     }
 
     /// Construct a new color from normalized float color values (0.0 - 1.0).
@@ -102,6 +188,506 @@ impl Color {
         }
     }
 
+    /// Construct a new color from HSV (hue/saturation/value) components plus
+    /// an alpha value.
+    ///
+    /// `h` is the hue in degrees, wrapped into `[0.0, 360.0)`. `s`, `v` and
+    /// `a` are in the range `[0.0, 1.0]`.
+    pub fn from_hsv(h: f32, s: f32, v: f32, a: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+
+        let c = v * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = match (h / 60.0) as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Self {
+            red: r + m,
+            green: g + m,
+            blue: b + m,
+            alpha: a,
+        }
+    }
+
+    /// Construct a new color from HSL (hue/saturation/lightness) components
+    /// plus an alpha value.
+    ///
+    /// `h` is the hue in degrees, wrapped into `[0.0, 360.0)`. `s`, `l` and
+    /// `a` are in the range `[0.0, 1.0]`.
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r, g, b) = match (h / 60.0) as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Self {
+            red: r + m,
+            green: g + m,
+            blue: b + m,
+            alpha: a,
+        }
+    }
+
+    /// Converts this color to HSV (hue/saturation/value) components, along
+    /// with the passthrough alpha value, as `(h, s, v, a)`.
+    ///
+    /// `h` is in degrees in the range `[0.0, 360.0)`; `s`, `v` and `a` are in
+    /// the range `[0.0, 1.0]`.
+    pub fn to_hsv(&self) -> (f32, f32, f32, f32) {
+        let max = self.red.max(self.green).max(self.blue);
+        let min = self.red.min(self.green).min(self.blue);
+        let delta = max - min;
+
+        let v = max;
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        let h = Self::hue(self.red, self.green, self.blue, max, delta);
+
+        (h, s, v, self.alpha)
+    }
+
+    /// Converts this color to HSL (hue/saturation/lightness) components,
+    /// along with the passthrough alpha value, as `(h, s, l, a)`.
+    ///
+    /// `h` is in degrees in the range `[0.0, 360.0)`; `s`, `l` and `a` are in
+    /// the range `[0.0, 1.0]`.
+    pub fn to_hsl(&self) -> (f32, f32, f32, f32) {
+        let max = self.red.max(self.green).max(self.blue);
+        let min = self.red.min(self.green).min(self.blue);
+        let delta = max - min;
+
+        let l = (max + min) / 2.0;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        let h = Self::hue(self.red, self.green, self.blue, max, delta);
+
+        (h, s, l, self.alpha)
+    }
+
+    /// Derives the hue in degrees (`[0.0, 360.0)`) from RGB channels and
+    /// their precomputed `max`/`delta`. Returns `0.0` if `delta` is zero
+    /// (i.e. the color is a shade of gray).
+    fn hue(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        let h = if max == r {
+            ((g - b) / delta) % 6.0
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        (h * 60.0).rem_euclid(360.0)
+    }
+
+    /// Moves each RGB channel toward `0.0` by `amount`, leaving alpha
+    /// unchanged.
+    ///
+    /// `amount` is the fraction of the remaining distance to black to travel,
+    /// e.g. `0.5` halves each channel.
+    pub fn darken(self, amount: f32) -> Self {
+        Self {
+            red: self.red * (1.0 - amount),
+            green: self.green * (1.0 - amount),
+            blue: self.blue * (1.0 - amount),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Moves each RGB channel toward `1.0` by `amount`, leaving alpha
+    /// unchanged.
+    ///
+    /// `amount` is the fraction of the remaining distance to white to travel,
+    /// e.g. `0.5` halves the gap between each channel and `1.0`.
+    pub fn lighten(self, amount: f32) -> Self {
+        Self {
+            red: self.red + (1.0 - self.red) * amount,
+            green: self.green + (1.0 - self.green) * amount,
+            blue: self.blue + (1.0 - self.blue) * amount,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Returns a copy of this color with the alpha channel replaced by `a`.
+    pub fn with_alpha(self, a: f32) -> Self {
+        Self { alpha: a, ..self }
+    }
+
+    /// Returns the alpha-premultiplied form of this color (`red * alpha`,
+    /// `green * alpha`, `blue * alpha`), as required by Direct2D bitmaps and
+    /// brushes that expect premultiplied alpha.
+    pub fn premultiply(self) -> Self {
+        Self {
+            red: self.red * self.alpha,
+            green: self.green * self.alpha,
+            blue: self.blue * self.alpha,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Quantizes this color down to the nearest xterm 256-color palette
+    /// index, for driving diagnostic or headless console output with the
+    /// same color values used for DirectX rendering.
+    ///
+    /// Terminals expect sRGB-encoded byte values, but [`Color`] stores
+    /// linear light, so the color is re-encoded via [`Color::to_srgb`]
+    /// before quantizing.
+    pub fn to_ansi256(self) -> u8 {
+        let srgb = self.to_srgb();
+        let r = (srgb.red * 255.0).round() as u8;
+        let g = (srgb.green * 255.0).round() as u8;
+        let b = (srgb.blue * 255.0).round() as u8;
+
+        if r == g && g == b {
+            return if r < 8 {
+                16
+            } else if r > 248 {
+                231
+            } else {
+                (f32::from(r - 8) / 247.0 * 24.0) as u8 + 232
+            };
+        }
+
+        let cube = |c: u8| (f32::from(c) * 5.0 / 255.0).round() as u8;
+        16 + 36 * cube(r) + 6 * cube(g) + cube(b)
+    }
+
+    /// Composites `self` over `background` using the source-over alpha
+    /// blending rule, returning an opaque result.
+    pub fn over(self, background: Color) -> Color {
+        let a = self.alpha + background.alpha * (1.0 - self.alpha);
+
+        if a == 0.0 {
+            return Color::new_normalized(0.0, 0.0, 0.0, 0.0);
+        }
+
+        let blend = |fg: f32, bg: f32| {
+            (fg * self.alpha + bg * background.alpha * (1.0 - self.alpha)) / a
+        };
+
+        Color {
+            red: blend(self.red, background.red),
+            green: blend(self.green, background.green),
+            blue: blend(self.blue, background.blue),
+            alpha: a,
+        }
+    }
+
+    /// Linearly interpolates between `self` and `other`, channel by channel,
+    /// by the factor `t`.
+    ///
+    /// `t` is clamped to `[0.0, 1.0]`; a value of `0.0` returns `self`
+    /// unchanged and a value of `1.0` returns `other` unchanged.
+    pub fn lerp(&self, other: &Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+
+        Color {
+            red: self.red + (other.red - self.red) * t,
+            green: self.green + (other.green - self.green) * t,
+            blue: self.blue + (other.blue - self.blue) * t,
+            alpha: self.alpha + (other.alpha - self.alpha) * t,
+        }
+    }
+
+    /// Converts this color from (assumed) sRGB space into linear light,
+    /// applying the sRGB transfer function channel-wise. Alpha is left
+    /// untouched.
+    ///
+    /// [`Color`] stores linear light internally (see [`Color::new`]), but
+    /// operations like interpolation produce more physically correct results
+    /// when performed in linear space; see [`Color::lerp_linear`].
+    pub fn to_linear(self) -> Self {
+        let decode = |c: f32| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        Self {
+            red: decode(self.red),
+            green: decode(self.green),
+            blue: decode(self.blue),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Converts this color from linear light back into sRGB space, applying
+    /// the inverse sRGB transfer function channel-wise. Alpha is left
+    /// untouched.
+    pub fn from_linear(self) -> Self {
+        let encode = |c: f32| {
+            if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+
+        Self {
+            red: encode(self.red),
+            green: encode(self.green),
+            blue: encode(self.blue),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Linearly interpolates between `self` and `other` in linear light
+    /// rather than sRGB space, producing gamma-correct gradients.
+    ///
+    /// Equivalent to converting both endpoints with [`Color::to_linear`],
+    /// calling [`Color::lerp`], then converting the result back with
+    /// [`Color::from_linear`].
+    pub fn lerp_linear(&self, other: &Color, t: f32) -> Color {
+        self.to_linear()
+            .lerp(&other.to_linear(), t)
+            .from_linear()
+    }
+
+    /// Decodes this color from sRGB into linear light. Alias of
+    /// [`Color::to_linear`] for callers thinking in sRGB→linear terms.
+    pub fn from_srgb(self) -> Self {
+        self.to_linear()
+    }
+
+    /// Encodes this color from linear light into sRGB. Alias of
+    /// [`Color::from_linear`] for callers thinking in linear→sRGB terms.
+    pub fn to_srgb(self) -> Self {
+        self.from_linear()
+    }
+
+    /// Looks up one of the Microsoft UI core library predefined colors by
+    /// name, case-insensitively and ignoring underscores, so both
+    /// `"CornflowerBlue"` and `"cornflower_blue"` resolve to the same color.
+    ///
+    /// Returns `None` if `name` doesn't match any predefined color.
+    pub fn lookup(name: &str) -> Option<Color> {
+        let normalized = name.to_lowercase().replace('_', "");
+
+        match normalized.as_str() {
+            "aliceblue" => Some(Self::alice_blue()),
+            "antiquewhite" => Some(Self::antique_white()),
+            "aqua" => Some(Self::aqua()),
+            "aquamarine" => Some(Self::aquamarine()),
+            "azure" => Some(Self::azure()),
+            "beige" => Some(Self::beige()),
+            "bisque" => Some(Self::bisque()),
+            "black" => Some(Self::black()),
+            "blanchedalmond" => Some(Self::blanched_almond()),
+            "blue" => Some(Self::blue()),
+            "blueviolet" => Some(Self::blue_violet()),
+            "brown" => Some(Self::brown()),
+            "burlywood" => Some(Self::burly_wood()),
+            "cadetblue" => Some(Self::cadet_blue()),
+            "chartreuse" => Some(Self::chartreuse()),
+            "chocolate" => Some(Self::chocolate()),
+            "coral" => Some(Self::coral()),
+            "cornflowerblue" => Some(Self::cornflower_blue()),
+            "cornsilk" => Some(Self::cornsilk()),
+            "crimson" => Some(Self::crimson()),
+            "cyan" => Some(Self::cyan()),
+            "darkblue" => Some(Self::dark_blue()),
+            "darkcyan" => Some(Self::dark_cyan()),
+            "darkgoldenrod" => Some(Self::dark_goldenrod()),
+            "darkgray" => Some(Self::dark_gray()),
+            "darkgreen" => Some(Self::dark_green()),
+            "darkkhaki" => Some(Self::dark_khaki()),
+            "darkmagenta" => Some(Self::dark_magenta()),
+            "darkolivegreen" => Some(Self::dark_olive_green()),
+            "darkorange" => Some(Self::dark_orange()),
+            "darkorchid" => Some(Self::dark_orchid()),
+            "darkred" => Some(Self::dark_red()),
+            "darksalmon" => Some(Self::dark_salmon()),
+            "darkseagreen" => Some(Self::dark_sea_green()),
+            "darkslateblue" => Some(Self::dark_slate_blue()),
+            "darkslategray" => Some(Self::dark_slate_gray()),
+            "darkturquoise" => Some(Self::dark_turquoise()),
+            "darkviolet" => Some(Self::dark_violet()),
+            "deeppink" => Some(Self::deep_pink()),
+            "deepskyblue" => Some(Self::deep_sky_blue()),
+            "dimgray" => Some(Self::dim_gray()),
+            "dodgerblue" => Some(Self::dodger_blue()),
+            "firebrick" => Some(Self::firebrick()),
+            "floralwhite" => Some(Self::floral_white()),
+            "forestgreen" => Some(Self::forest_green()),
+            "fuchsia" => Some(Self::fuchsia()),
+            "gainsboro" => Some(Self::gainsboro()),
+            "ghostwhite" => Some(Self::ghost_white()),
+            "gold" => Some(Self::gold()),
+            "goldenrod" => Some(Self::goldenrod()),
+            "gray" => Some(Self::gray()),
+            "green" => Some(Self::green()),
+            "greenyellow" => Some(Self::green_yellow()),
+            "honeydew" => Some(Self::honeydew()),
+            "hotpink" => Some(Self::hot_pink()),
+            "indianred" => Some(Self::indian_red()),
+            "indigo" => Some(Self::indigo()),
+            "ivory" => Some(Self::ivory()),
+            "khaki" => Some(Self::khaki()),
+            "lavender" => Some(Self::lavender()),
+            "lavenderblush" => Some(Self::lavender_blush()),
+            "lawngreen" => Some(Self::lawn_green()),
+            "lemonchiffon" => Some(Self::lemon_chiffon()),
+            "lightblue" => Some(Self::light_blue()),
+            "lightcoral" => Some(Self::light_coral()),
+            "lightcyan" => Some(Self::light_cyan()),
+            "lightgoldenrodyellow" => Some(Self::light_goldenrod_yellow()),
+            "lightgray" => Some(Self::light_gray()),
+            "lightgreen" => Some(Self::light_green()),
+            "lightpink" => Some(Self::light_pink()),
+            "lightsalmon" => Some(Self::light_salmon()),
+            "lightseagreen" => Some(Self::light_sea_green()),
+            "lightskyblue" => Some(Self::light_sky_blue()),
+            "lightslategray" => Some(Self::light_slate_gray()),
+            "lightsteelblue" => Some(Self::light_steel_blue()),
+            "lightyellow" => Some(Self::light_yellow()),
+            "lime" => Some(Self::lime()),
+            "limegreen" => Some(Self::lime_green()),
+            "linen" => Some(Self::linen()),
+            "magenta" => Some(Self::magenta()),
+            "maroon" => Some(Self::maroon()),
+            "mediumaquamarine" => Some(Self::medium_aquamarine()),
+            "mediumblue" => Some(Self::medium_blue()),
+            "mediumorchid" => Some(Self::medium_orchid()),
+            "mediumpurple" => Some(Self::medium_purple()),
+            "mediumseagreen" => Some(Self::medium_sea_green()),
+            "mediumslateblue" => Some(Self::medium_slate_blue()),
+            "mediumspringgreen" => Some(Self::medium_spring_green()),
+            "mediumturquoise" => Some(Self::medium_turquoise()),
+            "mediumvioletred" => Some(Self::medium_violet_red()),
+            "midnightblue" => Some(Self::midnight_blue()),
+            "mintcream" => Some(Self::mint_cream()),
+            "mistyrose" => Some(Self::misty_rose()),
+            "moccasin" => Some(Self::moccasin()),
+            "navajowhite" => Some(Self::navajo_white()),
+            "navy" => Some(Self::navy()),
+            "oldlace" => Some(Self::old_lace()),
+            "olive" => Some(Self::olive()),
+            "olivedrab" => Some(Self::olive_drab()),
+            "orange" => Some(Self::orange()),
+            "orangered" => Some(Self::orange_red()),
+            "orchid" => Some(Self::orchid()),
+            "palegoldenrod" => Some(Self::pale_goldenrod()),
+            "palegreen" => Some(Self::pale_green()),
+            "paleturquoise" => Some(Self::pale_turquoise()),
+            "palevioletred" => Some(Self::pale_violet_red()),
+            "papayawhip" => Some(Self::papaya_whip()),
+            "peachpuff" => Some(Self::peach_puff()),
+            "peru" => Some(Self::peru()),
+            "pink" => Some(Self::pink()),
+            "plum" => Some(Self::plum()),
+            "powderblue" => Some(Self::powder_blue()),
+            "purple" => Some(Self::purple()),
+            "red" => Some(Self::red()),
+            "rosybrown" => Some(Self::rosy_brown()),
+            "royalblue" => Some(Self::royal_blue()),
+            "saddlebrown" => Some(Self::saddle_brown()),
+            "salmon" => Some(Self::salmon()),
+            "sandybrown" => Some(Self::sandy_brown()),
+            "seagreen" => Some(Self::sea_green()),
+            "seashell" => Some(Self::sea_shell()),
+            "sienna" => Some(Self::sienna()),
+            "silver" => Some(Self::silver()),
+            "skyblue" => Some(Self::sky_blue()),
+            "slateblue" => Some(Self::slate_blue()),
+            "slategray" => Some(Self::slate_gray()),
+            "snow" => Some(Self::snow()),
+            "springgreen" => Some(Self::spring_green()),
+            "steelblue" => Some(Self::steel_blue()),
+            "tan" => Some(Self::tan()),
+            "teal" => Some(Self::teal()),
+            "thistle" => Some(Self::thistle()),
+            "tomato" => Some(Self::tomato()),
+            "transparent" => Some(Self::transparent()),
+            "turquoise" => Some(Self::turquoise()),
+            "violet" => Some(Self::violet()),
+            "wheat" => Some(Self::wheat()),
+            "white" => Some(Self::white()),
+            "whitesmoke" => Some(Self::white_smoke()),
+            "yellow" => Some(Self::yellow()),
+            "yellowgreen" => Some(Self::yellow_green()),
+            _ => None,
+        }
+    }
+
+    /// Parses a color from a hex string in RGBA channel order (`#RRGGBB`,
+    /// `#RRGGBBAA`, or the `#RGB` shorthand), or from a bare Microsoft UI
+    /// color name (see [`Color::lookup`]).
+    ///
+    /// `#RGB` is expanded by duplicating each nibble, e.g. `#abc` becomes
+    /// `#aabbcc`. When no alpha is present, it defaults to fully opaque.
+    pub fn from_str_rgba(s: &str) -> Result<Self, ColorParseError> {
+        Self::from_hex_or_name(s, false)
+    }
+
+    /// Parses a color from a hex string in ARGB channel order (`#RRGGBB`,
+    /// `#AARRGGBB`, or the `#RGB` shorthand), or from a bare Microsoft UI
+    /// color name (see [`Color::lookup`]).
+    ///
+    /// `#RGB` is expanded by duplicating each nibble, e.g. `#abc` becomes
+    /// `#aabbcc`. When no alpha is present, it defaults to fully opaque.
+    pub fn from_str_argb(s: &str) -> Result<Self, ColorParseError> {
+        Self::from_hex_or_name(s, true)
+    }
+
+    fn from_hex_or_name(s: &str, alpha_first: bool) -> Result<Self, ColorParseError> {
+        let Some(digits) = s.strip_prefix('#') else {
+            return Self::lookup(s).ok_or(ColorParseError::UnknownName);
+        };
+
+        let (r, g, b, a) = Self::parse_hex_digits(digits, alpha_first)?;
+        Ok(Self::new(r, g, b, a))
+    }
+
+    /// Parses the hex digits of a `#RGB`/`#RRGGBB`/`#RGBA`/`#RRGGBBAA` string
+    /// (without the leading `#`) into `(r, g, b, a)` bytes, expanding the
+    /// shorthand forms and defaulting alpha to `0xFF` when absent.
+    fn parse_hex_digits(
+        digits: &str,
+        alpha_first: bool,
+    ) -> Result<(u8, u8, u8, u8), ColorParseError> {
+        let expanded: String = match digits.len() {
+            3 | 4 => digits.chars().flat_map(|c| [c, c]).collect(),
+            6 | 8 => digits.to_owned(),
+            _ => return Err(ColorParseError::InvalidLength),
+        };
+
+        let byte = |i: usize| -> Result<u8, ColorParseError> {
+            u8::from_str_radix(&expanded[i * 2..i * 2 + 2], 16)
+                .map_err(|_| ColorParseError::InvalidDigit)
+        };
+
+        match (expanded.len(), alpha_first) {
+            (8, true) => Ok((byte(1)?, byte(2)?, byte(3)?, byte(0)?)),
+            (8, false) => Ok((byte(0)?, byte(1)?, byte(2)?, byte(3)?)),
+            _ => Ok((byte(0)?, byte(1)?, byte(2)?, 0xFF)),
+        }
+    }
+
     /// AliceBlue predefined color from the Microsoft UI core library.
     pub fn alice_blue() -> Color {
         Color::new_argb(0xFFF0F8FF)
@@ -669,6 +1255,92 @@ impl Color {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use ::std::fmt;
+
+    impl Serialize for Color {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            // `Color` stores linear light, but the hex string is sRGB, so
+            // re-encode before quantizing to bytes. Mirrors the sRGB decode
+            // [`Color::new`]/[`FromStr`] apply on the way back in.
+            let srgb = self.to_srgb();
+            let r = (srgb.red * 255.0).round() as u8;
+            let g = (srgb.green * 255.0).round() as u8;
+            let b = (srgb.blue * 255.0).round() as u8;
+            let a = (srgb.alpha * 255.0).round() as u8;
+
+            serializer.serialize_str(&format!("#{r:02X}{g:02X}{b:02X}{a:02X}"))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Color {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(ColorVisitor)
+        }
+    }
+
+    struct ColorVisitor;
+
+    impl<'de> de::Visitor<'de> for ColorVisitor {
+        type Value = Color;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a hex/CSS color string or a {red, green, blue, alpha} map")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            v.parse().map_err(de::Error::custom)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            #[derive(Deserialize)]
+            #[serde(field_identifier, rename_all = "lowercase")]
+            enum Field {
+                Red,
+                Green,
+                Blue,
+                Alpha,
+            }
+
+            let mut red = None;
+            let mut green = None;
+            let mut blue = None;
+            let mut alpha = None;
+
+            while let Some(key) = map.next_key()? {
+                match key {
+                    Field::Red => red = Some(map.next_value()?),
+                    Field::Green => green = Some(map.next_value()?),
+                    Field::Blue => blue = Some(map.next_value()?),
+                    Field::Alpha => alpha = Some(map.next_value()?),
+                }
+            }
+
+            Ok(Color {
+                red: red.ok_or_else(|| de::Error::missing_field("red"))?,
+                green: green.ok_or_else(|| de::Error::missing_field("green"))?,
+                blue: blue.ok_or_else(|| de::Error::missing_field("blue"))?,
+                alpha: alpha.ok_or_else(|| de::Error::missing_field("alpha"))?,
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -678,35 +1350,292 @@ mod tests {
     #[test]
     fn test_byte_color() {
         let color = Color::new(0x9A, 0xCD, 0x32, 0xFF);
-        assert_eq!(color.red, 0x9A as f32 / 255.0);
-        assert_eq!(color.green, 0xCD as f32 / 255.0);
-        assert_eq!(color.blue, 0x32 as f32 / 255.0);
-        assert_eq!(color.alpha, 0xFF as f32 / 255.0);
+        let expected = Color::new_rgba_linear(0x9ACD32FF).to_linear();
 
+        assert_eq!(color, expected);
         assert_eq!(color, Color::yellow_green());
     }
 
     #[test]
     fn test_rgba_color() {
         let color = Color::new_rgba(0x9ACD32FF);
+        let expected = Color::new_rgba_linear(0x9ACD32FF).to_linear();
 
-        assert_eq!(color.red, 0x9A as f32 / 255.0);
-        assert_eq!(color.green, 0xCD as f32 / 255.0);
-        assert_eq!(color.blue, 0x32 as f32 / 255.0);
-        assert_eq!(color.alpha, 0xFF as f32 / 255.0);
-
+        assert_eq!(color, expected);
         assert_eq!(color, Color::yellow_green());
     }
 
     #[test]
     fn test_argb_color() {
         let color = Color::new_argb(0xFF9ACD32);
+        let expected = Color::new_argb_linear(0xFF9ACD32).to_linear();
+
+        assert_eq!(color, expected);
+        assert_eq!(color, Color::yellow_green());
+    }
+
+    #[test]
+    fn test_d2d1_color_f_round_trips_srgb_bytes() {
+        let color = Color::new(0x9A, 0xCD, 0x32, 0x80);
+        let d2d_color: D2D1_COLOR_F = color.into();
+
+        let tolerance = 1.0 / 255.0;
+        assert!((d2d_color.r - 0x9A as f32 / 255.0).abs() < tolerance);
+        assert!((d2d_color.g - 0xCD as f32 / 255.0).abs() < tolerance);
+        assert!((d2d_color.b - 0x32 as f32 / 255.0).abs() < tolerance);
+        assert!((d2d_color.a - 0x80 as f32 / 255.0).abs() < tolerance);
+    }
 
-        assert_eq!(color.red, 0x9A as f32 / 255.0);
-        assert_eq!(color.green, 0xCD as f32 / 255.0);
-        assert_eq!(color.blue, 0x32 as f32 / 255.0);
-        assert_eq!(color.alpha, 0xFF as f32 / 255.0);
+    #[test]
+    fn test_from_str_rgba_long_form() {
+        assert_eq!(Color::from_str_rgba("#9ACD32FF"), Ok(Color::yellow_green()));
+        assert_eq!(Color::from_str_rgba("#9ACD32"), Ok(Color::yellow_green()));
+    }
+
+    #[test]
+    fn test_from_str_rgba_shorthand() {
+        assert_eq!(Color::from_str_rgba("#abc"), Color::from_str_rgba("#aabbcc"));
+    }
+
+    #[test]
+    fn test_from_str_argb_long_form() {
+        assert_eq!(Color::from_str_argb("#FF9ACD32"), Ok(Color::yellow_green()));
+    }
 
+    #[test]
+    fn test_from_str_name() {
+        assert_eq!(Color::from_str_rgba("cornflower_blue"), Ok(Color::cornflower_blue()));
+        assert_eq!(
+            Color::from_str_rgba("not_a_color"),
+            Err(ColorParseError::UnknownName)
+        );
+    }
+
+    #[test]
+    fn test_from_str_invalid_length() {
+        assert_eq!(Color::from_str_rgba("#1234567"), Err(ColorParseError::InvalidLength));
+    }
+
+    #[test]
+    fn test_from_str_invalid_digit() {
+        assert_eq!(Color::from_str_rgba("#gggggg"), Err(ColorParseError::InvalidDigit));
+    }
+
+    #[test]
+    fn test_from_str_trait_impl() {
+        let color: Color = "#9ACD32FF".parse().unwrap();
+        assert_eq!(color, Color::yellow_green());
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let color = Color::try_from("#9ACD32FF").unwrap();
         assert_eq!(color, Color::yellow_green());
+
+        assert_eq!(
+            Color::try_from("not_a_color"),
+            Err(ColorParseError::UnknownName)
+        );
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        let color = Color::from_hsv(90.0, 0.5, 0.75, 1.0);
+        let (h, s, v, a) = color.to_hsv();
+
+        assert!((h - 90.0).abs() < 0.001);
+        assert!((s - 0.5).abs() < 0.001);
+        assert!((v - 0.75).abs() < 0.001);
+        assert_eq!(a, 1.0);
+    }
+
+    #[test]
+    fn test_hsv_achromatic() {
+        // Grays have no defined hue; `s == 0` and `h` should report `0.0`.
+        let gray = Color::from_hsv(200.0, 0.0, 0.6, 1.0);
+        let (h, s, v, _) = gray.to_hsv();
+
+        assert_eq!(h, 0.0);
+        assert_eq!(s, 0.0);
+        assert!((v - 0.6).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_hsv_primary_colors() {
+        assert_eq!(Color::from_hsv(0.0, 1.0, 1.0, 1.0), Color::new_normalized(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(
+            Color::from_hsv(120.0, 1.0, 1.0, 1.0),
+            Color::new_normalized(0.0, 1.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            Color::from_hsv(240.0, 1.0, 1.0, 1.0),
+            Color::new_normalized(0.0, 0.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        let color = Color::from_hsl(210.0, 0.6, 0.4, 1.0);
+        let (h, s, l, a) = color.to_hsl();
+
+        assert!((h - 210.0).abs() < 0.001);
+        assert!((s - 0.6).abs() < 0.001);
+        assert!((l - 0.4).abs() < 0.001);
+        assert_eq!(a, 1.0);
+    }
+
+    #[test]
+    fn test_hsl_black_and_white() {
+        assert_eq!(Color::from_hsl(0.0, 0.0, 0.0, 1.0), Color::black());
+        assert_eq!(Color::from_hsl(0.0, 0.0, 1.0, 1.0), Color::white());
+
+        let (h, s, _, _) = Color::black().to_hsl();
+        assert_eq!(h, 0.0);
+        assert_eq!(s, 0.0);
+    }
+
+    #[test]
+    fn test_darken_and_lighten() {
+        let gray = Color::new_normalized(0.5, 0.5, 0.5, 1.0);
+
+        assert_eq!(gray.darken(0.5), Color::new_normalized(0.25, 0.25, 0.25, 1.0));
+        assert_eq!(gray.lighten(0.5), Color::new_normalized(0.75, 0.75, 0.75, 1.0));
+        assert_eq!(gray.darken(0.0), gray);
+        assert_eq!(gray.lighten(0.0), gray);
+    }
+
+    #[test]
+    fn test_with_alpha() {
+        let color = Color::red().with_alpha(0.5);
+
+        assert_eq!(color.alpha, 0.5);
+        assert_eq!(color.red, Color::red().red);
+    }
+
+    #[test]
+    fn test_premultiply() {
+        let color = Color::new_normalized(1.0, 0.5, 0.25, 0.5).premultiply();
+
+        assert_eq!(color.red, 0.5);
+        assert_eq!(color.green, 0.25);
+        assert_eq!(color.blue, 0.125);
+        assert_eq!(color.alpha, 0.5);
+    }
+
+    #[test]
+    fn test_to_ansi256_endpoints() {
+        assert_eq!(Color::black().to_ansi256(), 16);
+        assert_eq!(Color::white().to_ansi256(), 231);
+    }
+
+    #[test]
+    fn test_to_ansi256_grays() {
+        assert_eq!(Color::new(0x80, 0x80, 0x80, 0xFF).to_ansi256(), 243);
+        assert_eq!(Color::new(0x40, 0x40, 0x40, 0xFF).to_ansi256(), 237);
+    }
+
+    #[test]
+    fn test_to_ansi256_color_cube() {
+        assert_eq!(Color::red().to_ansi256(), 196);
+    }
+
+    #[test]
+    fn test_over_opaque_background() {
+        let red = Color::red().with_alpha(0.5);
+        let result = red.over(Color::white());
+
+        assert_eq!(result.alpha, 1.0);
+        assert_eq!(result, Color::new_normalized(1.0, 0.5, 0.5, 1.0));
+    }
+
+    #[test]
+    fn test_over_fully_transparent_source() {
+        let transparent = Color::red().with_alpha(0.0);
+        assert_eq!(transparent.over(Color::blue()), Color::blue());
+    }
+
+    #[test]
+    fn test_lerp() {
+        let black = Color::black();
+        let white = Color::white();
+
+        assert_eq!(black.lerp(&white, 0.0), black);
+        assert_eq!(black.lerp(&white, 1.0), white);
+        assert_eq!(black.lerp(&white, 0.5), Color::new_normalized(0.5, 0.5, 0.5, 1.0));
+
+        // `t` is clamped to `[0.0, 1.0]`.
+        assert_eq!(black.lerp(&white, -1.0), black);
+        assert_eq!(black.lerp(&white, 2.0), white);
+    }
+
+    #[test]
+    fn test_srgb_linear_round_trip() {
+        let color = Color::new_normalized(0.8, 0.4, 0.1, 0.5);
+        let round_tripped = color.to_linear().from_linear();
+
+        assert!((round_tripped.red - color.red).abs() < 0.0001);
+        assert!((round_tripped.green - color.green).abs() < 0.0001);
+        assert!((round_tripped.blue - color.blue).abs() < 0.0001);
+        assert_eq!(round_tripped.alpha, color.alpha);
+    }
+
+    #[test]
+    fn test_linear_endpoints() {
+        assert_eq!(Color::black().to_linear(), Color::black());
+        assert_eq!(Color::white().to_linear(), Color::white());
+    }
+
+    #[test]
+    fn test_lerp_linear() {
+        let black = Color::black();
+        let white = Color::white();
+
+        assert_eq!(black.lerp_linear(&white, 0.0), black);
+        assert_eq!(black.lerp_linear(&white, 1.0), white);
+
+        // The linear midpoint should differ from the naive sRGB midpoint.
+        assert_ne!(black.lerp_linear(&white, 0.5), black.lerp(&white, 0.5));
+    }
+
+    #[test]
+    fn test_linear_constructors_and_aliases() {
+        // The byte/hex constructors decode sRGB input to linear, while their
+        // `_linear` counterparts store the bytes unmodified, so the two
+        // differ except at the 0x00/0xFF endpoints.
+        assert_ne!(Color::new_rgba_linear(0x9ACD32FF), Color::new_rgba(0x9ACD32FF));
+        assert_ne!(Color::new_argb_linear(0xFF9ACD32), Color::new_argb(0xFF9ACD32));
+        assert_eq!(Color::new_rgba_linear(0x000000FF), Color::new_rgba(0x000000FF));
+        assert_eq!(Color::new_rgba_linear(0xFFFFFFFF), Color::new_rgba(0xFFFFFFFF));
+
+        let color = Color::new_normalized(0.8, 0.4, 0.1, 0.5);
+        assert_eq!(color.from_srgb(), color.to_linear());
+        assert_eq!(color.to_srgb(), color.from_linear());
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(Color::lookup("CornflowerBlue"), Some(Color::cornflower_blue()));
+        assert_eq!(Color::lookup("cornflower_blue"), Some(Color::cornflower_blue()));
+        assert_eq!(Color::lookup("CORNFLOWERBLUE"), Some(Color::cornflower_blue()));
+        assert_eq!(Color::lookup("not_a_color"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip_string() {
+        let color = Color::yellow_green();
+        let json = ::serde_json::to_string(&color).unwrap();
+
+        assert_eq!(json, "\"#9ACD32FF\"");
+        assert_eq!(::serde_json::from_str::<Color>(&json).unwrap(), color);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_deserialize_from_map() {
+        let json = r#"{"red": 0.5, "green": 0.25, "blue": 0.125, "alpha": 1.0}"#;
+        let color: Color = ::serde_json::from_str(json).unwrap();
+
+        assert_eq!(color, Color::new_normalized(0.5, 0.25, 0.125, 1.0));
     }
 }
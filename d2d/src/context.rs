@@ -1,15 +1,59 @@
 //! Graphics context which is used for all concrete drawing operations within a
 //! draw cycle.
 
-use ::std::rc::Rc;
+use ::std::{
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+use ::widestring::U16CString;
 use ::win_geom::d2::{Ellipse2D, Point2D, Rect2D, RoundedRect2D, Size2D};
-use ::windows::{core::InParam, Win32::Graphics::Direct2D::ID2D1HwndRenderTarget};
+use ::windows::{
+    core::InParam,
+    Foundation::Numerics::Matrix3x2,
+    Win32::Graphics::Direct2D::{
+        Common::D2D_RECT_F, ID2D1HwndRenderTarget, ID2D1StrokeStyle, D2D1_ANTIALIAS_MODE,
+        D2D1_ANTIALIAS_MODE_ALIASED, D2D1_ANTIALIAS_MODE_PER_PRIMITIVE,
+    },
+};
 
 use crate::{
-    brushes::{Brush, SolidColorBrush},
+    brushes::Brush,
+    geometry::PathGeometry,
+    imaging::{Bitmap, Interpolation},
+    stroke_style::StrokeStyle,
+    text::{TextFormat, TextLayout},
     Color, DeviceResource, RenderTarget,
 };
 
+/// Converts an optional [`StrokeStyle`] into the `InParam` expected by the
+/// underlying `ID2D1HwndRenderTarget` stroke-taking methods.
+fn stroke_style_param(stroke_style: Option<&StrokeStyle>) -> InParam<'_, ID2D1StrokeStyle> {
+    match stroke_style {
+        Some(stroke_style) => stroke_style.device_stroke_style().into(),
+        None => InParam::null(),
+    }
+}
+
+/// Antialiasing mode applied to a clip region pushed via
+/// [`Context::push_clip`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AliasMode {
+    /// The clip's edges are antialiased against what's already on the
+    /// target.
+    PerPrimitive,
+    /// The clip's edges are not antialiased.
+    Aliased,
+}
+
+impl From<AliasMode> for D2D1_ANTIALIAS_MODE {
+    fn from(val: AliasMode) -> Self {
+        match val {
+            AliasMode::PerPrimitive => D2D1_ANTIALIAS_MODE_PER_PRIMITIVE,
+            AliasMode::Aliased => D2D1_ANTIALIAS_MODE_ALIASED,
+        }
+    }
+}
+
 /// Drawing context for performing batched operations on an underlying render
 /// target. Drawing may _only_ be performed via a `Context`.
 ///
@@ -25,11 +69,11 @@ use crate::{
 /// # let factory = D2DFactory::new().unwrap();
 /// # let mut render_target = factory.make_render_target(
 /// #     HWND(0),
-/// #     Size2D { width: 100, height: 100 },
+/// #     Size2D::new(100, 100),
 /// # );
 /// let ctx = render_target.begin_draw();
 /// ctx.clear(Color::blue());
-/// ctx.put_pixel(Point2D { x: 10.0, y: 10.0 }, Color::red());
+/// ctx.put_pixel(Point2D::new(10.0, 10.0), Color::red());
 /// ctx.end_draw();
 /// ```
 pub struct Context<'t> {
@@ -52,6 +96,21 @@ impl<'t> Context<'t> {
         }
     }
 
+    /// The render target's current size, in DIPs, so drawing code can lay
+    /// out against the window's actual client area instead of a size
+    /// baked in at creation time.
+    pub fn target_size(&self) -> Size2D<i32> {
+        self.render_target.size()
+    }
+
+    /// The ratio between the target window's current DPI and the Windows
+    /// default of 96, e.g. `1.5` at 150% scaling, so drawing code can
+    /// convert between logical and physical coordinates without querying
+    /// the window directly.
+    pub fn scale_factor(&self) -> f32 {
+        self.render_target.dpi().scale_factor()
+    }
+
     /// Clears the entire screen by filling with `color`.
     pub fn clear(&self, color: Color) {
         unsafe {
@@ -59,28 +118,34 @@ impl<'t> Context<'t> {
         }
     }
 
-    /// TEMP/HACK
-    /// Put a single pixel to the screen using `brush` at `coord`.
-    /// TODO: allow any type of brush, not just SolidColor
-    pub fn put_pixel(&mut self, origin: Point2D<f32>, brush: &mut SolidColorBrush) {
-        brush.recreate_if_needed(self.render_target);
+    /// Put a single pixel to the screen using `color` at `origin`.
+    ///
+    /// The underlying device brush is served out of
+    /// [`RenderTarget`]'s brush cache, so repeatedly calling this with the
+    /// same handful of colors doesn't allocate a fresh device brush on
+    /// every call.
+    pub fn put_pixel(&mut self, origin: Point2D<f32>, color: Color) {
+        let device_brush = self.render_target.cached_brush(color);
 
         let rect = Rect2D::from_size_and_origin(Size2D::pixel(), origin);
         unsafe {
-            self.device_target
-                .FillRectangle(&rect.into() as _, brush.device_brush());
+            self.device_target.FillRectangle(&rect.into() as _, (&device_brush).into());
         }
     }
 
     /// Draws a line between the specified points using a solid stroke of width
-    /// `stroke_width`.
-    pub fn draw_line(
+    /// `stroke_width`, optionally styled by `stroke_style` (dashes, caps).
+    /// Pass `None` for a solid, flat-capped line.
+    pub fn draw_line<B>(
         &mut self,
         p0: Point2D<f32>,
         p1: Point2D<f32>,
         stroke_width: f32,
-        brush: &mut SolidColorBrush,
-    ) {
+        brush: &mut B,
+        stroke_style: Option<&StrokeStyle>,
+    ) where
+        B: Brush + DeviceResource,
+    {
         brush.recreate_if_needed(self.render_target);
 
         unsafe {
@@ -89,13 +154,16 @@ impl<'t> Context<'t> {
                 p1.into(),
                 brush.device_brush(),
                 stroke_width,
-                InParam::null(),
+                stroke_style_param(stroke_style),
             );
         }
     }
 
     /// Paints the interior of the specified rectangle.
-    pub fn fill_rect(&mut self, rect: Rect2D<f32>, brush: &mut SolidColorBrush) {
+    pub fn fill_rect<B>(&mut self, rect: Rect2D<f32>, brush: &mut B)
+    where
+        B: Brush + DeviceResource,
+    {
         brush.recreate_if_needed(self.render_target);
         unsafe {
             self.device_target
@@ -104,20 +172,24 @@ impl<'t> Context<'t> {
     }
 
     /// Draws the outline of a rectangle that has the specified dimensions with
-    /// a solid color stroke.
-    pub fn stroke_rect(
+    /// a solid color stroke, optionally styled by `stroke_style` (dashes,
+    /// caps, joins). Pass `None` for a solid, mitered outline.
+    pub fn stroke_rect<B>(
         &mut self,
         rect: Rect2D<f32>,
-        brush: &mut SolidColorBrush,
+        brush: &mut B,
         stroke_width: f32,
-    ) {
+        stroke_style: Option<&StrokeStyle>,
+    ) where
+        B: Brush + DeviceResource,
+    {
         brush.recreate_if_needed(self.render_target);
         unsafe {
             self.device_target.DrawRectangle(
                 &rect.into() as _,
                 brush.device_brush(),
                 stroke_width,
-                InParam::null(),
+                stroke_style_param(stroke_style),
             );
         }
     }
@@ -131,7 +203,10 @@ impl<'t> Context<'t> {
     ///
     /// [`radius_x`]: RoundedRect2D.radius_x
     /// [`radius_y`]: RoundedRect2D.radius_y
-    pub fn fill_rounded_rect(&mut self, rect: RoundedRect2D<f32>, brush: &mut SolidColorBrush) {
+    pub fn fill_rounded_rect<B>(&mut self, rect: RoundedRect2D<f32>, brush: &mut B)
+    where
+        B: Brush + DeviceResource,
+    {
         brush.recreate_if_needed(self.render_target);
         unsafe {
             self.device_target
@@ -149,25 +224,31 @@ impl<'t> Context<'t> {
     ///
     /// [`radius_x`]: RoundedRect2D.radius_x
     /// [`radius_y`]: RoundedRect2D.radius_y
-    pub fn stroke_rounded_rect(
+    pub fn stroke_rounded_rect<B>(
         &mut self,
         rect: RoundedRect2D<f32>,
-        brush: &mut SolidColorBrush,
+        brush: &mut B,
         stroke_width: f32,
-    ) {
+        stroke_style: Option<&StrokeStyle>,
+    ) where
+        B: Brush + DeviceResource,
+    {
         brush.recreate_if_needed(self.render_target);
         unsafe {
             self.device_target.DrawRoundedRectangle(
                 &rect.into() as _,
                 brush.device_brush(),
                 stroke_width,
-                InParam::null(),
+                stroke_style_param(stroke_style),
             );
         }
     }
 
     /// Paints the interior of the specified ellipse.
-    pub fn fill_ellipse(&mut self, ellipse: Ellipse2D<f32>, brush: &mut SolidColorBrush) {
+    pub fn fill_ellipse<B>(&mut self, ellipse: Ellipse2D<f32>, brush: &mut B)
+    where
+        B: Brush + DeviceResource,
+    {
         brush.recreate_if_needed(self.render_target);
         unsafe {
             self.device_target
@@ -176,33 +257,238 @@ impl<'t> Context<'t> {
     }
 
     /// Draws the outline of an ellipse that has the specified dimensions with a
-    /// solid color stroke.
-    pub fn stroke_ellipse(
+    /// solid color stroke, optionally styled by `stroke_style` (dashes,
+    /// caps, joins). Pass `None` for a solid outline.
+    pub fn stroke_ellipse<B>(
         &mut self,
         ellipse: Ellipse2D<f32>,
-        brush: &mut SolidColorBrush,
+        brush: &mut B,
         stroke_width: f32,
-    ) {
+        stroke_style: Option<&StrokeStyle>,
+    ) where
+        B: Brush + DeviceResource,
+    {
         brush.recreate_if_needed(self.render_target);
         unsafe {
             self.device_target.DrawEllipse(
                 &ellipse.into() as _,
                 brush.device_brush(),
                 stroke_width,
-                InParam::null(),
+                stroke_style_param(stroke_style),
+            );
+        }
+    }
+
+    /// Draws a pre-measured [`TextLayout`] with its top-left corner at
+    /// `origin`, painted with `brush`.
+    pub fn draw_text_layout<B>(&mut self, layout: &TextLayout, origin: Point2D<f32>, brush: &mut B)
+    where
+        B: Brush + DeviceResource,
+    {
+        brush.recreate_if_needed(self.render_target);
+        unsafe {
+            self.device_target.DrawTextLayout(
+                origin.into(),
+                layout.device_layout(),
+                brush.device_brush(),
+                Default::default(),
+            );
+        }
+    }
+
+    /// Draws `text` directly within `rect`, shaped and laid out internally
+    /// by Direct2D according to `format`. Painted with `brush`.
+    ///
+    /// Unlike [`Self::draw_text_layout`], this skips building a
+    /// [`TextLayout`] up front - prefer [`Self::draw_text_layout`] with a
+    /// cached layout when drawing the same text across multiple frames,
+    /// since re-measuring text every frame is comparatively expensive.
+    pub fn draw_text<B>(
+        &mut self,
+        text: &str,
+        format: &TextFormat,
+        rect: Rect2D<f32>,
+        brush: &mut B,
+    ) where
+        B: Brush + DeviceResource,
+    {
+        brush.recreate_if_needed(self.render_target);
+
+        let text = U16CString::from_str(text).expect("Text contained null byte");
+        unsafe {
+            self.device_target.DrawText(
+                text.as_slice(),
+                format.device_format(),
+                &rect.into() as _,
+                brush.device_brush(),
+                Default::default(),
+                Default::default(),
             );
         }
     }
 
+    /// Paints the interior of `geometry` with `brush`.
+    pub fn fill_geometry<B>(&mut self, geometry: &PathGeometry, brush: &mut B)
+    where
+        B: Brush + DeviceResource,
+    {
+        brush.recreate_if_needed(self.render_target);
+
+        unsafe {
+            self.device_target.FillGeometry(
+                geometry.device_geometry(),
+                brush.device_brush(),
+                None,
+            );
+        }
+    }
+
+    /// Draws the outline of `geometry` with a `stroke_width` stroke of
+    /// `brush`, optionally styled by `stroke_style` (dashes, caps, joins).
+    pub fn draw_geometry<B>(
+        &mut self,
+        geometry: &PathGeometry,
+        brush: &mut B,
+        stroke_width: f32,
+        stroke_style: Option<&StrokeStyle>,
+    ) where
+        B: Brush + DeviceResource,
+    {
+        brush.recreate_if_needed(self.render_target);
+
+        unsafe {
+            self.device_target.DrawGeometry(
+                geometry.device_geometry(),
+                brush.device_brush(),
+                stroke_width,
+                stroke_style_param(stroke_style),
+            );
+        }
+    }
+
+    /// Draws `bitmap` into `dest`, scaling to fit using `interpolation`. If
+    /// `src` is given, only that sub-rectangle of `bitmap` is sampled;
+    /// otherwise the whole bitmap is used.
+    pub fn draw_bitmap(
+        &mut self,
+        bitmap: &mut Bitmap,
+        dest: Rect2D<f32>,
+        opacity: f32,
+        interpolation: Interpolation,
+        src: Option<Rect2D<f32>>,
+    ) {
+        bitmap.recreate_if_needed(self.render_target);
+
+        let src_rect: Option<D2D_RECT_F> = src.map(Into::into);
+        unsafe {
+            self.device_target.DrawBitmap(
+                bitmap.device_bitmap(),
+                Some(&dest.into() as _),
+                opacity,
+                interpolation.into(),
+                src_rect.as_ref().map(|r| r as *const _),
+            );
+        }
+    }
+
+    /// The transform currently applied to all drawing on this [`Context`].
+    pub fn transform(&self) -> Matrix3x2 {
+        let mut transform = Matrix3x2::identity();
+        unsafe {
+            self.device_target.GetTransform(&mut transform as _);
+        }
+        transform
+    }
+
+    /// Replaces the transform applied to all subsequent drawing on this
+    /// [`Context`], e.g. to pan, zoom, rotate, or skew.
+    ///
+    /// Coordinates passed to draw calls are in the *untransformed* space; the
+    /// transform is applied by Direct2D itself. Prefer [`Self::with_transform`]
+    /// over calling this directly, so the previous transform is always
+    /// restored.
+    pub fn set_transform(&mut self, transform: Matrix3x2) {
+        unsafe {
+            self.device_target.SetTransform(&transform as _);
+        }
+    }
+
+    /// Composes `transform` onto the current transform, runs `f`, then
+    /// restores the transform that was active beforehand - so transforms
+    /// nest cleanly no matter how deeply [`Self::with_transform`] calls are
+    /// stacked.
+    pub fn with_transform(&mut self, transform: Matrix3x2, f: impl FnOnce(&mut Self)) {
+        let previous = self.transform();
+        self.set_transform(transform * previous);
+        f(self);
+        self.set_transform(previous);
+    }
+
+    /// Constrains subsequent drawing to `rect` until the returned
+    /// [`ClipGuard`] is dropped, which automatically pops the clip - so every
+    /// push is always matched with a pop, as Direct2D requires before
+    /// [`Self::end_draw`].
+    ///
+    /// Clips nest: pushing a second clip while the first is still active
+    /// further constrains drawing to their intersection, restored in turn
+    /// when each guard is dropped.
+    pub fn push_clip(&mut self, rect: Rect2D<f32>, antialias: AliasMode) -> ClipGuard<'_, 't> {
+        unsafe {
+            self.device_target.PushAxisAlignedClip(&rect.into() as _, antialias.into());
+        }
+        ClipGuard { context: self }
+    }
+
+    fn pop_clip(&mut self) {
+        unsafe {
+            self.device_target.PopAxisAlignedClip();
+        }
+    }
+
     /// Ends drawing operations on the render target causing the changes to
     /// become visible and the render target to become ready for the next
     /// [`begin_draw`](RenderTarget::begin_draw) call.
     pub fn end_draw(self) {
+        self.end_draw_retryable();
+    }
+
+    /// Crate-private variant of [`Self::end_draw`] used by
+    /// [`RenderTarget::draw`] to detect device loss. Returns `true` if the
+    /// frame was presented, or `false` if the device was lost and the
+    /// caller should re-run the frame against the freshly recreated target.
+    pub(crate) fn end_draw_retryable(self) -> bool {
         let Self {
             render_target,
             device_target,
         } = self;
 
-        render_target.end_draw(device_target);
+        render_target.end_draw(device_target)
+    }
+}
+
+/// RAII guard returned by [`Context::push_clip`]. Pops the clip rect when
+/// dropped, so a pushed clip is always matched with a pop even if a caller
+/// returns early or panics mid-draw.
+pub struct ClipGuard<'c, 't> {
+    context: &'c mut Context<'t>,
+}
+
+impl<'c, 't> Deref for ClipGuard<'c, 't> {
+    type Target = Context<'t>;
+
+    fn deref(&self) -> &Self::Target {
+        self.context
+    }
+}
+
+impl<'c, 't> DerefMut for ClipGuard<'c, 't> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.context
+    }
+}
+
+impl<'c, 't> Drop for ClipGuard<'c, 't> {
+    fn drop(&mut self) {
+        self.context.pop_clip();
     }
 }
@@ -15,8 +15,8 @@ use ::windows::Win32::{
     Graphics::Direct2D::{
         D2D1CreateFactory, ID2D1Factory, ID2D1HwndRenderTarget, D2D1_ANTIALIAS_MODE_PER_PRIMITIVE,
         D2D1_FACTORY_OPTIONS, D2D1_FACTORY_TYPE_SINGLE_THREADED, D2D1_FEATURE_LEVEL_10,
-        D2D1_HWND_RENDER_TARGET_PROPERTIES, D2D1_RENDER_TARGET_PROPERTIES,
-        D2D1_RENDER_TARGET_TYPE_HARDWARE,
+        D2D1_HWND_RENDER_TARGET_PROPERTIES, D2D1_PRESENT_OPTIONS_RETAIN_CONTENTS,
+        D2D1_RENDER_TARGET_PROPERTIES, D2D1_RENDER_TARGET_TYPE_HARDWARE,
     },
 };
 
@@ -57,6 +57,13 @@ impl D2DFactory {
         }))
     }
 
+    /// Crate-private access to the underlying `ID2D1Factory`, used by
+    /// [`crate::composition`] to upgrade to the `ID2D1Factory1` interface
+    /// required for device/device-context based rendering.
+    pub(crate) fn inner(&self) -> &ID2D1Factory {
+        &self.inner
+    }
+
     /// Makes a new Direct2D render target which targets a Win32 window.
     ///
     /// # Example
@@ -93,6 +100,10 @@ impl D2DFactory {
         let hwnd_target_props = D2D1_HWND_RENDER_TARGET_PROPERTIES {
             hwnd,
             pixelSize: pixel_size.cast::<u32>().into(),
+            // Retain the previous frame's contents across `Present` calls,
+            // avoiding the black-frame flash some drivers otherwise show
+            // after a resize or device-loss recreation.
+            presentOptions: D2D1_PRESENT_OPTIONS_RETAIN_CONTENTS,
             ..Default::default()
         };
 
@@ -103,6 +114,7 @@ impl D2DFactory {
                     .CreateHwndRenderTarget(&render_props as _, &hwnd_target_props as _)
             },
             "CreateHwndRenderTarget",
+            (file!(), line!()),
         )?;
 
         unsafe {
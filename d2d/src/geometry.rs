@@ -0,0 +1,182 @@
+//! Arbitrary path geometry (polylines, beziers, filled/stroked figures),
+//! built via an `ID2D1GeometrySink` and drawn through
+//! [`Context::fill_geometry`](crate::Context::fill_geometry) /
+//! [`Context::draw_geometry`](crate::Context::draw_geometry).
+
+use ::win32::{errors::Result, invoke::check_res};
+use ::win_geom::d2::Point2D;
+use ::windows::Win32::Graphics::Direct2D::{
+    ID2D1Geometry, ID2D1GeometrySink, ID2D1PathGeometry, D2D1_BEZIER_SEGMENT,
+    D2D1_FIGURE_BEGIN, D2D1_FIGURE_BEGIN_FILLED, D2D1_FIGURE_BEGIN_HOLLOW, D2D1_FIGURE_END,
+    D2D1_FIGURE_END_CLOSED, D2D1_FIGURE_END_OPEN, D2D1_QUADRATIC_BEZIER_SEGMENT,
+};
+
+use crate::D2DFactory;
+
+/// Whether a figure's interior is painted by
+/// [`Context::fill_geometry`](crate::Context::fill_geometry).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FigureFill {
+    /// The figure's interior is filled.
+    Filled,
+    /// The figure has no interior; only its outline is drawn.
+    Hollow,
+}
+
+impl From<FigureFill> for D2D1_FIGURE_BEGIN {
+    fn from(val: FigureFill) -> Self {
+        match val {
+            FigureFill::Filled => D2D1_FIGURE_BEGIN_FILLED,
+            FigureFill::Hollow => D2D1_FIGURE_BEGIN_HOLLOW,
+        }
+    }
+}
+
+/// Whether a figure's start and end points are implicitly joined with a
+/// final segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FigureEnd {
+    /// The figure's start and end points are left unjoined.
+    Open,
+    /// The figure's start and end points are joined, closing the figure.
+    Closed,
+}
+
+impl From<FigureEnd> for D2D1_FIGURE_END {
+    fn from(val: FigureEnd) -> Self {
+        match val {
+            FigureEnd::Open => D2D1_FIGURE_END_OPEN,
+            FigureEnd::Closed => D2D1_FIGURE_END_CLOSED,
+        }
+    }
+}
+
+/// Builds a [`PathGeometry`] out of one or more figures (polylines,
+/// beziers), each started with [`Self::begin_figure`] and finished with
+/// [`Self::end_figure`]. Create via [`D2DFactory::make_path_geometry`],
+/// finalize with [`Self::build`].
+pub struct PathGeometryBuilder {
+    geometry: ID2D1PathGeometry,
+    sink: ID2D1GeometrySink,
+}
+
+impl PathGeometryBuilder {
+    pub(crate) fn new(geometry: ID2D1PathGeometry) -> Result<Self> {
+        let sink = check_res(
+            || unsafe { geometry.Open() },
+            "ID2D1PathGeometry::Open",
+            (file!(), line!()),
+        )?;
+
+        Ok(Self { geometry, sink })
+    }
+
+    /// Starts a new figure at `start`. Must be paired with a later call to
+    /// [`Self::end_figure`] before starting another figure or calling
+    /// [`Self::build`].
+    pub fn begin_figure(&mut self, start: Point2D<f32>, fill: FigureFill) -> &mut Self {
+        unsafe {
+            self.sink.BeginFigure(start.into(), fill.into());
+        }
+        self
+    }
+
+    /// Adds a straight line segment from the current point to `point`.
+    pub fn add_line(&mut self, point: Point2D<f32>) -> &mut Self {
+        unsafe {
+            self.sink.AddLine(point.into());
+        }
+        self
+    }
+
+    /// Adds a sequence of straight line segments, one per point in `points`.
+    pub fn add_lines(&mut self, points: &[Point2D<f32>]) -> &mut Self {
+        for &point in points {
+            self.add_line(point);
+        }
+        self
+    }
+
+    /// Adds a cubic Bezier segment from the current point to `end`, using
+    /// `c1`/`c2` as control points.
+    pub fn add_bezier(
+        &mut self,
+        c1: Point2D<f32>,
+        c2: Point2D<f32>,
+        end: Point2D<f32>,
+    ) -> &mut Self {
+        unsafe {
+            self.sink.AddBezier(&D2D1_BEZIER_SEGMENT {
+                point1: c1.into(),
+                point2: c2.into(),
+                point3: end.into(),
+            });
+        }
+        self
+    }
+
+    /// Adds a quadratic Bezier segment from the current point to `end`, using
+    /// `control` as the single control point.
+    pub fn add_quadratic_bezier(&mut self, control: Point2D<f32>, end: Point2D<f32>) -> &mut Self {
+        unsafe {
+            self.sink.AddQuadraticBezier(&D2D1_QUADRATIC_BEZIER_SEGMENT {
+                point1: control.into(),
+                point2: end.into(),
+            });
+        }
+        self
+    }
+
+    /// Finishes the current figure, started via [`Self::begin_figure`].
+    pub fn end_figure(&mut self, end: FigureEnd) -> &mut Self {
+        unsafe {
+            self.sink.EndFigure(end.into());
+        }
+        self
+    }
+
+    /// Closes the sink and finalizes the built figures into a usable
+    /// [`PathGeometry`].
+    pub fn build(self) -> Result<PathGeometry> {
+        check_res(
+            || unsafe { self.sink.Close() },
+            "ID2D1GeometrySink::Close",
+            (file!(), line!()),
+        )?;
+
+        Ok(PathGeometry { inner: self.geometry })
+    }
+}
+
+/// An immutable, arbitrary path geometry (polylines, beziers, filled/stroked
+/// figures), drawable via [`Context::fill_geometry`](crate::Context::fill_geometry)
+/// and [`Context::draw_geometry`](crate::Context::draw_geometry). Build one
+/// via [`D2DFactory::make_path_geometry`].
+///
+/// Unlike [`crate::SolidColorBrush`], this is a factory resource rather than
+/// a device resource - it can be built once and reused across frames and
+/// render targets, with no generation tracking required.
+pub struct PathGeometry {
+    inner: ID2D1PathGeometry,
+}
+
+impl PathGeometry {
+    pub(crate) fn device_geometry(&self) -> &ID2D1Geometry {
+        (&self.inner).into()
+    }
+}
+
+impl D2DFactory {
+    /// Starts building a new [`PathGeometry`]. Add figures via the returned
+    /// [`PathGeometryBuilder`], then finalize with
+    /// [`PathGeometryBuilder::build`].
+    pub fn make_path_geometry(&self) -> Result<PathGeometryBuilder> {
+        let geometry = check_res(
+            || unsafe { self.inner().CreatePathGeometry() },
+            "ID2D1Factory::CreatePathGeometry",
+            (file!(), line!()),
+        )?;
+
+        PathGeometryBuilder::new(geometry)
+    }
+}
@@ -58,6 +58,7 @@ impl<'t> Context<'t> {
                     .CreateSolidColorBrush(color.as_d2d1_color(), Some(&brush_props as _))
             },
             "CreateSolidColorBrush",
+            (file!(), line!()),
         )
         .expect("failed to create brush for put_pixel");
 
@@ -82,6 +83,11 @@ impl<'t> Drop for Context<'t> {
     /// Drops the context, automatically committing the batched drawing
     /// commands.
     fn drop(&mut self) {
-        check_res(|| unsafe { self.tgt().EndDraw(None, None) }, "EndDraw").unwrap();
+        check_res(
+            || unsafe { self.tgt().EndDraw(None, None) },
+            "EndDraw",
+            (file!(), line!()),
+        )
+        .unwrap();
     }
 }
\ No newline at end of file
@@ -48,6 +48,7 @@ impl RenderTarget {
                 d2d_factory.CreateHwndRenderTarget(&render_props as _, &hwnd_target_props as _)
             },
             "CreateHwndRenderTarget",
+            (file!(), line!()),
         )?;
 
         Ok(Self {
@@ -0,0 +1,323 @@
+//! WIC-based image decoding and drawing bitmaps onto a [`RenderTarget`].
+
+use crate::{DeviceResource, RenderTarget};
+use ::std::rc::Rc;
+use ::widestring::U16CString;
+use ::win32::{
+    errors::{Error, Result},
+    invoke::check_res,
+};
+use ::win_geom::d2::{Rect2D, Size2D};
+use ::windows::{
+    core::{IStream, PCWSTR},
+    Win32::{
+        Graphics::{
+            Direct2D::{
+                Common::{
+                    D2D1_ALPHA_MODE_PREMULTIPLIED, D2D1_ALPHA_MODE_STRAIGHT, D2D1_PIXEL_FORMAT,
+                },
+                ID2D1Bitmap, D2D1_BITMAP_PROPERTIES,
+            },
+            Dxgi::Common::DXGI_FORMAT_B8G8R8A8_UNORM,
+            Imaging::{
+                CLSID_WICImagingFactory, GUID_WICPixelFormat32bppPBGRA, IWICBitmapDecoder,
+                IWICFormatConverter, IWICImagingFactory, WICBitmapDitherTypeNone,
+                WICBitmapPaletteTypeMedianCut, WICDecodeMetadataCacheOnLoad,
+            },
+        },
+        System::Com::{CoCreateInstance, SHCreateMemStream, CLSCTX_INPROC_SERVER},
+    },
+};
+
+/// A WIC (Windows Imaging Component) factory - the starting point for
+/// decoding raster images from disk or memory.
+pub struct ImagingFactory {
+    inner: IWICImagingFactory,
+}
+
+impl ImagingFactory {
+    /// Creates a new factory from which images can be decoded.
+    pub fn new() -> Result<Rc<Self>> {
+        let inner: IWICImagingFactory = check_res(
+            || unsafe { CoCreateInstance(&CLSID_WICImagingFactory, None, CLSCTX_INPROC_SERVER) },
+            "CoCreateInstance(CLSID_WICImagingFactory)",
+            (file!(), line!()),
+        )?;
+
+        Ok(Rc::new(Self { inner }))
+    }
+
+    /// Decodes an image (PNG, JPEG, BMP, etc) from a file path, converting it
+    /// to 32bpp PBGRA so it's immediately usable as a Direct2D bitmap source.
+    pub fn decode_file(&self, path: impl AsRef<::std::path::Path>) -> Result<DecodedImage> {
+        let path = U16CString::from_os_str(path.as_ref())
+            .expect("Image path contained a null character");
+
+        let decoder: IWICBitmapDecoder = check_res(
+            || unsafe {
+                self.inner.CreateDecoderFromFilename(
+                    PCWSTR::from_raw(path.as_ptr()),
+                    None,
+                    ::windows::Win32::Storage::FileSystem::GENERIC_READ,
+                    WICDecodeMetadataCacheOnLoad,
+                )
+            },
+            "IWICImagingFactory::CreateDecoderFromFilename",
+            (file!(), line!()),
+        )?;
+
+        self.convert_first_frame(decoder)
+    }
+
+    /// Decodes an image (PNG, JPEG, BMP, etc) from an in-memory byte slice,
+    /// converting it to 32bpp PBGRA so it's immediately usable as a Direct2D
+    /// bitmap source.
+    pub fn decode_bytes(&self, bytes: &[u8]) -> Result<DecodedImage> {
+        let stream: IStream = unsafe { SHCreateMemStream(Some(bytes)) }.ok_or_else(|| {
+            Error::Unexpected {
+                function: "SHCreateMemStream",
+                context: ::windows::core::Error::from_win32(),
+                location: Some((file!(), line!())),
+            }
+        })?;
+
+        let decoder: IWICBitmapDecoder = check_res(
+            || unsafe {
+                self.inner
+                    .CreateDecoderFromStream(&stream, None, WICDecodeMetadataCacheOnLoad)
+            },
+            "IWICImagingFactory::CreateDecoderFromStream",
+            (file!(), line!()),
+        )?;
+
+        self.convert_first_frame(decoder)
+    }
+
+    fn convert_first_frame(&self, decoder: IWICBitmapDecoder) -> Result<DecodedImage> {
+        let frame = check_res(
+            || unsafe { decoder.GetFrame(0) },
+            "IWICBitmapDecoder::GetFrame",
+            (file!(), line!()),
+        )?;
+
+        let converter: IWICFormatConverter = check_res(
+            || unsafe { self.inner.CreateFormatConverter() },
+            "IWICImagingFactory::CreateFormatConverter",
+            (file!(), line!()),
+        )?;
+
+        check_res(
+            || unsafe {
+                converter.Initialize(
+                    &frame,
+                    &GUID_WICPixelFormat32bppPBGRA,
+                    WICBitmapDitherTypeNone,
+                    None,
+                    0.0,
+                    WICBitmapPaletteTypeMedianCut,
+                )
+            },
+            "IWICFormatConverter::Initialize",
+            (file!(), line!()),
+        )?;
+
+        Ok(DecodedImage { converter })
+    }
+}
+
+/// A decoded image, converted to 32bpp PBGRA and ready to be uploaded to a
+/// [`RenderTarget`] via [`RenderTarget::create_bitmap_from_image`].
+pub struct DecodedImage {
+    converter: IWICFormatConverter,
+}
+
+/// The source a [`Bitmap`]'s device bitmap was uploaded from, retained so it
+/// can be re-uploaded if the owning render target is lost.
+enum BitmapSource {
+    /// Uploaded from a decoded WIC image via
+    /// [`RenderTarget::create_bitmap_from_image`].
+    Image(IWICFormatConverter),
+    /// Uploaded from raw pixel bytes via
+    /// [`RenderTarget::create_bitmap_from_pixels`].
+    Pixels {
+        bytes: Rc<[u8]>,
+        size: Size2D<u32>,
+        format: ImageFormat,
+        stride: u32,
+    },
+}
+
+/// A device-dependent bitmap which can be drawn via
+/// [`Context::draw_bitmap`](crate::Context::draw_bitmap).
+///
+/// This is a device-specific resource and is tied to the [`RenderTarget`] by
+/// which it was created, like [`crate::SolidColorBrush`]: the source pixels
+/// are retained and automatically re-uploaded if the render target is lost.
+pub struct Bitmap {
+    source: BitmapSource,
+    inner: ID2D1Bitmap,
+    generation: usize,
+}
+
+impl Bitmap {
+    pub(crate) fn device_bitmap(&self) -> &ID2D1Bitmap {
+        &self.inner
+    }
+}
+
+impl DeviceResource for Bitmap {
+    fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn recreate_if_needed(&mut self, render_target: &mut RenderTarget) {
+        if self.generation() != render_target.generation() {
+            self.inner = match &self.source {
+                BitmapSource::Image(converter) => {
+                    render_target.create_bitmap_device_bitmap(converter)
+                }
+                BitmapSource::Pixels { bytes, size, format, stride } => {
+                    render_target.create_pixel_device_bitmap(bytes, *size, *format, *stride)
+                }
+            }
+            .expect("Failed to re-upload Direct2D bitmap after device loss");
+
+            self.generation = render_target.generation();
+        }
+    }
+}
+
+/// Pixel layout of raw bytes passed to
+/// [`RenderTarget::create_bitmap_from_pixels`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// 8 bits per channel BGRA, with color channels already multiplied by
+    /// alpha. Required by most Direct2D drawing operations.
+    Bgra8Premultiplied,
+    /// 8 bits per channel BGRA, with color channels independent of alpha.
+    Bgra8Straight,
+}
+
+impl From<ImageFormat> for D2D1_PIXEL_FORMAT {
+    fn from(val: ImageFormat) -> Self {
+        let alpha_mode = match val {
+            ImageFormat::Bgra8Premultiplied => D2D1_ALPHA_MODE_PREMULTIPLIED,
+            ImageFormat::Bgra8Straight => D2D1_ALPHA_MODE_STRAIGHT,
+        };
+
+        D2D1_PIXEL_FORMAT { format: DXGI_FORMAT_B8G8R8A8_UNORM, alphaMode: alpha_mode }
+    }
+}
+
+/// Interpolation mode used when scaling a [`Bitmap`] to its destination
+/// rectangle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Nearest-neighbor sampling. Cheapest, but blocky when scaled up.
+    NearestNeighbor,
+    /// Bilinear sampling. Smoother, and the usual default.
+    Linear,
+}
+
+impl From<Interpolation> for ::windows::Win32::Graphics::Direct2D::D2D1_BITMAP_INTERPOLATION_MODE {
+    fn from(val: Interpolation) -> Self {
+        use ::windows::Win32::Graphics::Direct2D::{
+            D2D1_BITMAP_INTERPOLATION_MODE_LINEAR, D2D1_BITMAP_INTERPOLATION_MODE_NEAREST_NEIGHBOR,
+        };
+        match val {
+            Interpolation::NearestNeighbor => D2D1_BITMAP_INTERPOLATION_MODE_NEAREST_NEIGHBOR,
+            Interpolation::Linear => D2D1_BITMAP_INTERPOLATION_MODE_LINEAR,
+        }
+    }
+}
+
+impl RenderTarget {
+    /// Uploads a [`DecodedImage`] to this render target as a device-dependent
+    /// [`Bitmap`], ready to be drawn via
+    /// [`Context::draw_bitmap`](crate::Context::draw_bitmap).
+    pub fn create_bitmap_from_image(&mut self, image: &DecodedImage) -> Result<Bitmap> {
+        let inner = self.create_bitmap_device_bitmap(&image.converter)?;
+
+        Ok(Bitmap {
+            source: BitmapSource::Image(image.converter.clone()),
+            inner,
+            generation: self.generation(),
+        })
+    }
+
+    /// Uploads raw pixel bytes, laid out as `format` with the given `stride`
+    /// (bytes per row, which may include padding beyond `size.width *
+    /// bytes-per-pixel`), to this render target as a device-dependent
+    /// [`Bitmap`], ready to be drawn via
+    /// [`Context::draw_bitmap`](crate::Context::draw_bitmap).
+    pub fn create_bitmap_from_pixels(
+        &mut self,
+        bytes: &[u8],
+        size: Size2D<u32>,
+        format: ImageFormat,
+        stride: u32,
+    ) -> Result<Bitmap> {
+        let bytes: Rc<[u8]> = Rc::from(bytes);
+        let inner = self.create_pixel_device_bitmap(&bytes, size, format, stride)?;
+
+        Ok(Bitmap {
+            source: BitmapSource::Pixels { bytes, size, format, stride },
+            inner,
+            generation: self.generation(),
+        })
+    }
+
+    /// Uploads `converter`'s decoded pixels as a raw `ID2D1Bitmap`. Shared by
+    /// [`Self::create_bitmap_from_image`] and [`Bitmap`]'s device-loss
+    /// recreation.
+    fn create_bitmap_device_bitmap(&self, converter: &IWICFormatConverter) -> Result<ID2D1Bitmap> {
+        check_res(
+            || unsafe {
+                self.device_target_for_resource_creation()
+                    .CreateBitmapFromWicBitmap(converter, None)
+            },
+            "ID2D1RenderTarget::CreateBitmapFromWicBitmap",
+            (file!(), line!()),
+        )
+    }
+
+    /// Uploads raw pixel bytes as a raw `ID2D1Bitmap`. Shared by
+    /// [`Self::create_bitmap_from_pixels`] and [`Bitmap`]'s device-loss
+    /// recreation.
+    fn create_pixel_device_bitmap(
+        &self,
+        bytes: &[u8],
+        size: Size2D<u32>,
+        format: ImageFormat,
+        stride: u32,
+    ) -> Result<ID2D1Bitmap> {
+        let props = D2D1_BITMAP_PROPERTIES { pixelFormat: format.into(), dpiX: 96.0, dpiY: 96.0 };
+
+        check_res(
+            || unsafe {
+                self.device_target_for_resource_creation().CreateBitmap(
+                    size.into(),
+                    Some(bytes.as_ptr() as _),
+                    stride,
+                    &props as _,
+                )
+            },
+            "ID2D1RenderTarget::CreateBitmap",
+            (file!(), line!()),
+        )
+    }
+
+    /// Draws `bitmap` into `dest`, scaling to fit using `interpolation`.
+    pub fn draw_bitmap(
+        &mut self,
+        bitmap: &mut Bitmap,
+        dest: Rect2D<f32>,
+        opacity: f32,
+        interpolation: Interpolation,
+        src: Option<Rect2D<f32>>,
+    ) {
+        let mut ctx = self.begin_draw();
+        ctx.draw_bitmap(bitmap, dest, opacity, interpolation, src);
+        ctx.end_draw();
+    }
+}
@@ -15,12 +15,26 @@
     )
 )]
 
+pub mod brushes;
+pub(crate) use brushes::DeviceResource;
 mod color;
+mod composition;
 mod context;
 mod factory;
+mod geometry;
+mod imaging;
+mod stroke_style;
 mod target;
+mod text;
+mod wic_target;
 
 pub use color::*;
+pub use composition::*;
 pub use context::*;
 pub use factory::*;
+pub use geometry::*;
+pub use imaging::*;
+pub use stroke_style::*;
 pub use target::*;
+pub use text::*;
+pub use wic_target::*;
@@ -0,0 +1,201 @@
+//! Configurable stroke styles (dash patterns, caps, and joins) for the
+//! stroke-taking [`Context`](crate::Context) methods.
+
+use ::win32::{errors::Result, invoke::check_res};
+use ::windows::Win32::Graphics::Direct2D::{
+    ID2D1StrokeStyle, D2D1_CAP_STYLE, D2D1_CAP_STYLE_FLAT, D2D1_CAP_STYLE_ROUND,
+    D2D1_CAP_STYLE_SQUARE, D2D1_CAP_STYLE_TRIANGLE, D2D1_DASH_STYLE, D2D1_DASH_STYLE_CUSTOM,
+    D2D1_DASH_STYLE_DASH, D2D1_DASH_STYLE_DASH_DOT, D2D1_DASH_STYLE_DASH_DOT_DOT,
+    D2D1_DASH_STYLE_DOT, D2D1_DASH_STYLE_SOLID, D2D1_LINE_JOIN, D2D1_LINE_JOIN_BEVEL,
+    D2D1_LINE_JOIN_MITER, D2D1_LINE_JOIN_MITER_OR_BEVEL, D2D1_LINE_JOIN_ROUND,
+    D2D1_STROKE_STYLE_PROPERTIES,
+};
+
+use crate::D2DFactory;
+
+/// The shape drawn at the ends of an open stroke (e.g. the endpoints of a
+/// line, or the unjoined ends of a dash).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CapStyle {
+    /// The stroke ends flush with its endpoint.
+    Flat,
+    /// The stroke ends with a square that extends past its endpoint by half
+    /// the stroke width.
+    Square,
+    /// The stroke ends with a semicircle that extends past its endpoint by
+    /// half the stroke width.
+    Round,
+    /// The stroke ends with a triangle that extends past its endpoint by
+    /// half the stroke width.
+    Triangle,
+}
+
+impl From<CapStyle> for D2D1_CAP_STYLE {
+    fn from(val: CapStyle) -> Self {
+        match val {
+            CapStyle::Flat => D2D1_CAP_STYLE_FLAT,
+            CapStyle::Square => D2D1_CAP_STYLE_SQUARE,
+            CapStyle::Round => D2D1_CAP_STYLE_ROUND,
+            CapStyle::Triangle => D2D1_CAP_STYLE_TRIANGLE,
+        }
+    }
+}
+
+/// The shape drawn where two stroke segments meet, e.g. at the corner of a
+/// stroked rectangle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    /// A sharp corner, unless the corner would be too sharp (beyond
+    /// `miter_limit`), in which case it's beveled instead.
+    Miter,
+    /// A corner with the outer edge flattened off ("cut").
+    Bevel,
+    /// A rounded corner.
+    Round,
+    /// Like [`Self::Miter`], but always falls back to a plain bevel rather
+    /// than Direct2D's miter-limit fallback behavior.
+    MiterOrBevel,
+}
+
+impl From<LineJoin> for D2D1_LINE_JOIN {
+    fn from(val: LineJoin) -> Self {
+        match val {
+            LineJoin::Miter => D2D1_LINE_JOIN_MITER,
+            LineJoin::Bevel => D2D1_LINE_JOIN_BEVEL,
+            LineJoin::Round => D2D1_LINE_JOIN_ROUND,
+            LineJoin::MiterOrBevel => D2D1_LINE_JOIN_MITER_OR_BEVEL,
+        }
+    }
+}
+
+/// The dash pattern used along the length of a stroke.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DashStyle {
+    /// No dashes; a continuous line.
+    Solid,
+    /// Evenly spaced dashes.
+    Dash,
+    /// Evenly spaced dots.
+    Dot,
+    /// Alternating dashes and dots.
+    DashDot,
+    /// Alternating dashes and pairs of dots.
+    DashDotDot,
+    /// A custom dash pattern, given by the `dashes` argument to
+    /// [`D2DFactory::make_stroke_style`].
+    Custom,
+}
+
+impl From<DashStyle> for D2D1_DASH_STYLE {
+    fn from(val: DashStyle) -> Self {
+        match val {
+            DashStyle::Solid => D2D1_DASH_STYLE_SOLID,
+            DashStyle::Dash => D2D1_DASH_STYLE_DASH,
+            DashStyle::Dot => D2D1_DASH_STYLE_DOT,
+            DashStyle::DashDot => D2D1_DASH_STYLE_DASH_DOT,
+            DashStyle::DashDotDot => D2D1_DASH_STYLE_DASH_DOT_DOT,
+            DashStyle::Custom => D2D1_DASH_STYLE_CUSTOM,
+        }
+    }
+}
+
+/// Describes a [`StrokeStyle`] to be created via
+/// [`D2DFactory::make_stroke_style`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StrokeStyleProperties {
+    /// Cap style applied to the start of each open figure.
+    pub start_cap: CapStyle,
+    /// Cap style applied to the end of each open figure.
+    pub end_cap: CapStyle,
+    /// Cap style applied to both ends of each dash.
+    pub dash_cap: CapStyle,
+    /// Join style applied where two stroke segments meet.
+    pub line_join: LineJoin,
+    /// The limit on the ratio of miter length to stroke width before a
+    /// [`LineJoin::Miter`] join is beveled instead. Ignored for other line
+    /// joins.
+    pub miter_limit: f32,
+    /// The dash pattern used along the stroke. [`DashStyle::Custom`]
+    /// requires a non-empty `dashes` array to be passed to
+    /// [`D2DFactory::make_stroke_style`].
+    pub dash_style: DashStyle,
+    /// An offset into the dash pattern at which the first dash begins.
+    pub dash_offset: f32,
+}
+
+impl Default for StrokeStyleProperties {
+    fn default() -> Self {
+        Self {
+            start_cap: CapStyle::Flat,
+            end_cap: CapStyle::Flat,
+            dash_cap: CapStyle::Flat,
+            line_join: LineJoin::Miter,
+            miter_limit: 10.0,
+            dash_style: DashStyle::Solid,
+            dash_offset: 0.0,
+        }
+    }
+}
+
+/// A stroke style (dash pattern, caps, and joins) usable by the
+/// stroke-taking [`Context`](crate::Context) methods, e.g.
+/// [`Context::draw_line`](crate::Context::draw_line).
+///
+/// Unlike [`crate::SolidColorBrush`], this is a factory resource rather than
+/// a device resource - it doesn't depend on the render target and needs no
+/// generation tracking.
+pub struct StrokeStyle {
+    inner: ID2D1StrokeStyle,
+}
+
+impl StrokeStyle {
+    pub(crate) fn device_stroke_style(&self) -> &ID2D1StrokeStyle {
+        &self.inner
+    }
+}
+
+impl D2DFactory {
+    /// Creates a new [`StrokeStyle`] from `properties`, with an optional
+    /// custom dash pattern (required when `properties.dash_style` is
+    /// [`DashStyle::Custom`], ignored otherwise).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::d2d::{D2DFactory, DashStyle, StrokeStyleProperties};
+    ///
+    /// let factory = D2DFactory::new().unwrap();
+    /// let dashed = factory
+    ///     .make_stroke_style(
+    ///         StrokeStyleProperties {
+    ///             dash_style: DashStyle::Dash,
+    ///             ..Default::default()
+    ///         },
+    ///         &[],
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn make_stroke_style(
+        &self,
+        properties: StrokeStyleProperties,
+        dashes: &[f32],
+    ) -> Result<StrokeStyle> {
+        let device_props = D2D1_STROKE_STYLE_PROPERTIES {
+            startCap: properties.start_cap.into(),
+            endCap: properties.end_cap.into(),
+            dashCap: properties.dash_cap.into(),
+            lineJoin: properties.line_join.into(),
+            miterLimit: properties.miter_limit,
+            dashStyle: properties.dash_style.into(),
+            dashOffset: properties.dash_offset,
+        };
+
+        let inner = check_res(
+            || unsafe { self.inner().CreateStrokeStyle(&device_props as _, Some(dashes)) },
+            "ID2D1Factory::CreateStrokeStyle",
+            (file!(), line!()),
+        )?;
+
+        Ok(StrokeStyle { inner })
+    }
+}
@@ -1,12 +1,101 @@
-use crate::{context::Context, factory::D2DFactory};
+use crate::{
+    brushes::{ExtendMode, GradientStop, LinearGradientBrush, RadialGradientBrush, SolidColorBrush},
+    color::Color,
+    context::Context,
+    factory::D2DFactory,
+};
 use ::std::rc::Rc;
-use ::win32::invoke::check_res;
-use ::win_geom::d2::Size2D;
-use ::windows::Win32::{
-    Foundation::{D2DERR_RECREATE_TARGET, HWND},
-    Graphics::Direct2D::ID2D1HwndRenderTarget,
+use ::win32::{invoke::check_res, window::DPI};
+use ::win_geom::d2::{Point2D, Size2D};
+use ::windows::{
+    Foundation::Numerics::Matrix3x2,
+    Win32::{
+        Foundation::{D2DERR_RECREATE_TARGET, HWND},
+        Graphics::Direct2D::{
+            Common::D2D1_COLOR_F, ID2D1GradientStopCollection, ID2D1HwndRenderTarget,
+            ID2D1SolidColorBrush, D2D1_BRUSH_PROPERTIES, D2D1_GAMMA_2_2, D2D1_GRADIENT_STOP,
+            D2D1_LINEAR_GRADIENT_BRUSH_PROPERTIES, D2D1_RADIAL_GRADIENT_BRUSH_PROPERTIES,
+        },
+    },
 };
 
+/// Number of four-way associative sets in [`RenderTarget`]'s brush cache,
+/// giving a total capacity of `BRUSH_CACHE_SETS * BRUSH_CACHE_WAYS` cached
+/// brushes.
+const BRUSH_CACHE_SETS: usize = 256;
+
+/// Number of brushes held per set in [`RenderTarget`]'s brush cache, evicted
+/// round-robin on a miss.
+const BRUSH_CACHE_WAYS: usize = 4;
+
+/// A small, fixed-capacity cache of solid-color brushes, keyed by a
+/// quantized [`Color`]. Used by immediate-mode, per-pixel/per-primitive draw
+/// calls (e.g. [`Context::put_pixel`]) so that repeatedly drawing with the
+/// same handful of colors doesn't allocate a fresh device brush on every
+/// call.
+///
+/// Four-way set associative: the quantized color is hashed down to one of
+/// [`BRUSH_CACHE_SETS`] sets, and each set holds up to [`BRUSH_CACHE_WAYS`]
+/// brushes, evicted round-robin on a miss.
+#[derive(Default)]
+struct BrushCache {
+    sets: Vec<BrushCacheSet>,
+}
+
+#[derive(Default)]
+struct BrushCacheSet {
+    entries: [Option<(u32, ID2D1SolidColorBrush)>; BRUSH_CACHE_WAYS],
+    next_evict: usize,
+}
+
+impl BrushCache {
+    fn new() -> Self {
+        Self {
+            sets: (0..BRUSH_CACHE_SETS).map(|_| BrushCacheSet::default()).collect(),
+        }
+    }
+
+    /// Flushes every cached brush. Must be called whenever the render
+    /// target's generation increments, since cached device brushes from the
+    /// previous device are no longer valid.
+    fn flush(&mut self) {
+        for set in &mut self.sets {
+            *set = BrushCacheSet::default();
+        }
+    }
+
+    fn get(&self, key: u32) -> Option<ID2D1SolidColorBrush> {
+        self.sets[Self::set_index(key)].entries.iter().find_map(|entry| match entry {
+            Some((entry_key, brush)) if *entry_key == key => Some(brush.clone()),
+            _ => None,
+        })
+    }
+
+    fn insert(&mut self, key: u32, brush: ID2D1SolidColorBrush) {
+        let set = &mut self.sets[Self::set_index(key)];
+        let way = set.next_evict;
+        set.entries[way] = Some((key, brush));
+        set.next_evict = (way + 1) % BRUSH_CACHE_WAYS;
+    }
+
+    /// Quantizes `color`'s four channels to 8 bits each and packs them into a
+    /// single key, used both to index into [`Self::sets`] and to identify
+    /// cache hits.
+    fn quantize(color: Color) -> u32 {
+        let color: D2D1_COLOR_F = color.into();
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u32;
+
+        (channel(color.r) << 24)
+            | (channel(color.g) << 16)
+            | (channel(color.b) << 8)
+            | channel(color.a)
+    }
+
+    fn set_index(key: u32) -> usize {
+        (key as usize) % BRUSH_CACHE_SETS
+    }
+}
+
 /// Renders drawing instructions to a window.
 ///
 /// You must call [`begin_draw`] before issuing drawing commands to receive a
@@ -22,6 +111,10 @@ use ::windows::Win32::{
 /// batch flushed by [`end_draw`], then the buffer is not presented, and the
 /// application must call [`begin_draw`] and re-draw the frame.
 ///
+/// Most callers should prefer [`Self::draw`], which handles this recreate-
+/// and-retry dance automatically rather than hand-rolling it around
+/// [`begin_draw`]/[`end_draw`].
+///
 /// # Example
 ///
 /// ```no_run
@@ -31,13 +124,13 @@ use ::windows::Win32::{
 /// use ::d2d::{D2DFactory, Color};
 ///
 /// # let hwnd = HWND(0);
-/// # let size = Size2D { width: 100, height: 100 };
+/// # let size = Size2D::new(100, 100);
 /// let factory = D2DFactory::new().unwrap();
 /// let mut render_target = factory.make_render_target(hwnd, size);
 ///
 /// let ctx = render_target.begin_draw();
 /// ctx.clear(Color::blue());
-/// ctx.put_pixel(Point2D { x: 10.0, y: 10.0 }, Color::red());
+/// ctx.put_pixel(Point2D::new(10.0, 10.0), Color::red());
 /// ctx.end_draw();
 /// ```
 ///
@@ -78,16 +171,297 @@ impl RenderTarget {
     /// Ends drawing operations on the render target causing the changes to
     /// become visible and the render target to become ready for the next
     /// [`Self::begin_draw`] call.
-    pub(crate) fn end_draw(&mut self, device_target: ID2D1HwndRenderTarget) {
-        let must_recreate =
-            match check_res(|| unsafe { device_target.EndDraw(None, None) }, "EndDraw") {
-                Err(e) if e.code() == Some(D2DERR_RECREATE_TARGET) => true,
-                Err(e) => panic!("Unexpected error in Direct2D EndDraw(): {e}"),
-                Ok(_) => false,
-            };
+    ///
+    /// Returns `true` if the frame was presented, or `false` if Direct2D
+    /// reported `D2DERR_RECREATE_TARGET`, in which case device-specific
+    /// resources will be re-created on the following [`Self::begin_draw`]
+    /// call.
+    pub(crate) fn end_draw(&mut self, device_target: ID2D1HwndRenderTarget) -> bool {
+        let must_recreate = match check_res(
+            || unsafe { device_target.EndDraw(None, None) },
+            "EndDraw",
+            (file!(), line!()),
+        ) {
+            Err(e) if e.code() == Some(D2DERR_RECREATE_TARGET) => true,
+            Err(e) => panic!("Unexpected error in Direct2D EndDraw(): {e}"),
+            Ok(_) => false,
+        };
 
         self.state = ::std::mem::replace(&mut self.state, State::Poisoned)
             .end_draw(must_recreate, device_target);
+
+        !must_recreate
+    }
+
+    /// Draws a single frame via `f`, automatically recovering from device
+    /// loss.
+    ///
+    /// This is a convenience wrapper around [`Self::begin_draw`] and
+    /// [`Context::end_draw`]: if Direct2D reports `D2DERR_RECREATE_TARGET`
+    /// when the batch is flushed, the device render target and any
+    /// device-dependent resources (brushes, bitmaps, ...) are discarded and
+    /// re-created, and `f` is re-run once against the fresh target so the
+    /// frame is never silently dropped because of a hardware reset.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ::win_geom::d2::Size2D;
+    /// # use ::windows::Win32::Foundation::HWND;
+    /// # use ::d2d::{D2DFactory, Color};
+    /// # let factory = D2DFactory::new().unwrap();
+    /// # let mut render_target = factory.make_render_target(
+    /// #     HWND(0),
+    /// #     Size2D::new(100, 100),
+    /// # );
+    /// render_target.draw(|ctx| {
+    ///     ctx.clear(Color::blue());
+    /// });
+    /// ```
+    pub fn draw(&mut self, mut f: impl FnMut(&mut Context<'_>)) {
+        for _ in 0..2 {
+            let mut ctx = self.begin_draw();
+            f(&mut ctx);
+
+            if ctx.end_draw_retryable() {
+                return;
+            }
+        }
+    }
+
+    /// Creates a new solid-color brush bound to this render target.
+    pub fn make_solid_color_brush(&self, color: Color) -> SolidColorBrush {
+        let device_brush = self.create_solid_color_device_brush(color);
+
+        SolidColorBrush::new(color, device_brush, self.generation())
+    }
+
+    /// Creates a raw `ID2D1SolidColorBrush`, without wrapping it in a
+    /// [`SolidColorBrush`]. Shared by [`Self::make_solid_color_brush`] and
+    /// [`Self::cached_brush`].
+    fn create_solid_color_device_brush(&self, color: Color) -> ID2D1SolidColorBrush {
+        check_res(
+            || unsafe {
+                self.device_target_for_resource_creation().CreateSolidColorBrush(
+                    &color.into(),
+                    Some(&Self::opaque_brush_properties() as _),
+                )
+            },
+            "CreateSolidColorBrush",
+            (file!(), line!()),
+        )
+        .expect("Failed to create Direct2D solid color brush")
+    }
+
+    /// Creates a new linear gradient brush, transitioning through `stops`
+    /// along the axis from `start` to `end`. `extend_mode` controls how the
+    /// area beyond the first/last stop is painted.
+    pub fn make_linear_gradient_brush(
+        &self,
+        stops: Vec<GradientStop>,
+        start: Point2D<f32>,
+        end: Point2D<f32>,
+        extend_mode: ExtendMode,
+    ) -> LinearGradientBrush {
+        let stop_collection = self.make_gradient_stop_collection(&stops, extend_mode);
+
+        let props = D2D1_LINEAR_GRADIENT_BRUSH_PROPERTIES {
+            startPoint: start.into(),
+            endPoint: end.into(),
+        };
+        let device_brush = check_res(
+            || unsafe {
+                self.device_target_for_resource_creation().CreateLinearGradientBrush(
+                    &props as _,
+                    Some(&Self::opaque_brush_properties() as _),
+                    &stop_collection,
+                )
+            },
+            "CreateLinearGradientBrush",
+            (file!(), line!()),
+        )
+        .expect("Failed to create Direct2D linear gradient brush");
+
+        LinearGradientBrush::new(stops, start, end, extend_mode, device_brush, self.generation())
+    }
+
+    /// Creates a new radial gradient brush, transitioning through `stops`
+    /// from `center` (offset by `gradient_origin_offset`) out to the
+    /// ellipse described by `radius_x`/`radius_y`. `extend_mode` controls how
+    /// the area beyond the first/last stop is painted.
+    #[allow(clippy::too_many_arguments)]
+    pub fn make_radial_gradient_brush(
+        &self,
+        stops: Vec<GradientStop>,
+        center: Point2D<f32>,
+        gradient_origin_offset: Point2D<f32>,
+        radius_x: f32,
+        radius_y: f32,
+        extend_mode: ExtendMode,
+    ) -> RadialGradientBrush {
+        let stop_collection = self.make_gradient_stop_collection(&stops, extend_mode);
+
+        let props = D2D1_RADIAL_GRADIENT_BRUSH_PROPERTIES {
+            center: center.into(),
+            gradientOriginOffset: gradient_origin_offset.into(),
+            radiusX: radius_x,
+            radiusY: radius_y,
+        };
+        let device_brush = check_res(
+            || unsafe {
+                self.device_target_for_resource_creation().CreateRadialGradientBrush(
+                    &props as _,
+                    Some(&Self::opaque_brush_properties() as _),
+                    &stop_collection,
+                )
+            },
+            "CreateRadialGradientBrush",
+            (file!(), line!()),
+        )
+        .expect("Failed to create Direct2D radial gradient brush");
+
+        RadialGradientBrush::new(
+            stops,
+            center,
+            gradient_origin_offset,
+            radius_x,
+            radius_y,
+            extend_mode,
+            device_brush,
+            self.generation(),
+        )
+    }
+
+    /// Builds the device-specific stop collection shared by both gradient
+    /// brush flavors.
+    fn make_gradient_stop_collection(
+        &self,
+        stops: &[GradientStop],
+        extend_mode: ExtendMode,
+    ) -> ID2D1GradientStopCollection {
+        let device_stops: Vec<D2D1_GRADIENT_STOP> = stops
+            .iter()
+            .map(|stop| D2D1_GRADIENT_STOP {
+                position: stop.position,
+                color: stop.color.into(),
+            })
+            .collect();
+
+        check_res(
+            || unsafe {
+                self.device_target_for_resource_creation().CreateGradientStopCollection(
+                    &device_stops,
+                    D2D1_GAMMA_2_2,
+                    extend_mode.into(),
+                )
+            },
+            "CreateGradientStopCollection",
+            (file!(), line!()),
+        )
+        .expect("Failed to create Direct2D gradient stop collection")
+    }
+
+    /// Default brush properties (fully opaque, identity transform) shared by
+    /// all brush constructors.
+    fn opaque_brush_properties() -> D2D1_BRUSH_PROPERTIES {
+        D2D1_BRUSH_PROPERTIES {
+            opacity: 1.0,
+            transform: Matrix3x2::identity(),
+        }
+    }
+
+    /// Notifies the render target that the window's client area has been
+    /// resized, e.g. in response to `WM_SIZE`.
+    ///
+    /// If device-specific resources already exist, the underlying
+    /// `ID2D1HwndRenderTarget` is resized in place. Otherwise the new size is
+    /// simply recorded and picked up the next time the target is
+    /// (re-)created.
+    pub fn resize(&mut self, size: Size2D<i32>) {
+        let inner = match &mut self.state {
+            State::Created { inner, target } => {
+                let dpi = DPI::detect(inner.hwnd);
+                let pixel_size = dpi.scale_size(size).cast::<u32>();
+                check_res(
+                    || unsafe { target.Resize(&pixel_size.into() as _) },
+                    "Resize",
+                    (file!(), line!()),
+                )
+                .expect("Failed to resize Direct2D render target");
+                inner
+            }
+            State::Drawing { inner } => inner,
+            State::RequiresRecreation { inner } => inner,
+            State::Poisoned => panic!("Render target state poisoned"),
+        };
+
+        inner.size = size;
+    }
+
+    /// The render target's current size, in DIPs. Reflects the most recent
+    /// [`Self::resize`] call, so drawing code can lay out against the
+    /// window's actual client area rather than a size captured at creation
+    /// time.
+    pub(crate) fn size(&self) -> Size2D<i32> {
+        match &self.state {
+            State::Created { inner, .. } => inner.size,
+            State::Drawing { inner } => inner.size,
+            State::RequiresRecreation { inner } => inner.size,
+            State::Poisoned => panic!("Render target state poisoned"),
+        }
+    }
+
+    /// The DPI of the window this render target draws into, queried fresh
+    /// from the window each time so it stays correct across a
+    /// `WM_DPICHANGED` without the render target needing to be notified
+    /// separately.
+    pub(crate) fn dpi(&self) -> DPI {
+        DPI::detect(self.hwnd())
+    }
+
+    /// The `HWND` backing this render target, common to every state.
+    fn hwnd(&self) -> HWND {
+        match &self.state {
+            State::Created { inner, .. } => inner.hwnd,
+            State::Drawing { inner } => inner.hwnd,
+            State::RequiresRecreation { inner } => inner.hwnd,
+            State::Poisoned => panic!("Render target state poisoned"),
+        }
+    }
+
+    /// The current generation of device-specific resources. Bumped every
+    /// time the device render target is (re-)created, so that
+    /// device-dependent resources (brushes, bitmaps, ...) can tell when
+    /// they must be re-created by comparing against the generation they
+    /// were stamped with.
+    pub(crate) fn generation(&self) -> usize {
+        match &self.state {
+            State::Created { inner, .. } => inner.generation,
+            State::Drawing { inner } => inner.generation,
+            State::RequiresRecreation { inner } => inner.generation,
+            State::Poisoned => panic!("Render target state poisoned"),
+        }
+    }
+
+    /// Crate-private access to the current device render target, for
+    /// resource creation (bitmaps, brushes, etc) that doesn't require an
+    /// active `BeginDraw`/`EndDraw` batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the render target is mid-recreation (i.e. has not yet been
+    /// drawn to for the first time) or poisoned. Callers should go through
+    /// [`Self::begin_draw`] at least once before creating device-dependent
+    /// resources.
+    pub(crate) fn device_target_for_resource_creation(&self) -> &ID2D1HwndRenderTarget {
+        match &self.state {
+            State::Created { target, .. } => target,
+            State::Drawing { .. } => panic!("Cannot create resources while mid-draw"),
+            State::RequiresRecreation { .. } => {
+                panic!("Render target must be drawn to at least once before creating resources")
+            }
+            State::Poisoned => panic!("Render target state poisoned"),
+        }
     }
 
     /// Crate-internal constructor, called by the [`Factory`](super::Factory).
@@ -98,10 +472,39 @@ impl RenderTarget {
                     factory: factory.clone(),
                     hwnd,
                     size,
+                    generation: 0,
+                    brush_cache: BrushCache::new(),
                 },
             },
         }
     }
+
+    /// Returns a cached solid-color brush for `color`, creating one only on
+    /// a cache miss, so that repeatedly drawing with the same handful of
+    /// colors (e.g. via [`Context::put_pixel`]) doesn't allocate a fresh
+    /// device brush on every call.
+    pub(crate) fn cached_brush(&mut self, color: Color) -> ID2D1SolidColorBrush {
+        let key = BrushCache::quantize(color);
+
+        if let Some(brush) = self.inner_mut().brush_cache.get(key) {
+            return brush;
+        }
+
+        let brush = self.create_solid_color_device_brush(color);
+        self.inner_mut().brush_cache.insert(key, brush.clone());
+        brush
+    }
+
+    /// Crate-private access to the `Inner` state shared across all states of
+    /// our state pattern render target.
+    fn inner_mut(&mut self) -> &mut Inner {
+        match &mut self.state {
+            State::Created { inner, .. } => inner,
+            State::Drawing { inner } => inner,
+            State::RequiresRecreation { inner } => inner,
+            State::Poisoned => panic!("Render target state poisoned"),
+        }
+    }
 }
 
 /// Inner components which are common to all states of our state pattern render
@@ -121,6 +524,16 @@ struct Inner {
 
     /// Size of both the window and the render target.
     size: Size2D<i32>,
+
+    /// Incremented every time device-specific resources are (re-)created.
+    /// Device-dependent resources (brushes, bitmaps, ...) stamp the
+    /// generation they were created against and compare it against this
+    /// value to know when they must be re-created.
+    generation: usize,
+
+    /// Cache of solid-color brushes used by immediate-mode draw calls. See
+    /// [`BrushCache`].
+    brush_cache: BrushCache,
 }
 
 /// The internal state of our render target, encapsulated as a state pattern.
@@ -155,12 +568,18 @@ impl State {
             Self::Poisoned => panic!("Render target state poisoned"),
             Self::Drawing { .. } => panic!("Render target should not be re-created mid-draw"),
             Self::Created { target, inner } => (Self::Drawing { inner }, target),
-            Self::RequiresRecreation { inner } => {
+            Self::RequiresRecreation { mut inner } => {
                 let target = inner
                     .factory
                     .make_device_render_target(inner.hwnd, inner.size)
                     .expect("Failed to create device resources for Direct2D render target");
 
+                // Device-dependent resources stamped with the previous
+                // generation are now invalid and must be re-created lazily
+                // the next time they're used.
+                inner.generation += 1;
+                inner.brush_cache.flush();
+
                 // Recurse
                 Self::Created { inner, target }.begin_draw()
             }
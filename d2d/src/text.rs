@@ -0,0 +1,170 @@
+//! DirectWrite text layout and measurement, for drawing text onto a
+//! [`RenderTarget`].
+
+use crate::{brushes::Brush, DeviceResource, RenderTarget};
+use ::std::rc::Rc;
+use ::widestring::U16CString;
+use ::win32::{errors::Result, invoke::check_res};
+use ::win_geom::d2::{Point2D, Size2D};
+use ::windows::Win32::Graphics::DirectWrite::{
+    DWriteCreateFactory, IDWriteFactory, IDWriteTextFormat, IDWriteTextLayout,
+    DWRITE_FACTORY_TYPE_SHARED, DWRITE_FONT_STRETCH_NORMAL, DWRITE_FONT_STYLE_NORMAL,
+    DWRITE_FONT_WEIGHT_NORMAL, DWRITE_PARAGRAPH_ALIGNMENT_NEAR, DWRITE_TEXT_ALIGNMENT_LEADING,
+};
+
+/// A DirectWrite factory - the starting point for measuring and laying out
+/// text.
+///
+/// Mirrors [`crate::D2DFactory`]: only one [`TextFactory`] should exist per
+/// thread, and it should live for the duration of the thread.
+pub struct TextFactory {
+    inner: IDWriteFactory,
+}
+
+impl TextFactory {
+    /// Creates a new factory from which [`TextFormat`] and [`TextLayout`]
+    /// objects can be created.
+    pub fn new() -> Result<Rc<Self>> {
+        let inner: IDWriteFactory = check_res(
+            || unsafe { DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED) },
+            "DWriteCreateFactory",
+            (file!(), line!()),
+        )?;
+
+        Ok(Rc::new(Self { inner }))
+    }
+
+    /// Creates a new [`TextFormat`] describing a font family, size, weight,
+    /// and style to lay text out with.
+    pub fn make_text_format(&self, font_family: &str, size: f32) -> Result<TextFormat> {
+        let font_family = U16CString::from_str(font_family).expect("Font family contained null");
+
+        let format: IDWriteTextFormat = check_res(
+            || unsafe {
+                self.inner.CreateTextFormat(
+                    ::windows::core::PCWSTR::from_raw(font_family.as_ptr()),
+                    None,
+                    DWRITE_FONT_WEIGHT_NORMAL,
+                    DWRITE_FONT_STYLE_NORMAL,
+                    DWRITE_FONT_STRETCH_NORMAL,
+                    size,
+                    ::windows::core::w!(""),
+                )
+            },
+            "IDWriteFactory::CreateTextFormat",
+            (file!(), line!()),
+        )?;
+
+        unsafe {
+            format.SetTextAlignment(DWRITE_TEXT_ALIGNMENT_LEADING).ok();
+            format
+                .SetParagraphAlignment(DWRITE_PARAGRAPH_ALIGNMENT_NEAR)
+                .ok();
+        }
+
+        Ok(TextFormat { inner: format })
+    }
+
+    /// Lays out `text` against `format`, wrapping within `max_size`.
+    pub fn make_text_layout(
+        &self,
+        text: &str,
+        format: &TextFormat,
+        max_size: Size2D<f32>,
+    ) -> Result<TextLayout> {
+        let text = U16CString::from_str(text).expect("Text contained null byte");
+
+        let inner: IDWriteTextLayout = check_res(
+            || unsafe {
+                self.inner.CreateTextLayout(
+                    text.as_slice(),
+                    &format.inner,
+                    max_size.width,
+                    max_size.height,
+                )
+            },
+            "IDWriteFactory::CreateTextLayout",
+            (file!(), line!()),
+        )?;
+
+        Ok(TextLayout { inner })
+    }
+}
+
+/// Describes the font family, size, weight, style, and alignment that text
+/// should be laid out with. Create via
+/// [`TextFactory::make_text_format`].
+pub struct TextFormat {
+    inner: IDWriteTextFormat,
+}
+
+/// A laid-out, measured run of text, ready to be drawn via
+/// [`RenderTarget::draw_text`]. Create via [`TextFactory::make_text_layout`].
+pub struct TextLayout {
+    inner: IDWriteTextLayout,
+}
+
+/// The measured dimensions of a [`TextLayout`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextMetrics {
+    /// The width and height of the formatted text, ignoring trailing
+    /// whitespace.
+    pub size: Size2D<f32>,
+    /// The number of text lines.
+    pub line_count: u32,
+}
+
+impl TextLayout {
+    /// Returns the measured metrics (size, line count) of the laid-out text.
+    pub fn metrics(&self) -> TextMetrics {
+        let metrics = unsafe { self.inner.GetMetrics() }.expect("GetMetrics should not fail");
+
+        TextMetrics {
+            size: Size2D::new(metrics.width, metrics.height),
+            line_count: metrics.lineCount,
+        }
+    }
+
+    pub(crate) fn device_layout(&self) -> &IDWriteTextLayout {
+        &self.inner
+    }
+}
+
+impl TextFormat {
+    pub(crate) fn device_format(&self) -> &IDWriteTextFormat {
+        &self.inner
+    }
+}
+
+impl RenderTarget {
+    /// Draws a pre-measured [`TextLayout`] at `origin` using `brush`.
+    pub fn draw_text<B>(&mut self, layout: &TextLayout, origin: Point2D<f32>, brush: &mut B)
+    where
+        B: Brush + DeviceResource,
+    {
+        let mut ctx = self.begin_draw();
+        ctx.draw_text_layout(layout, origin, brush);
+        ctx.end_draw();
+    }
+
+    /// Convenience method which formats, lays out, and draws `text` in one
+    /// call. Prefer [`Self::draw_text`] with a cached [`TextLayout`] when
+    /// drawing the same text across multiple frames, since re-measuring text
+    /// every frame is comparatively expensive.
+    pub fn draw_text_simple<B>(
+        &mut self,
+        text: &str,
+        text_factory: &TextFactory,
+        format: &TextFormat,
+        rect: ::win_geom::d2::Rect2D<f32>,
+        brush: &mut B,
+    ) -> Result<()>
+    where
+        B: Brush + DeviceResource,
+    {
+        let size = Size2D::new(rect.width(), rect.height());
+        let layout = text_factory.make_text_layout(text, format, size)?;
+        self.draw_text(&layout, Point2D::new(rect.left, rect.top), brush);
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+//! Offscreen, headless Direct2D rendering via a WIC bitmap render target.
+//!
+//! Unlike [`crate::RenderTarget`], a [`WicRenderTarget`] has no associated
+//! HWND and can be drawn to without ever creating a window. This is useful
+//! for golden-image unit tests of drawing code, and for generating
+//! thumbnails/exports.
+
+use ::std::rc::Rc;
+use ::win32::{errors::Result, invoke::check_res};
+use ::win_geom::d2::Size2D;
+use ::windows::Win32::{
+    Graphics::{
+        Direct2D::{
+            Common::{D2D1_ALPHA_MODE_PREMULTIPLIED, D2D1_PIXEL_FORMAT},
+            ID2D1Factory, ID2D1RenderTarget, D2D1_RENDER_TARGET_PROPERTIES,
+        },
+        Imaging::{
+            CLSID_WICImagingFactory, GUID_ContainerFormatPng, GUID_WICPixelFormat32bppPBGRA,
+            IWICBitmap, IWICBitmapEncoder, IWICImagingFactory, WICBitmapCacheOnDemand,
+        },
+    },
+    System::Com::{
+        CoCreateInstance, IStream, SHCreateMemStream, CLSCTX_INPROC_SERVER,
+    },
+};
+
+use crate::D2DFactory;
+
+impl D2DFactory {
+    /// Creates a headless render target which draws into an in-memory WIC
+    /// bitmap rather than onto a window. No HWND is required.
+    pub fn make_wic_render_target(self: &Rc<Self>, size: Size2D<u32>) -> Result<WicRenderTarget> {
+        WicRenderTarget::new(self.inner(), size)
+    }
+}
+
+/// An offscreen Direct2D render target which draws into an in-memory WIC
+/// bitmap, for headless rendering, screenshot tests, and thumbnail/export
+/// generation.
+pub struct WicRenderTarget {
+    bitmap: IWICBitmap,
+    render_target: ID2D1RenderTarget,
+}
+
+impl WicRenderTarget {
+    fn new(d2d_factory: &ID2D1Factory, size: Size2D<u32>) -> Result<Self> {
+        let imaging_factory: IWICImagingFactory = check_res(
+            || unsafe { CoCreateInstance(&CLSID_WICImagingFactory, None, CLSCTX_INPROC_SERVER) },
+            "CoCreateInstance(CLSID_WICImagingFactory)",
+            (file!(), line!()),
+        )?;
+
+        let bitmap = check_res(
+            || unsafe {
+                imaging_factory.CreateBitmap(
+                    size.width,
+                    size.height,
+                    &GUID_WICPixelFormat32bppPBGRA,
+                    WICBitmapCacheOnDemand,
+                )
+            },
+            "IWICImagingFactory::CreateBitmap",
+            (file!(), line!()),
+        )?;
+
+        let render_props = D2D1_RENDER_TARGET_PROPERTIES {
+            pixelFormat: D2D1_PIXEL_FORMAT {
+                format: ::windows::Win32::Graphics::Dxgi::Common::DXGI_FORMAT_B8G8R8A8_UNORM,
+                alphaMode: D2D1_ALPHA_MODE_PREMULTIPLIED,
+            },
+            ..Default::default()
+        };
+
+        let render_target = check_res(
+            || unsafe { d2d_factory.CreateWicBitmapRenderTarget(&bitmap, &render_props as _) },
+            "ID2D1Factory::CreateWicBitmapRenderTarget",
+            (file!(), line!()),
+        )?;
+
+        Ok(Self {
+            bitmap,
+            render_target,
+        })
+    }
+
+    /// Runs `f`, passing it the underlying `ID2D1RenderTarget` between a
+    /// `BeginDraw`/`EndDraw` pair. Panics if Direct2D reports any rendering
+    /// error other than device loss, since there is no window to recreate
+    /// against.
+    pub fn draw(&mut self, f: impl FnOnce(&ID2D1RenderTarget)) {
+        unsafe { self.render_target.BeginDraw() };
+        f(&self.render_target);
+        check_res(
+            || unsafe { self.render_target.EndDraw(None, None) },
+            "EndDraw",
+            (file!(), line!()),
+        )
+        .expect("Unexpected Direct2D error drawing to a headless WicRenderTarget");
+    }
+
+    /// Encodes the current contents of the bitmap as a PNG.
+    pub fn encode_png(&self) -> Result<Vec<u8>> {
+        let imaging_factory: IWICImagingFactory = check_res(
+            || unsafe { CoCreateInstance(&CLSID_WICImagingFactory, None, CLSCTX_INPROC_SERVER) },
+            "CoCreateInstance(CLSID_WICImagingFactory)",
+            (file!(), line!()),
+        )?;
+
+        let stream: IStream = check_res(
+            || {
+                unsafe { SHCreateMemStream(None) }
+                    .ok_or_else(::windows::core::Error::from_win32)
+            },
+            "SHCreateMemStream",
+            (file!(), line!()),
+        )?;
+
+        let encoder: IWICBitmapEncoder = check_res(
+            || unsafe { imaging_factory.CreateEncoder(&GUID_ContainerFormatPng, None) },
+            "IWICImagingFactory::CreateEncoder",
+            (file!(), line!()),
+        )?;
+
+        unsafe {
+            encoder.Initialize(&stream, Default::default())?;
+            let mut frame = None;
+            let mut frame_options = None;
+            encoder.CreateNewFrame(&mut frame, &mut frame_options)?;
+            let frame = frame.expect("CreateNewFrame did not populate out-param");
+            frame.Initialize(None)?;
+            frame.WriteSource(&self.bitmap, None)?;
+            frame.Commit()?;
+            encoder.Commit()?;
+        }
+
+        let mut stat = Default::default();
+        unsafe { stream.Stat(&mut stat, 0)? };
+        let len = stat.cbSize as usize;
+
+        let mut buf = vec![0u8; len];
+        unsafe {
+            stream.Seek(0, ::windows::Win32::System::Com::STREAM_SEEK_SET, None)?;
+            let mut bytes_read = 0u32;
+            stream.Read(
+                buf.as_mut_ptr() as *mut _,
+                buf.len() as u32,
+                Some(&mut bytes_read),
+            )?;
+        }
+
+        Ok(buf)
+    }
+
+    /// Encodes the current contents of the bitmap as a PNG and writes it to
+    /// `path`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` cannot be written to. File I/O failures don't carry a
+    /// Win32 error code to report through [`win32::errors::Error`], so unlike
+    /// the rest of this crate this is a plain panic rather than a `Result`.
+    pub fn save_png(&self, path: impl AsRef<::std::path::Path>) -> Result<()> {
+        let bytes = self.encode_png()?;
+        ::std::fs::write(path, bytes).expect("Failed to write PNG to disk");
+        Ok(())
+    }
+}
@@ -2,13 +2,19 @@ use crate::resources::FERRIS_ICON;
 
 use ::d2d::{brushes::SolidColorBrush, win_ui_colors, Color, D2DFactory, RenderTarget};
 use ::std::rc::Rc;
+use ::std::time::{Duration, Instant};
 use ::tracing::info;
-use ::win32::{errors::Result, window::Window};
+use ::win32::{errors::Result, input::keyboard::KeyCode, window::Window};
 use ::win_geom::d2::{Point2D, Rect2D, Size2D};
 use ::windows::Win32::UI::WindowsAndMessaging::{
-    DispatchMessageW, GetMessageW, PostQuitMessage, TranslateMessage, MSG,
+    DispatchMessageW, MsgWaitForMultipleObjects, PeekMessageW, PostQuitMessage, TranslateMessage,
+    MSG, PM_REMOVE, QS_ALLINPUT, WM_QUIT,
 };
 
+/// Fixed simulation timestep. [`Game::update`] is stepped in slices of this
+/// duration, independent of however often the outer loop happens to spin.
+const FIXED_TIMESTEP: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
 struct DeviceResources {
     dark_slate_gray_brush: SolidColorBrush,
     cornflower_blue_brush: SolidColorBrush,
@@ -47,16 +53,17 @@ pub struct Game {
     /// avoid calling `update()`/`render()` or anything else that might interact
     /// with the window.
     is_shutting_down: bool,
+
+    /// Whether the space bar was held down as of the last simulation step,
+    /// used to detect when it's toggled and a redraw is needed.
+    is_space_held: bool,
 }
 
 impl Game {
     pub fn new() -> Self {
         // Use dimensions which are divisible by 8 to work well on 100%, 125%
         // and 150% DPI.
-        let size = Size2D {
-            width: 720,
-            height: 640,
-        };
+        let size = Size2D::new(720, 640);
 
         let main_window = Window::new(size, "Main Window", Some(FERRIS_ICON.id().into()))
             .expect("Failed to create main window");
@@ -74,14 +81,28 @@ impl Game {
             resources,
             is_render_dirty: true, // Immediately dirty to ensure first draw
             is_shutting_down: false,
+            is_space_held: false,
         }
     }
 
     fn update(&mut self) {
-        // TODO...
+        let mut keyboard = self.main_window.keyboard();
+
+        let is_space_held = keyboard.is_down(KeyCode::Space);
+        if is_space_held != self.is_space_held {
+            self.is_space_held = is_space_held;
+            self.is_render_dirty = true;
+        }
+
+        keyboard.snapshot();
     }
 
-    fn draw(&mut self) {
+    fn draw(&mut self, interpolation_alpha: f64) {
+        // `update()` doesn't yet retain previous/current state to lerp
+        // between, so there's nothing to do with `interpolation_alpha` yet,
+        // but it's threaded through so `draw` can pick it up once it does.
+        let _ = interpolation_alpha;
+
         if !self.is_render_dirty {
             return;
         }
@@ -103,11 +124,12 @@ impl Game {
             };
 
             ctx.draw_line(
-                Point2D { x, y: 0.0 },
-                Point2D { x, y: f_dim.height },
+                Point2D::new(x, 0.0),
+                Point2D::new(x, f_dim.height),
                 stroke_width,
                 brush,
                 //&mut self.resources.light_slate_gray_brush,
+                None,
             );
         }
         for (i, y) in (0..u_dim.height).step_by(8).map(|u| u as f32).enumerate() {
@@ -118,60 +140,106 @@ impl Game {
                 _ => unreachable!(),
             };
             ctx.draw_line(
-                Point2D { x: 0.0, y },
-                Point2D { x: f_dim.width, y },
+                Point2D::new(0.0, y),
+                Point2D::new(f_dim.width, y),
                 stroke_width,
                 brush,
                 //&mut self.resources.light_slate_gray_brush,
+                None,
             );
         }
 
         // Draw two rectangles, one inner filled gray and one outer stroked blue
         let stroke_width = 1.0;
         ctx.fill_rect(
-            Rect2D {
-                left: (u_dim.width / 2 - 56) as _,
-                right: (u_dim.width / 2 + 56) as _,
-                top: (u_dim.height / 2 - 56) as _,
-                bottom: (u_dim.height / 2 + 56) as _,
-            },
+            Rect2D::new(
+                (u_dim.width / 2 - 56) as _,
+                (u_dim.height / 2 - 56) as _,
+                (u_dim.width / 2 + 56) as _,
+                (u_dim.height / 2 + 56) as _,
+            ),
             &mut self.resources.cornflower_blue_brush,
         );
         ctx.stroke_rect(
-            Rect2D {
-                left: (u_dim.width / 2 - 104) as _,
-                right: (u_dim.width / 2 + 104) as _,
-                top: (u_dim.height / 2 - 104) as _,
-                bottom: (u_dim.height / 2 + 104) as _,
-            },
+            Rect2D::new(
+                (u_dim.width / 2 - 104) as _,
+                (u_dim.height / 2 - 104) as _,
+                (u_dim.width / 2 + 104) as _,
+                (u_dim.height / 2 + 104) as _,
+            ),
             &mut self.resources.dark_slate_gray_brush,
             stroke_width,
+            None,
         );
 
         ctx.end_draw();
         self.is_render_dirty = false;
     }
 
+    /// Pumps the Win32 message queue and runs the game loop.
+    ///
+    /// Unlike a simple `GetMessageW` loop (which blocks until a message
+    /// arrives, and so only ever repaints in response to input), this drains
+    /// pending messages non-blockingly via `PeekMessageW` and then steps the
+    /// simulation on a fixed timestep, rendering once per outer iteration.
+    /// When there's nothing pending and nothing dirty, it yields via
+    /// `MsgWaitForMultipleObjects` instead of busy-spinning.
     pub fn run(&mut self) -> Result<()> {
         let mut msg = MSG::default();
-        while unsafe { GetMessageW(&mut msg, None, 0, 0) }.as_bool() {
-            unsafe { TranslateMessage(&msg) };
-            unsafe { DispatchMessageW(&msg) };
+        let mut last_instant = Instant::now();
+        let mut accumulator = Duration::ZERO;
+
+        loop {
+            let mut had_message = false;
+            while unsafe { PeekMessageW(&mut msg, None, 0, 0, PM_REMOVE) }.as_bool() {
+                if msg.message == WM_QUIT {
+                    return Ok(());
+                }
 
-            if self.main_window.clear_close_request() {
-                info!("posting quit message");
-                unsafe {
-                    PostQuitMessage(0);
+                had_message = true;
+                unsafe { TranslateMessage(&msg) };
+                unsafe { DispatchMessageW(&msg) };
+
+                if self.main_window.clear_close_request() {
+                    info!("posting quit message");
+                    unsafe {
+                        PostQuitMessage(0);
+                    }
+                    self.is_shutting_down = true;
                 }
-                self.is_shutting_down = true;
             }
 
-            if !self.is_shutting_down {
+            if self.is_shutting_down {
+                // Keep pumping messages to let teardown finish, without
+                // running the simulation or rendering, yielding rather than
+                // spinning while we wait for the final `WM_QUIT`.
+                if !had_message {
+                    unsafe {
+                        MsgWaitForMultipleObjects(None, false, 1, QS_ALLINPUT);
+                    }
+                }
+                continue;
+            }
+
+            let now = Instant::now();
+            accumulator += now.duration_since(last_instant);
+            last_instant = now;
+
+            while accumulator >= FIXED_TIMESTEP {
                 self.update();
-                self.draw();
+                accumulator -= FIXED_TIMESTEP;
             }
-        }
 
-        Ok(())
+            let interpolation_alpha = accumulator.as_secs_f64() / FIXED_TIMESTEP.as_secs_f64();
+            self.draw(interpolation_alpha);
+
+            if !had_message && !self.is_render_dirty {
+                let remaining = FIXED_TIMESTEP.saturating_sub(accumulator);
+                let remaining_ms = remaining.as_millis() as u32;
+                unsafe {
+                    MsgWaitForMultipleObjects(None, false, remaining_ms, QS_ALLINPUT);
+                }
+            }
+        }
     }
 }
@@ -13,6 +13,7 @@ use ::win32::proc::{self, ComLibraryHandle};
 
 pub fn main() {
     proc::enable_heap_protection();
+    proc::enable_per_monitor_dpi_awareness();
     trace::configure();
 
     info!(
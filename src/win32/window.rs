@@ -117,45 +117,343 @@
 //!                      └──────────────────────────────────────────────┘
 //! ```
 use crate::{
-    geom::Dimension2D,
-    win32::{errors::*, invoke::chk, window::inner::WindowClass},
+    geom::{Dimension2D, Point2D},
+    win32::{
+        errors::*,
+        invoke::chk,
+        window::inner::{wakeup_msg_id, WindowClass},
+    },
 };
 
 use ::std::{
     ffi::CString,
     rc::Rc,
     cell::Cell,
-    sync::{
-        Arc, Weak as SyncWeak,
-    },
+    sync::{mpsc, Arc, Weak as SyncWeak},
 };
+use ::parking_lot::Mutex;
 use ::tokio::sync::watch;
 use ::windows::{
-    core::PCSTR,
+    core::{PCSTR, PCWSTR},
     Win32::{
-        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
-        System::LibraryLoader::GetModuleHandleA,
-        UI::WindowsAndMessaging::{
-            AdjustWindowRectEx, CreateWindowExA, DefWindowProcA, DestroyWindow, GetWindowLongPtrA,
-            LoadCursorA, RegisterClassExA, SetWindowLongPtrA, ShowWindow, UnregisterClassA,
-            CREATESTRUCTA, CS_HREDRAW, CS_VREDRAW, CW_USEDEFAULT, GWLP_USERDATA, GWLP_WNDPROC,
-            IDC_ARROW, SW_SHOWNORMAL, WINDOW_EX_STYLE, WM_CLOSE, WM_NCCREATE, WM_NCDESTROY,
-            WM_PAINT, WNDCLASSEXA, WS_OVERLAPPEDWINDOW,
+        Foundation::{BOOL, HINSTANCE, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM},
+        Graphics::Gdi::{
+            EnumDisplayMonitors, EnumDisplaySettingsW, GetMonitorInfoW, MonitorFromWindow,
+            DEVMODEW, ENUM_CURRENT_SETTINGS, HDC, HMONITOR, MONITORINFOEXW,
+            MONITOR_DEFAULTTOPRIMARY,
+        },
+        System::{
+            Console::{SetConsoleCtrlHandler, CTRL_CLOSE_EVENT, CTRL_C_EVENT, CTRL_SHUTDOWN_EVENT},
+            LibraryLoader::GetModuleHandleA,
+        },
+        UI::{
+            Input::KeyboardAndMouse::{GetKeyState, VIRTUAL_KEY, VK_CONTROL, VK_MENU, VK_SHIFT},
+            WindowsAndMessaging::{
+                AdjustWindowRectEx, ClientToScreen, ClipCursor, CreateWindowExA, DefWindowProcA,
+                DestroyWindow, GetClientRect, GetWindowLongPtrA, GetWindowPlacement, LoadCursorA,
+                PostMessageW, RegisterClassExA, RegisterWindowMessageA, SetCursor,
+                SetWindowLongPtrA, SetWindowPlacement, SetWindowPos, ShowCursor, ShowWindow,
+                UnregisterClassA, CREATESTRUCTA, CS_HREDRAW, CS_VREDRAW,
+                CW_USEDEFAULT, GET_WHEEL_DELTA_WPARAM, GWLP_USERDATA, GWLP_WNDPROC, GWL_STYLE,
+                HCURSOR, HTCLIENT, IDC_ARROW, IDC_HAND, IDC_IBEAM, IDC_SIZEALL, IDC_WAIT,
+                SW_SHOWNORMAL, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+                WINDOWPLACEMENT, WINDOW_EX_STYLE, WINDOW_STYLE, WM_CLOSE, WM_ENTERSIZEMOVE,
+                WM_EXITSIZEMOVE, WM_KEYDOWN, WM_KEYUP, WM_KILLFOCUS, WM_LBUTTONDOWN,
+                WM_LBUTTONUP, WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEMOVE, WM_MOUSEWHEEL,
+                WM_NCCREATE, WM_NCDESTROY, WM_PAINT, WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SETCURSOR,
+                WM_SETFOCUS, WM_SIZE, WM_SYSKEYDOWN, WM_SYSKEYUP, WNDCLASSEXA, WS_MAXIMIZEBOX,
+                WS_OVERLAPPEDWINDOW, WS_THICKFRAME,
+            },
         },
     },
 };
 
 type WndProc = extern "system" fn(HWND, u32, WPARAM, LPARAM) -> LRESULT;
 
-/// The state of a window. If the state is `CloseRequested`, the corresponding
-/// [Window] should be dropped to action the close.
+/// Identifies which mouse button an event pertains to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseButton {
+    /// The primary (usually left) mouse button.
+    Left,
+    /// The secondary (usually right) mouse button.
+    Right,
+    /// The middle mouse button / scroll wheel button.
+    Middle,
+}
+
+/// Which modifier keys were held down at the time of a key or mouse event,
+/// captured via `GetKeyState`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyModifiers {
+    /// Either SHIFT key was held down.
+    pub shift: bool,
+    /// Either CTRL key was held down.
+    pub ctrl: bool,
+    /// Either ALT key was held down.
+    pub alt: bool,
+}
+
+impl KeyModifiers {
+    /// Captures the currently held modifier keys via `GetKeyState`.
+    fn capture() -> Self {
+        let is_down = |vk: VIRTUAL_KEY| unsafe { GetKeyState(vk.0 as i32) } < 0;
+
+        Self {
+            shift: is_down(VK_SHIFT),
+            ctrl: is_down(VK_CONTROL),
+            alt: is_down(VK_MENU),
+        }
+    }
+}
+
+/// A system cursor shape, loaded via `LoadCursorA` and applied with
+/// [`Window::set_cursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorIcon {
+    /// The default pointer arrow.
+    Arrow,
+    /// A text-editing caret, typically shown over editable text.
+    IBeam,
+    /// A pointing hand, typically shown over hyperlinks/buttons.
+    Hand,
+    /// The busy/wait hourglass.
+    Wait,
+    /// A four-way move/resize cursor.
+    SizeAll,
+}
+
+impl CursorIcon {
+    /// The Win32 `IDC_*` resource identifier for this cursor shape.
+    fn win32_resource_id(self) -> PCSTR {
+        let wide = match self {
+            Self::Arrow => IDC_ARROW,
+            Self::IBeam => IDC_IBEAM,
+            Self::Hand => IDC_HAND,
+            Self::Wait => IDC_WAIT,
+            Self::SizeAll => IDC_SIZEALL,
+        };
+        PCSTR::from_raw(wide.as_ptr() as *const u8)
+    }
+}
+
+/// A physical display monitor attached to the system, identified by its
+/// Win32 `HMONITOR` handle.
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    name: String,
+    position: Point2D<i32>,
+    dimensions: Dimension2D<i32>,
+    refresh_rate: u32,
+}
+
+impl Monitor {
+    /// The name of the monitor's display device, e.g. `\\.\DISPLAY1`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The position of the monitor's top-left corner, in virtual screen
+    /// coordinates.
+    pub const fn position(&self) -> Point2D<i32> {
+        self.position
+    }
+
+    /// The dimensions of the monitor, in physical pixels.
+    pub const fn dimensions(&self) -> Dimension2D<i32> {
+        self.dimensions
+    }
+
+    /// The monitor's current refresh rate, in Hz.
+    pub const fn refresh_rate(&self) -> u32 {
+        self.refresh_rate
+    }
+
+    /// Enumerates every monitor currently attached to the system.
+    pub fn get_available_monitors() -> Result<Vec<Self>> {
+        let mut monitors: Vec<Self> = Vec::new();
+
+        unsafe {
+            EnumDisplayMonitors(
+                HDC::default(),
+                None,
+                Some(Self::enum_proc),
+                LPARAM(&mut monitors as *mut Vec<Self> as isize),
+            );
+        }
+
+        Ok(monitors)
+    }
+
+    /// Returns the system's primary monitor.
+    pub fn get_primary_monitor() -> Result<Self> {
+        let hmonitor = unsafe { MonitorFromWindow(HWND::default(), MONITOR_DEFAULTTOPRIMARY) };
+        Self::from_hmonitor(hmonitor)
+    }
+
+    fn from_hmonitor(hmonitor: HMONITOR) -> Result<Self> {
+        let mut info = MONITORINFOEXW::default();
+        info.monitorInfo.cbSize = ::std::mem::size_of::<MONITORINFOEXW>() as u32;
+        chk!(bool; GetMonitorInfoW(hmonitor, &mut info as *mut MONITORINFOEXW as *mut _))?;
+
+        let name_len = info
+            .szDevice
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(info.szDevice.len());
+        let name = String::from_utf16_lossy(&info.szDevice[..name_len]);
+
+        let mut devmode = DEVMODEW {
+            dmSize: ::std::mem::size_of::<DEVMODEW>() as u16,
+            ..Default::default()
+        };
+        chk!(bool; EnumDisplaySettingsW(
+            PCWSTR::from_raw(info.szDevice.as_ptr()),
+            ENUM_CURRENT_SETTINGS,
+            &mut devmode
+        ))?;
+
+        let rect = info.monitorInfo.rcMonitor;
+
+        Ok(Self {
+            name,
+            position: Point2D { x: rect.left, y: rect.top },
+            dimensions: Dimension2D {
+                width: rect.right - rect.left,
+                height: rect.bottom - rect.top,
+            },
+            refresh_rate: devmode.dmDisplayFrequency,
+        })
+    }
+
+    extern "system" fn enum_proc(
+        hmonitor: HMONITOR,
+        _hdc: HDC,
+        _clip_rect: *mut RECT,
+        lparam: LPARAM,
+    ) -> BOOL {
+        let monitors = unsafe { &mut *(lparam.0 as *mut Vec<Self>) };
+
+        if let Ok(monitor) = Self::from_hmonitor(hmonitor) {
+            monitors.push(monitor);
+        }
+
+        BOOL(1)
+    }
+}
+
+/// A `Send + Sync + Clone` handle that other threads can use to inject work
+/// onto a window's GUI thread, obtained via [`Window::event_proxy`].
+///
+/// `Window` itself is `!Send + !Sync` (Win32 windows must be driven from the
+/// thread that created them), so background tasks that need to ask the GUI
+/// thread to do something go through an `EventProxy` instead: [`Self::send`]
+/// queues a callback and wakes the window's message pump via a
+/// process-registered custom message, so it runs promptly even if the
+/// window is otherwise idle.
+#[derive(Clone)]
+pub struct EventProxy {
+    hwnd: isize,
+    sender: Arc<Mutex<mpsc::Sender<Box<dyn FnOnce() + Send>>>>,
+}
+
+impl EventProxy {
+    /// Schedules `callback` to run on the window's GUI thread, then wakes
+    /// its message pump so it runs promptly.
+    ///
+    /// If the window has already been destroyed, `callback` is silently
+    /// dropped rather than run.
+    pub fn send(&self, callback: impl FnOnce() + Send + 'static) -> Result<()> {
+        // If the receiving end has been dropped the window is gone, so
+        // there's nothing useful to wake - just drop the callback.
+        let _ = self.sender.lock().send(Box::new(callback));
+
+        chk!(bool; PostMessageW(HWND(self.hwnd), wakeup_msg_id(), WPARAM(0), LPARAM(0)))
+    }
+}
+
+/// Event callbacks driven by the window's Win32 message pump.
+///
+/// Every method has a default no-op implementation, so implementors only need
+/// to override the events they care about. A bare `Fn()` closure also
+/// implements [`WinHandler`] (handling only [`Self::on_paint`]), so existing
+/// callers that only care about painting don't need to change.
+pub trait WinHandler {
+    /// Called when the window has been asked to close, typically because the
+    /// user clicked the window's close button. The [Window] is not actually
+    /// destroyed until it is dropped.
+    fn on_close_requested(&self) {}
+
+    /// Called after the window's client area has been resized.
+    fn on_resized(&self, _size: Dimension2D<i32>) {}
+
+    /// Called when the system requests the window be repainted.
+    fn on_paint(&self) {}
+
+    /// Called when the mouse moves within the window's client area.
+    fn on_mouse_move(&self, _position: Point2D<i32>) {}
+
+    /// Called when a mouse button is pressed within the window's client area.
+    fn on_mouse_down(&self, _position: Point2D<i32>, _button: MouseButton) {}
+
+    /// Called when a mouse button is released within the window's client
+    /// area.
+    fn on_mouse_up(&self, _position: Point2D<i32>, _button: MouseButton) {}
+
+    /// Called when the mouse wheel is scrolled, with `delta` expressed in
+    /// multiples of `WHEEL_DELTA` (120).
+    fn on_scroll(&self, _delta: i32) {}
+
+    /// Called when a key is pressed, with the Win32 virtual key code and the
+    /// modifier keys held at the time. Also fires for system keystrokes (e.g.
+    /// Alt+key combinations), reported via `WM_SYSKEYDOWN`.
+    fn on_key_down(&self, _vkey: u32, _modifiers: KeyModifiers) {}
+
+    /// Called when a key is released, with the Win32 virtual key code and the
+    /// modifier keys held at the time. Also fires for system keystrokes (e.g.
+    /// Alt+key combinations), reported via `WM_SYSKEYUP`.
+    fn on_key_up(&self, _vkey: u32, _modifiers: KeyModifiers) {}
+
+    /// Called when the window gains or loses keyboard focus.
+    fn on_focus(&self, _focused: bool) {}
+
+    /// Called when the user begins an interactive drag-resize or drag-move
+    /// of the window (`WM_ENTERSIZEMOVE`). Implementors can use this to
+    /// suspend expensive per-frame work until [`Self::on_exit_size_move`].
+    fn on_enter_size_move(&self) {}
+
+    /// Called when an interactive drag-resize or drag-move of the window
+    /// ends (`WM_EXITSIZEMOVE`).
+    fn on_exit_size_move(&self) {}
+}
+
+impl<F> WinHandler for F
+where
+    F: Fn(),
+{
+    fn on_paint(&self) {
+        self()
+    }
+}
+
+/// The state of a window.
+///
+/// `CloseRequested` and `Destroyed` are deliberately distinct: the former is
+/// only a request, published from `WM_CLOSE` while the Win32 window is still
+/// alive, and dropping the corresponding [Window] is what actually actions
+/// it. The latter is published from `WM_NCDESTROY` once the HWND is
+/// genuinely gone - including when the Win32 side initiated teardown - and
+/// is terminal; no further states follow it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WindowState {
     /// Window is active and running normally.
     Active,
     /// The window has requested to close - typically because the user clicked
-    /// the window's close button.
+    /// the window's close button. The corresponding [Window] should be
+    /// dropped to action the close.
     CloseRequested,
+    /// The window's underlying HWND has been destroyed. Terminal: no
+    /// further state changes will follow.
+    Destroyed,
 }
 
 /// The next step to take when handling a window proc message.
@@ -167,13 +465,54 @@ enum NextMessageAction {
     DontForward,
 }
 
+/// Builder for constructing a [`Window`] with options beyond the basics
+/// accepted by [`Window::new`].
+pub struct WindowBuilder<P>
+where
+    P: WinHandler,
+{
+    dimension: Dimension2D<i32>,
+    title: String,
+    handler: P,
+    resizable: bool,
+}
+
+impl<P> WindowBuilder<P>
+where
+    P: WinHandler,
+{
+    /// Starts building a new window. Defaults to resizable.
+    pub fn new(dimension: Dimension2D<i32>, title: &str, handler: P) -> Self {
+        Self {
+            dimension,
+            title: title.to_string(),
+            handler,
+            resizable: true,
+        }
+    }
+
+    /// Sets whether the window can be resized or maximized by the user via
+    /// its chrome. Defaults to `true`.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Constructs and displays the window.
+    pub fn build(self) -> Result<Window<P>> {
+        println!("Creating window: {}", self.title);
+        WindowInner::new(self.dimension, &self.title, self.handler, self.resizable)
+            .map(|inner| Window { inner })
+    }
+}
+
 /// A rusty wrapper around Win32 window class.
 ///
 /// A [Window] is `!Sync + !Send` as Win32 windows must be controlled by the
 /// same thread on which they were created.
 pub struct Window<P>
 where
-    P: Fn(),
+    P: WinHandler,
 {
     /// The inner refcounted window object. A clone of this object is held on
     /// the win32 API side and should be released when the window is destroyed.
@@ -182,12 +521,14 @@ where
 
 impl<P> Window<P>
 where
-    P: Fn(),
+    P: WinHandler,
 {
-    /// Construct and display a new window.
-    pub fn new(dimension: Dimension2D<i32>, title: &str, on_paint: P) -> Result<Self> {
-        println!("Creating window: {title}");
-        WindowInner::new(dimension, title, on_paint).map(|inner| Self { inner })
+    /// Construct and display a new, resizable window.
+    ///
+    /// Use [`WindowBuilder`] instead if you need a fixed-size window or
+    /// other construction-time options.
+    pub fn new(dimension: Dimension2D<i32>, title: &str, handler: P) -> Result<Self> {
+        WindowBuilder::new(dimension, title, handler).build()
     }
 
     /// The dimensions of the client area of our Win32 window. The window chrome
@@ -203,15 +544,77 @@ where
     }
 
     /// Returns a receiver that can be awaited to monitor the window state.
-    /// When the window requests to close, the [Window] should be dropped.
+    /// When the window requests to close, the [Window] should be dropped to
+    /// action the close. Once [`WindowState::Destroyed`] is observed, the
+    /// Win32 window is already gone and no further state changes will follow.
     pub fn close_receiver(&self) -> watch::Receiver<WindowState> {
         self.inner.close_receiver()
     }
+
+    /// Returns a receiver that can be awaited to monitor the window's
+    /// client area dimensions, updated on every `WM_SIZE`.
+    pub fn size_receiver(&self) -> watch::Receiver<Dimension2D<i32>> {
+        self.inner.size_receiver()
+    }
+
+    /// Sets the cursor shown while the pointer is within this window's
+    /// client area.
+    pub fn set_cursor(&self, icon: CursorIcon) -> Result<()> {
+        self.inner.set_cursor(icon)
+    }
+
+    /// Shows or hides the cursor. Repeated calls with the same value are
+    /// no-ops, so callers don't need to track their own balance.
+    pub fn set_cursor_visible(&self, visible: bool) {
+        self.inner.set_cursor_visible(visible)
+    }
+
+    /// Confines the cursor to this window's client area, or releases it
+    /// back to the full screen.
+    pub fn set_cursor_grab(&self, grab: bool) -> Result<()> {
+        self.inner.set_cursor_grab(grab)
+    }
+
+    /// Whether the cursor is currently confined to this window's client
+    /// area via [`Self::set_cursor_grab`].
+    pub fn cursor_grabbed(&self) -> bool {
+        self.inner.cursor_grabbed()
+    }
+
+    /// Enters fullscreen on the given monitor, or leaves fullscreen and
+    /// restores the window's previous style and placement if `None`.
+    ///
+    /// Fires [`WinHandler::on_resized`] once the transition completes, so
+    /// e.g. an adjacent Direct2D render target can resize its
+    /// device-specific resources to match.
+    pub fn set_fullscreen(&self, monitor: Option<Monitor>) -> Result<()> {
+        self.inner.set_fullscreen(monitor)
+    }
+
+    /// Returns a `Send + Sync + Clone` handle that other threads can use to
+    /// inject work onto this window's GUI thread and wake its message
+    /// pump, despite `Window` itself being `!Send + !Sync`.
+    pub fn event_proxy(&self) -> EventProxy {
+        self.inner.event_proxy()
+    }
+
+    /// Registers a console control handler (`CTRL_C_EVENT`,
+    /// `CTRL_CLOSE_EVENT`, `CTRL_SHUTDOWN_EVENT`) that posts `WM_CLOSE` to
+    /// this window, so an interrupted or shut-down console session tears
+    /// the window down through the same path as clicking its close button.
+    ///
+    /// The handler runs on a dedicated OS thread created by the system, so
+    /// it never touches this `!Send` window directly - it only posts a
+    /// message to the window's own thread, which processes it via the
+    /// existing `WM_CLOSE` arm of `handle_message`.
+    pub fn trap_console_signals(&self) -> Result<()> {
+        self.inner.trap_console_signals()
+    }
 }
 
 impl<P> Drop for Window<P>
 where
-    P: Fn(),
+    P: WinHandler,
 {
     fn drop(&mut self) {
         println!("Dropping window: {title}", title = &self.inner.title);
@@ -223,7 +626,7 @@ where
 
 struct WindowInner<P>
 where
-    P: Fn(),
+    P: WinHandler,
 {
     /// A reference-counted handle to the Win32 window class registered for
     /// windows of this type. When the last `Window` instance is released, the
@@ -232,34 +635,81 @@ where
     /// A handle to our corresponding Win32 window. If zero, the window has been
     /// destroyed on the Win32 size.
     hwnd: Cell<isize>,
-    /// Fixed dimensions for our window.
-    dimension: Dimension2D<i32>,
+    /// Dimensions of our window's client area, updated on every `WM_SIZE`.
+    dimension: Cell<Dimension2D<i32>>,
     /// The Window's title, as it appears in the Windows title bar.
     title: String,
-    /// A closure invoked when the system requests the window be painted.
-    on_paint: P,
+    /// The event handler invoked for window proc messages.
+    handler: P,
 
     close_sender: watch::Sender<WindowState>,
     close_receiver: watch::Receiver<WindowState>,
+
+    size_sender: watch::Sender<Dimension2D<i32>>,
+    size_receiver: watch::Receiver<Dimension2D<i32>>,
+
+    /// The cursor re-applied whenever Windows asks us to set it (via
+    /// `WM_SETCURSOR`) while the pointer is within our client area. `None`
+    /// defers to the window class's default cursor.
+    cursor: Cell<Option<HCURSOR>>,
+    /// Whether the cursor is currently hidden via [`Self::set_cursor_visible`].
+    /// Tracked ourselves so repeated calls are idempotent rather than
+    /// accumulating on `ShowCursor`'s own internal display counter.
+    cursor_hidden: Cell<bool>,
+    /// Whether the cursor is currently confined to the window's client area
+    /// via [`Self::set_cursor_grab`].
+    cursor_grabbed: Cell<bool>,
+    /// The window's style and placement, saved by [`Self::set_fullscreen`]
+    /// just before entering fullscreen so they can be restored when
+    /// leaving. `None` while not fullscreen.
+    fullscreen_state: Cell<Option<(WINDOW_STYLE, WINDOWPLACEMENT)>>,
+
+    /// Sending half of the cross-thread command channel, shared with every
+    /// [`EventProxy`] handed out by [`Self::event_proxy`].
+    event_sender: Arc<Mutex<mpsc::Sender<Box<dyn FnOnce() + Send>>>>,
+    /// Receiving half of the cross-thread command channel, drained on the
+    /// GUI thread whenever the registered wakeup message arrives.
+    event_receiver: Mutex<mpsc::Receiver<Box<dyn FnOnce() + Send>>>,
 }
 
 impl<P> WindowInner<P>
 where
-    P: Fn(),
+    P: WinHandler,
 {
     /// Construct and display a new window.
-    pub fn new(dimension: Dimension2D<i32>, title: &str, on_paint: P) -> Result<Rc<Self>> {
+    pub fn new(
+        dimension: Dimension2D<i32>,
+        title: &str,
+        handler: P,
+        resizable: bool,
+    ) -> Result<Rc<Self>> {
         println!("Creating window inner: {title}");
 
+        let style = if resizable {
+            WS_OVERLAPPEDWINDOW
+        } else {
+            WINDOW_STYLE(WS_OVERLAPPEDWINDOW.0 & !WS_THICKFRAME.0 & !WS_MAXIMIZEBOX.0)
+        };
+
         let (close_sender, close_receiver) = watch::channel(WindowState::Active);
+        let (size_sender, size_receiver) = watch::channel(dimension);
+        let (event_sender, event_receiver) = mpsc::channel();
         let this = Rc::new(Self {
             title: title.to_string(),
             window_class: WindowClass::get_or_create("MainWindow", Self::wnd_proc_setup)?,
             hwnd: Default::default(),
-            dimension,
-            on_paint,
+            dimension: Cell::new(dimension),
+            handler,
             close_sender,
             close_receiver,
+            size_sender,
+            size_receiver,
+            cursor: Cell::new(None),
+            cursor_hidden: Cell::new(false),
+            cursor_grabbed: Cell::new(false),
+            fullscreen_state: Cell::new(None),
+            event_sender: Arc::new(Mutex::new(event_sender)),
+            event_receiver: Mutex::new(event_receiver),
         });
 
         let hwnd = {
@@ -267,7 +717,7 @@ where
             let mut rect = dimension.into();
             chk!(bool; AdjustWindowRectEx(
                 &mut rect,
-                WS_OVERLAPPEDWINDOW,
+                style,
                 false,
                 WINDOW_EX_STYLE::default()
             ))?;
@@ -276,7 +726,7 @@ where
                     WINDOW_EX_STYLE::default(),
                     PCSTR::from_raw(this.window_class.class_name().as_ptr() as *const u8),
                     PCSTR::from_raw(name.as_ptr() as *const u8),
-                    WS_OVERLAPPEDWINDOW,
+                    style,
                     CW_USEDEFAULT,
                     CW_USEDEFAULT,
                     rect.right - rect.left,
@@ -298,8 +748,8 @@ where
 
     /// The dimensions of the client area of our Win32 window. The window chrome
     /// is in addition to this dimension.
-    pub const fn dimension(&self) -> Dimension2D<i32> {
-        self.dimension
+    pub fn dimension(&self) -> Dimension2D<i32> {
+        self.dimension.get()
     }
 
     /// Get a handle to the Win32 window's handle. This is often required when
@@ -315,11 +765,149 @@ where
         }
     }
     /// Returns a receiver that can be awaited to monitor the window state.
-    /// When the window requests to close, the [Window] should be dropped.
+    /// When the window requests to close, the [Window] should be dropped to
+    /// action the close. Once [`WindowState::Destroyed`] is observed, the
+    /// Win32 window is already gone and no further state changes will follow.
     pub fn close_receiver(&self) -> watch::Receiver<WindowState> {
         self.close_receiver.clone()
     }
 
+    /// Returns a receiver that can be awaited to monitor the window's
+    /// client area dimensions, updated on every `WM_SIZE`.
+    pub fn size_receiver(&self) -> watch::Receiver<Dimension2D<i32>> {
+        self.size_receiver.clone()
+    }
+
+    /// Sets the cursor shown while the pointer is within this window's client
+    /// area, re-applied automatically on every `WM_SETCURSOR`.
+    fn set_cursor(&self, icon: CursorIcon) -> Result<()> {
+        let cursor = chk!(res; LoadCursorA(HINSTANCE::default(), icon.win32_resource_id()))?;
+        self.cursor.set(Some(cursor));
+        unsafe { SetCursor(cursor) };
+        Ok(())
+    }
+
+    /// Shows or hides the cursor. Repeated calls with the same `visible`
+    /// value are no-ops, so toggling visibility doesn't require callers to
+    /// track their own balance against `ShowCursor`'s internal display
+    /// counter.
+    fn set_cursor_visible(&self, visible: bool) {
+        let hidden = !visible;
+        if hidden == self.cursor_hidden.get() {
+            return;
+        }
+        unsafe { ShowCursor(visible) };
+        self.cursor_hidden.set(hidden);
+    }
+
+    /// Confines the cursor to this window's client area, or releases it back
+    /// to the full screen.
+    fn set_cursor_grab(&self, grab: bool) -> Result<()> {
+        if grab {
+            let hwnd = self.hwnd().expect("Window handle was NULL");
+            let mut rect = RECT::default();
+            chk!(bool; GetClientRect(hwnd, &mut rect))?;
+
+            let mut top_left = POINT { x: rect.left, y: rect.top };
+            let mut bottom_right = POINT { x: rect.right, y: rect.bottom };
+            chk!(bool; ClientToScreen(hwnd, &mut top_left))?;
+            chk!(bool; ClientToScreen(hwnd, &mut bottom_right))?;
+
+            let screen_rect = RECT {
+                left: top_left.x,
+                top: top_left.y,
+                right: bottom_right.x,
+                bottom: bottom_right.y,
+            };
+            chk!(bool; ClipCursor(Some(&screen_rect)))?;
+        } else {
+            chk!(bool; ClipCursor(None))?;
+        }
+
+        self.cursor_grabbed.set(grab);
+        Ok(())
+    }
+
+    /// Whether the cursor is currently confined to this window's client
+    /// area via [`Self::set_cursor_grab`].
+    fn cursor_grabbed(&self) -> bool {
+        self.cursor_grabbed.get()
+    }
+
+    /// Enters fullscreen on the given monitor, or leaves fullscreen and
+    /// restores the window's previous style and placement if `None`.
+    fn set_fullscreen(&self, monitor: Option<Monitor>) -> Result<()> {
+        let hwnd = self.hwnd().expect("Window handle was NULL");
+
+        match monitor {
+            Some(monitor) => {
+                if self.fullscreen_state.get().is_none() {
+                    let style =
+                        WINDOW_STYLE(chk!(last_err; GetWindowLongPtrA(hwnd, GWL_STYLE))? as u32);
+
+                    let mut placement = WINDOWPLACEMENT {
+                        length: ::std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+                        ..Default::default()
+                    };
+                    chk!(bool; GetWindowPlacement(hwnd, &mut placement))?;
+
+                    self.fullscreen_state.set(Some((style, placement)));
+                }
+
+                let (style, _) = self.fullscreen_state.get().expect("just set above");
+                let fullscreen_style = WINDOW_STYLE(style.0 & !WS_OVERLAPPEDWINDOW.0);
+                chk!(last_err; SetWindowLongPtrA(hwnd, GWL_STYLE, fullscreen_style.0 as isize))?;
+
+                let position = monitor.position();
+                let dimensions = monitor.dimensions();
+                chk!(bool; SetWindowPos(
+                    hwnd,
+                    None,
+                    position.x,
+                    position.y,
+                    dimensions.width,
+                    dimensions.height,
+                    SWP_NOZORDER | SWP_FRAMECHANGED
+                ))?;
+
+                self.handler.on_resized(dimensions);
+            }
+            None => {
+                if let Some((style, placement)) = self.fullscreen_state.take() {
+                    chk!(last_err; SetWindowLongPtrA(hwnd, GWL_STYLE, style.0 as isize))?;
+                    chk!(bool; SetWindowPlacement(hwnd, &placement))?;
+                    chk!(bool; SetWindowPos(
+                        hwnd,
+                        None,
+                        0,
+                        0,
+                        0,
+                        0,
+                        SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_FRAMECHANGED
+                    ))?;
+
+                    let restored = placement.rcNormalPosition;
+                    self.handler.on_resized(Dimension2D {
+                        width: restored.right - restored.left,
+                        height: restored.bottom - restored.top,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a `Send + Sync + Clone` handle that other threads can use to
+    /// inject work onto this window's GUI thread and wake its message
+    /// pump, despite `Window` itself being `!Send + !Sync`.
+    fn event_proxy(&self) -> EventProxy {
+        EventProxy {
+            hwnd: self.hwnd.get(),
+            sender: self.event_sender.clone(),
+        }
+    }
+
     fn destroy(&self) -> Result<()> {
         if let Some(h) = self.hwnd() {
             chk!(bool; DestroyWindow(h))?;
@@ -327,14 +915,109 @@ where
         Ok(())
     }
 
-    fn handle_message(&self, umsg: u32, _wparam: WPARAM, _lparam: LPARAM) -> NextMessageAction {
-        //println!("{}", crate::win32::debug::msgs::DebugMsg::new(umsg, _wparam, _lparam));
+    /// Registers a console control handler that posts `WM_CLOSE` to this
+    /// window. See [`Window::trap_console_signals`].
+    fn trap_console_signals(&self) -> Result<()> {
+        inner::register_console_trap(self.hwnd.get())
+    }
+
+    /// Splits an `LPARAM` into its low/high 16-bit words, as used by Win32 to
+    /// pack a pair of coordinates (mouse position, resized client area, etc)
+    /// into a single parameter.
+    fn split_lparam(lparam: LPARAM) -> (i16, i16) {
+        let bits = lparam.0 as u32;
+        ((bits & 0xffff) as i16, ((bits >> 16) & 0xffff) as i16)
+    }
+
+    fn handle_message(&self, umsg: u32, wparam: WPARAM, lparam: LPARAM) -> NextMessageAction {
+        //println!("{}", crate::win32::debug::msgs::DebugMsg::new(umsg, wparam, lparam));
 
         match umsg {
             WM_PAINT => {
-                (self.on_paint)();
+                self.handler.on_paint();
+            }
+            WM_SIZE => {
+                let (width, height) = Self::split_lparam(lparam);
+                let dimension = Dimension2D {
+                    width: width as i32,
+                    height: height as i32,
+                };
+                self.dimension.set(dimension);
+                self.size_sender.send_replace(dimension);
+                self.handler.on_resized(dimension);
+            }
+            WM_ENTERSIZEMOVE => {
+                self.handler.on_enter_size_move();
+            }
+            WM_EXITSIZEMOVE => {
+                self.handler.on_exit_size_move();
+            }
+            WM_MOUSEMOVE => {
+                let (x, y) = Self::split_lparam(lparam);
+                self.handler
+                    .on_mouse_move(Point2D { x: x as i32, y: y as i32 });
+            }
+            WM_LBUTTONDOWN => {
+                let (x, y) = Self::split_lparam(lparam);
+                self.handler
+                    .on_mouse_down(Point2D { x: x as i32, y: y as i32 }, MouseButton::Left);
+            }
+            WM_LBUTTONUP => {
+                let (x, y) = Self::split_lparam(lparam);
+                self.handler
+                    .on_mouse_up(Point2D { x: x as i32, y: y as i32 }, MouseButton::Left);
+            }
+            WM_RBUTTONDOWN => {
+                let (x, y) = Self::split_lparam(lparam);
+                self.handler
+                    .on_mouse_down(Point2D { x: x as i32, y: y as i32 }, MouseButton::Right);
+            }
+            WM_RBUTTONUP => {
+                let (x, y) = Self::split_lparam(lparam);
+                self.handler
+                    .on_mouse_up(Point2D { x: x as i32, y: y as i32 }, MouseButton::Right);
+            }
+            WM_MBUTTONDOWN => {
+                let (x, y) = Self::split_lparam(lparam);
+                self.handler
+                    .on_mouse_down(Point2D { x: x as i32, y: y as i32 }, MouseButton::Middle);
+            }
+            WM_MBUTTONUP => {
+                let (x, y) = Self::split_lparam(lparam);
+                self.handler
+                    .on_mouse_up(Point2D { x: x as i32, y: y as i32 }, MouseButton::Middle);
+            }
+            WM_MOUSEWHEEL => {
+                self.handler.on_scroll(GET_WHEEL_DELTA_WPARAM(wparam) as i32);
+            }
+            WM_SETCURSOR => {
+                // The low word of `lparam` is the hit-test result; only
+                // override the cursor within our own client area so the
+                // system's resize/border cursors still work at the edges.
+                let hit_test = (lparam.0 as u32) & 0xFFFF;
+                if hit_test == HTCLIENT {
+                    if let Some(cursor) = self.cursor.get() {
+                        unsafe { SetCursor(cursor) };
+                        return NextMessageAction::DontForward;
+                    }
+                }
+            }
+            WM_KEYDOWN | WM_SYSKEYDOWN => {
+                self.handler
+                    .on_key_down(wparam.0 as u32, KeyModifiers::capture());
+            }
+            WM_KEYUP | WM_SYSKEYUP => {
+                self.handler
+                    .on_key_up(wparam.0 as u32, KeyModifiers::capture());
+            }
+            WM_SETFOCUS => {
+                self.handler.on_focus(true);
+            }
+            WM_KILLFOCUS => {
+                self.handler.on_focus(false);
             }
             WM_CLOSE => {
+                self.handler.on_close_requested();
                 self.close_sender.send_replace(WindowState::CloseRequested);
 
                 return NextMessageAction::DontForward;
@@ -348,9 +1031,23 @@ where
                     .unwrap() as *const Self;
                 let _ = unsafe { Rc::from_raw(self_) };
 
+                // Stop routing console control events to a window that's
+                // about to be gone.
+                inner::unregister_console_trap(self.hwnd.get());
+
+                // The HWND is genuinely gone now - as opposed to WM_CLOSE,
+                // which is only a request - so publish the terminal state
+                // before clearing our handle.
+                self.close_sender.send_replace(WindowState::Destroyed);
+
                 // Clear our window handle now that we're destroyed.
                 self.hwnd.set(0);
             }
+            _ if umsg == wakeup_msg_id() => {
+                while let Ok(callback) = self.event_receiver.lock().try_recv() {
+                    callback();
+                }
+            }
             _ => (),
         }
 
@@ -424,13 +1121,68 @@ mod inner {
     use ::lazy_static::lazy_static;
     use ::parking_lot::Mutex;
     use ::std::{
-        collections::{hash_map::Entry, HashMap},
+        collections::{hash_map::Entry, HashMap, HashSet},
         ffi::CStr,
     };
 
     lazy_static! {
         static ref WINDOW_REGISTRATIONS: Mutex<HashMap<String, SyncWeak<WindowClass>>> =
             Default::default();
+
+        /// Process-wide custom message id used to wake a window's message
+        /// pump from another thread via [`crate::win32::window::EventProxy`].
+        /// Registered once with the system via `RegisterWindowMessageA`, the
+        /// same pattern glutin/winit use to avoid colliding with other
+        /// libraries' custom messages.
+        static ref WAKEUP_MSG_ID: u32 =
+            unsafe { RegisterWindowMessageA(PCSTR::from_raw(b"directx::WakeUp\0".as_ptr())) };
+
+        /// HWNDs of windows which have opted into
+        /// [`crate::win32::window::Window::trap_console_signals`], looked up
+        /// by [`console_ctrl_handler`] when a console control event fires.
+        /// Keyed by the raw HWND rather than by thread, since a single
+        /// process-wide console control handler may end up serving windows
+        /// owned by several different GUI threads.
+        static ref CONSOLE_TRAPPED_WINDOWS: Mutex<HashSet<isize>> = Default::default();
+    }
+
+    /// Returns the process-wide registered message id used to wake a
+    /// window's message pump.
+    pub(super) fn wakeup_msg_id() -> u32 {
+        *WAKEUP_MSG_ID
+    }
+
+    /// Registers `hwnd` to receive a `WM_CLOSE` whenever a console control
+    /// event (Ctrl-C, console close, system shutdown) is trapped by
+    /// [`console_ctrl_handler`]. Installs the process-wide handler on first
+    /// use; subsequent calls (from other windows, or the same window) are
+    /// cheap no-ops beyond the registry insert.
+    pub(super) fn register_console_trap(hwnd: isize) -> Result<()> {
+        CONSOLE_TRAPPED_WINDOWS.lock().insert(hwnd);
+        chk!(bool; SetConsoleCtrlHandler(Some(console_ctrl_handler), true))
+    }
+
+    /// Unregisters `hwnd` so it no longer receives `WM_CLOSE` on console
+    /// control events. Called once the window's HWND has been destroyed.
+    pub(super) fn unregister_console_trap(hwnd: isize) {
+        CONSOLE_TRAPPED_WINDOWS.lock().remove(&hwnd);
+    }
+
+    /// Console control handler installed by [`register_console_trap`]. Runs
+    /// on a dedicated OS thread created by the system, so it must not touch
+    /// any `!Send` window state directly - it only posts `WM_CLOSE` to every
+    /// currently-registered HWND, letting each window's own thread process
+    /// it through the existing `WM_CLOSE` arm of `handle_message`.
+    extern "system" fn console_ctrl_handler(ctrl_type: u32) -> BOOL {
+        match ctrl_type {
+            CTRL_C_EVENT | CTRL_CLOSE_EVENT | CTRL_SHUTDOWN_EVENT => {
+                for &hwnd in CONSOLE_TRAPPED_WINDOWS.lock().iter() {
+                    let _ = unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0)) };
+                }
+                BOOL(1)
+            }
+            _ => BOOL(0),
+        }
     }
 
     pub(super) struct WindowClass {
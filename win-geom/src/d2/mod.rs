@@ -8,19 +8,53 @@
 //!
 //! If _feature_ `"win32"` is enabled, then some primitives can be directly
 //! converted into a Win32 structures.
+//!
+//! If _feature_ `"serde"` is enabled, then [`Point2D`], [`Size2D`],
+//! [`Rect2D`], [`RoundedRect2D`], and [`Ellipse2D`] derive
+//! [`Serialize`](::serde::Serialize)/[`Deserialize`](::serde::Deserialize).
+//!
+//! If _feature_ `"bytemuck"` is enabled, then `Pod`/`Zeroable` are
+//! implemented for the POD numeric instantiations of those same types,
+//! letting slices of them be safely cast to/from byte buffers.
+//!
+//! # Coordinate spaces
+//!
+//! Every type in this module carries a second, phantom `Unit` parameter (e.g.
+//! `Point2D<f32, ScreenSpace>`) defaulting to [`UnknownUnit`]. It costs
+//! nothing at runtime, but lets callers tag values with the coordinate space
+//! they belong to (window-space, DIPs, a particular monitor's pixels, ...) so
+//! the type checker - not a runtime assertion - catches accidentally mixing
+//! two spaces, e.g. passing a DIP-space point somewhere a pixel-space point
+//! is expected.
 
-use ::num_traits::{AsPrimitive, Num};
-use ::std::{fmt::Debug, ops::Add};
+use ::num_traits::{AsPrimitive, Num, NumCast, ToPrimitive};
+use ::std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Add, Sub},
+};
 
+#[cfg(feature = "bytemuck")]
+pub use bytemuck::*;
 #[cfg(feature = "d2d")]
 pub use d2d::*;
 #[cfg(feature = "win32")]
 pub use win32::*;
 
+/// The default [`Unit`](Point2D) tag for values that haven't been pinned to
+/// a particular coordinate space. Equivalent to leaving the unit unspecified.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnknownUnit;
+
 /// 2D point representation, compatible with any numeric representation.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(bound(serialize = "T: ::serde::Serialize", deserialize = "T: ::serde::Deserialize<'de>"))
+)]
 #[repr(C)]
-pub struct Point2D<T>
+pub struct Point2D<T, Unit = UnknownUnit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -28,9 +62,13 @@ where
     pub x: T,
     /// Co-ordinate along the y axis (vertical).
     pub y: T,
+    /// Zero-sized marker tagging which coordinate space this point belongs
+    /// to. See the [module docs](self) for why this exists.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _unit: PhantomData<Unit>,
 }
 
-impl<T> Default for Point2D<T>
+impl<T, Unit> Default for Point2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -38,14 +76,35 @@ where
         Self {
             x: T::zero(),
             y: T::zero(),
+            _unit: PhantomData,
         }
     }
 }
 
-impl<T> Point2D<T>
+impl<T, Unit> Point2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
+    /// Creates a new [`Point2D`] from its `x`/`y` coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Point2D;
+    ///
+    /// let point = Point2D::<f32>::new(3.3, 4.4);
+    ///
+    /// assert_eq!(point.x, 3.3);
+    /// assert_eq!(point.y, 4.4);
+    /// ```
+    pub fn new(x: T, y: T) -> Self {
+        Self {
+            x,
+            y,
+            _unit: PhantomData,
+        }
+    }
+
     /// Creates a new [`Point2D`] with `{x: 0, y: 0}` in whichever numeric type
     /// is specified by `T`.
     ///
@@ -73,7 +132,7 @@ where
     /// ```
     /// use ::win_geom::d2::Point2D;
     ///
-    /// let float_point = Point2D::<f32> { x: 3.3, y: 4.4 };
+    /// let float_point = Point2D::<f32>::new(3.3, 4.4);
     ///
     /// // Convert our point into an integer point.
     /// let int_point = float_point.cast::<i32>();
@@ -81,16 +140,156 @@ where
     /// assert_eq!(int_point.x, 3_i32);
     /// assert_eq!(int_point.y, 4_i32);
     /// ```
-    pub fn cast<U>(self) -> Point2D<U>
+    pub fn cast<U>(self) -> Point2D<U, Unit>
     where
         T: AsPrimitive<U>,
         U: Num + Clone + Copy + Debug + 'static,
     {
-        Point2D::<U> {
+        Point2D::<U, Unit> {
             x: self.x.as_(),
             y: self.y.as_(),
+            _unit: PhantomData,
+        }
+    }
+
+    /// A fallible counterpart to [`Self::cast`]: converts a [`Point2D`] from
+    /// numeric representation into another, returning `None` if either
+    /// coordinate isn't exactly representable in `U`, rather than silently
+    /// wrapping or saturating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Point2D;
+    ///
+    /// let point = Point2D::new(-3.0_f32, 4.0);
+    ///
+    /// assert_eq!(point.try_cast::<u32>(), None);
+    /// assert_eq!(point.try_cast::<i32>(), Some(Point2D::new(-3, 4)));
+    /// ```
+    pub fn try_cast<U>(self) -> Option<Point2D<U, Unit>>
+    where
+        T: ToPrimitive,
+        U: Num + Clone + Copy + Debug + NumCast,
+    {
+        Some(Point2D::<U, Unit> {
+            x: U::from(self.x)?,
+            y: U::from(self.y)?,
+            _unit: PhantomData,
+        })
+    }
+
+    /// Returns the point reached by moving this point by `by`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Point2D, Vector2D};
+    ///
+    /// let point = Point2D::new(1, 2).translate(Vector2D::new(3, 4));
+    ///
+    /// assert_eq!(point, Point2D::new(4, 6));
+    /// ```
+    pub fn translate(self, by: Vector2D<T, Unit>) -> Self {
+        self + by
+    }
+}
+
+/// 2D displacement representation, compatible with any numeric
+/// representation. Distinct from [`Point2D`] - a location - so that the two
+/// can't be accidentally confused: subtracting two [`Point2D`]s yields a
+/// [`Vector2D`], and adding a [`Vector2D`] to a [`Point2D`] yields another
+/// [`Point2D`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Vector2D<T, Unit = UnknownUnit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Displacement along the x axis (horizontal).
+    pub x: T,
+    /// Displacement along the y axis (vertical).
+    pub y: T,
+    /// Zero-sized marker tagging which coordinate space this vector belongs
+    /// to. See the [module docs](self) for why this exists.
+    _unit: PhantomData<Unit>,
+}
+
+impl<T, Unit> Default for Vector2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    fn default() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, Unit> Vector2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Creates a new [`Vector2D`] from its `x`/`y` displacement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Vector2D;
+    ///
+    /// let vector = Vector2D::<f32>::new(3.3, 4.4);
+    ///
+    /// assert_eq!(vector.x, 3.3);
+    /// assert_eq!(vector.y, 4.4);
+    /// ```
+    pub fn new(x: T, y: T) -> Self {
+        Self {
+            x,
+            y,
+            _unit: PhantomData,
         }
     }
+
+    /// Creates a new [`Vector2D`] with `{x: 0, y: 0}` in whichever numeric
+    /// type is specified by `T`.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, Unit> Sub for Point2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    type Output = Vector2D<T, Unit>;
+
+    /// The displacement from `rhs` to `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Point2D, Vector2D};
+    ///
+    /// let displacement = Point2D::new(4, 6) - Point2D::new(1, 2);
+    ///
+    /// assert_eq!(displacement, Vector2D::new(3, 4));
+    /// ```
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T, Unit> Add<Vector2D<T, Unit>> for Point2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Vector2D<T, Unit>) -> Self::Output {
+        Point2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
 }
 
 /// 2D size representation, compatible with any numeric representation.
@@ -100,8 +299,13 @@ where
 /// If _feature_ `"d2d"` is enabled, then a [`Size2D<u32>`] can be directly
 /// converted into a Direct2D `D2D_SIZE_U` struct.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(bound(serialize = "T: ::serde::Serialize", deserialize = "T: ::serde::Deserialize<'de>"))
+)]
 #[repr(C)]
-pub struct Size2D<T>
+pub struct Size2D<T, Unit = UnknownUnit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -109,9 +313,13 @@ where
     pub width: T,
     /// The extent of the element along the y axis.
     pub height: T,
+    /// Zero-sized marker tagging which coordinate space this size belongs
+    /// to. See the [module docs](self) for why this exists.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _unit: PhantomData<Unit>,
 }
 
-impl<T> Default for Size2D<T>
+impl<T, Unit> Default for Size2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -119,14 +327,35 @@ where
         Self {
             width: T::zero(),
             height: T::zero(),
+            _unit: PhantomData,
         }
     }
 }
 
-impl<T> Size2D<T>
+impl<T, Unit> Size2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
+    /// Creates a new [`Size2D`] from its `width`/`height`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Size2D;
+    ///
+    /// let size = Size2D::<f32>::new(10.3, 10.8);
+    ///
+    /// assert_eq!(size.width, 10.3);
+    /// assert_eq!(size.height, 10.8);
+    /// ```
+    pub fn new(width: T, height: T) -> Self {
+        Self {
+            width,
+            height,
+            _unit: PhantomData,
+        }
+    }
+
     /// Creates a new [`Size2D`] with `{width: 0, height: 0}` in whichever
     /// numeric type is specified by `T`.
     ///
@@ -161,11 +390,12 @@ where
         Self {
             width: T::one(),
             height: T::one(),
+            _unit: PhantomData,
         }
     }
 }
 
-impl<T> Size2D<T>
+impl<T, Unit> Size2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -177,9 +407,9 @@ where
     /// # Example
     ///
     /// ```
-    /// use ::win_geom::d2::{Rect2D, Size2D, Point2D};
+    /// use ::win_geom::d2::Size2D;
     ///
-    /// let size = Size2D { width: 10.3_f32, height: 10.8 };
+    /// let size = Size2D::new(10.3_f32, 10.8);
     ///
     /// // Convert our float size into an integer size compatible with the
     /// // Win32 `RECT` class.
@@ -187,16 +417,44 @@ where
     ///
     /// assert_eq!(uint_size.width, 10);
     /// ```
-    pub fn cast<U>(self) -> Size2D<U>
+    pub fn cast<U>(self) -> Size2D<U, Unit>
     where
         T: AsPrimitive<U>,
         U: Num + Clone + Copy + Debug + 'static,
     {
-        Size2D::<U> {
+        Size2D::<U, Unit> {
             width: self.width.as_(),
             height: self.height.as_(),
+            _unit: PhantomData,
         }
     }
+
+    /// A fallible counterpart to [`Self::cast`]: converts a [`Size2D`] from
+    /// numeric representation into another, returning `None` if either
+    /// dimension isn't exactly representable in `U`, rather than silently
+    /// wrapping or saturating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Size2D;
+    ///
+    /// let size = Size2D::new(-10.0_f32, 10.0);
+    ///
+    /// assert_eq!(size.try_cast::<u32>(), None);
+    /// assert_eq!(size.try_cast::<i32>(), Some(Size2D::new(-10, 10)));
+    /// ```
+    pub fn try_cast<U>(self) -> Option<Size2D<U, Unit>>
+    where
+        T: ToPrimitive,
+        U: Num + Clone + Copy + Debug + NumCast,
+    {
+        Some(Size2D::<U, Unit> {
+            width: U::from(self.width)?,
+            height: U::from(self.height)?,
+            _unit: PhantomData,
+        })
+    }
 }
 
 /// 2D dimensional rectangle, compatible with any numeric representation.
@@ -209,8 +467,13 @@ where
 /// If _feature_ `"win32"` is enabled, then a [`Rect2D<u32>`] can be directly
 /// converted into a Win32 `RECT` struct.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(bound(serialize = "T: ::serde::Serialize", deserialize = "T: ::serde::Deserialize<'de>"))
+)]
 #[repr(C)]
-pub struct Rect2D<T>
+pub struct Rect2D<T, Unit = UnknownUnit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -222,9 +485,13 @@ where
     pub right: T,
     /// The bottom-most edge, or maximum y value.
     pub bottom: T,
+    /// Zero-sized marker tagging which coordinate space this rect belongs
+    /// to. See the [module docs](self) for why this exists.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _unit: PhantomData<Unit>,
 }
 
-impl<T> Default for Rect2D<T>
+impl<T, Unit> Default for Rect2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -234,14 +501,36 @@ where
             top: T::zero(),
             right: T::zero(),
             bottom: T::zero(),
+            _unit: PhantomData,
         }
     }
 }
 
-impl<T> Rect2D<T>
+impl<T, Unit> Rect2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
+    /// Creates a new [`Rect2D`] from its edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Rect2D;
+    ///
+    /// let rect = Rect2D::<f32>::new(0.0, 0.0, 10.0, 10.0);
+    ///
+    /// assert_eq!(rect.right, 10.0);
+    /// ```
+    pub fn new(left: T, top: T, right: T, bottom: T) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+            _unit: PhantomData,
+        }
+    }
+
     /// Creates a new [`Rect2D`] with zero area in whichever numeric
     /// type is specified by `T`.
     ///
@@ -262,7 +551,7 @@ where
     }
 }
 
-impl<T> Rect2D<T>
+impl<T, Unit> Rect2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -275,14 +564,8 @@ where
     /// use ::win_geom::d2::{Rect2D, Size2D, Point2D};
     ///
     /// let rect = Rect2D::<f32>::from_size_and_origin(
-    ///     Size2D {
-    ///         width: 10.0,
-    ///         height: 10.0
-    ///     },
-    ///     Point2D {
-    ///         x: 2.5,
-    ///         y: 5.0,
-    ///     },
+    ///     Size2D::new(10.0, 10.0),
+    ///     Point2D::new(2.5, 5.0),
     /// );
     ///
     /// assert_eq!(rect.left, 2.5);
@@ -290,7 +573,7 @@ where
     /// assert_eq!(rect.top, 5.0);
     /// assert_eq!(rect.bottom, 15.0);
     /// ```
-    pub fn from_size_and_origin(size: Size2D<T>, origin: Point2D<T>) -> Self
+    pub fn from_size_and_origin(size: Size2D<T, Unit>, origin: Point2D<T, Unit>) -> Self
     where
         T: Add<Output = T>,
     {
@@ -299,6 +582,7 @@ where
             top: origin.y,
             right: origin.x + size.width,
             bottom: origin.y + size.height,
+            _unit: PhantomData,
         }
     }
 
@@ -312,6 +596,30 @@ where
         self.bottom - self.top
     }
 
+    /// Returns this rect moved by `by`, preserving its width and height.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Rect2D, Vector2D};
+    ///
+    /// let rect = Rect2D::new(0, 0, 10, 10).translate(Vector2D::new(3, 4));
+    ///
+    /// assert_eq!(rect, Rect2D::new(3, 4, 13, 14));
+    /// ```
+    pub fn translate(&self, by: Vector2D<T, Unit>) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        Self {
+            left: self.left + by.x,
+            top: self.top + by.y,
+            right: self.right + by.x,
+            bottom: self.bottom + by.y,
+            _unit: PhantomData,
+        }
+    }
+
     /// A generic interface which casts a [`Rect2D`] from numeric representation
     /// into another. The cast will never fail but may cause narrowing or
     /// precision loss. The underlying cast operates the same as the `as`
@@ -323,10 +631,7 @@ where
     /// use ::win_geom::d2::{Rect2D, Size2D, Point2D};
     ///
     /// let float_rect = Rect2D::<f32>::from_size_and_origin(
-    ///     Size2D {
-    ///         width: 10.0,
-    ///         height: 10.0
-    ///     },
+    ///     Size2D::new(10.0, 10.0),
     ///     Point2D::zero(),
     /// );
     ///
@@ -336,160 +641,714 @@ where
     ///
     /// assert_eq!(int_rect.right, 10_i32);
     /// ```
-    pub fn cast<U>(self) -> Rect2D<U>
+    pub fn cast<U>(self) -> Rect2D<U, Unit>
     where
         T: AsPrimitive<U>,
         U: Num + Clone + Copy + Debug + 'static,
     {
-        Rect2D::<U> {
+        Rect2D::<U, Unit> {
             left: self.left.as_(),
             top: self.top.as_(),
             right: self.right.as_(),
             bottom: self.bottom.as_(),
+            _unit: PhantomData,
         }
     }
-}
-
-/// 2D dimensional rounded rectangle, compatible with any numeric
-/// representation. Contains the dimensions and corner radii of a rounded
-/// rectangle.
-///
-/// Each corner of the rectangle specified by rect is replaced with a quarter
-/// ellipse, with a radius in each direction specified by radiusX and radiusY.
-///
-/// If [`radius_x`] is greater than or equal to half the width of the rectangle,
-/// and [`radius_y`] is greater than or equal to one-half the height, then the
-/// rounded rectangle is an ellipse with the same width and height of rect.
-///
-/// # Direct2D Note
-///
-/// Even when both [`radius_x`] and [`radius_y`] are zero, a [`RoundedRect2D`]
-/// is different from a [`Rect2D`]. When stroked, the corners of the rounded
-/// rectangle are roundly joined, not mitered (square).
-///
-/// # Conversions
-///
-/// If _feature_ `"d2d"` is enabled, then a [`RoundedRect2D<f32>`] can be
-/// directly converted into a Direct2D `D2D1_ROUNDED_RECT ` struct.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[repr(C)]
-pub struct RoundedRect2D<T>
-where
-    T: Num + Clone + Copy + Debug,
-{
-    /// The coordinates of the base rectangle.
-    pub rect: Rect2D<T>,
-    /// The x-radius for the quarter ellipse that is drawn to replace every
-    /// corner of the rectangle.
-    pub radius_x: T,
-    /// The y-radius for the quarter ellipse that is drawn to replace every
-    /// corner of the rectangle.
-    pub radius_y: T,
-}
-
-impl<T> Default for RoundedRect2D<T>
-where
-    T: Num + Clone + Copy + Debug,
-{
-    fn default() -> Self {
-        Self {
-            rect: Rect2D::zero(),
-            radius_x: T::zero(),
-            radius_y: T::zero(),
-        }
-    }
-}
 
-impl<T> RoundedRect2D<T>
-where
-    T: Num + Clone + Copy + Debug,
-{
-    /// Creates a new [`RoundedRect2D`] with zero area in whichever numeric
-    /// type is specified by `T`.
+    /// A fallible counterpart to [`Self::cast`]: converts a [`Rect2D`] from
+    /// numeric representation into another, returning `None` if any edge
+    /// isn't exactly representable in `U`, rather than silently wrapping or
+    /// saturating. Useful at FFI boundaries like converting a computed
+    /// `Rect2D<f32>` into the `Rect2D<i32>`/`RECT` a Win32 API demands,
+    /// where garbage coordinates should fail loudly instead of transmuting
+    /// into nonsense.
     ///
     /// # Example
     ///
     /// ```
-    /// use ::win_geom::d2::RoundedRect2D;
+    /// use ::win_geom::d2::Rect2D;
     ///
-    /// let empty = RoundedRect2D::<f32>::zero();
+    /// let rect = Rect2D::new(-5.0_f32, 0.0, 10.0, 10.0);
     ///
-    /// assert_eq!(empty.rect.left, 0.0);
-    /// assert_eq!(empty.rect.right, 0.0);
-    /// assert_eq!(empty.rect.top, 0.0);
-    /// assert_eq!(empty.rect.bottom, 0.0);
-    /// assert_eq!(empty.radius_x, 0.0);
-    /// assert_eq!(empty.radius)y, 0.0);
+    /// assert_eq!(rect.try_cast::<u32>(), None);
+    /// assert_eq!(rect.try_cast::<i32>(), Some(Rect2D::new(-5, 0, 10, 10)));
     /// ```
-    pub fn zero() -> Self {
-        Self::default()
+    pub fn try_cast<U>(self) -> Option<Rect2D<U, Unit>>
+    where
+        T: ToPrimitive,
+        U: Num + Clone + Copy + Debug + NumCast,
+    {
+        Some(Rect2D::<U, Unit> {
+            left: U::from(self.left)?,
+            top: U::from(self.top)?,
+            right: U::from(self.right)?,
+            bottom: U::from(self.bottom)?,
+            _unit: PhantomData,
+        })
     }
 }
 
-impl<T> RoundedRect2D<T>
+impl<T, Unit> Rect2D<T, Unit>
 where
-    T: Num + Clone + Copy + Debug,
+    T: Num + Clone + Copy + Debug + PartialOrd,
 {
-    /// Constructs a [`Rect2D`] with a given [`Size2D`], anchored with the
-    /// origin (top-left corner) rooted at `origin`.
+    /// Returns whether this rectangle encloses no area, i.e. its width or
+    /// height is zero or negative, or any edge is NaN (for float `T`; a
+    /// NaN-infected rect has no well-defined area, so it's treated as
+    /// empty rather than silently reporting `false`).
     ///
     /// # Example
     ///
     /// ```
-    /// use ::win_geom::d2::{Rect2D, Size2D, Point2D};
+    /// use ::win_geom::d2::Rect2D;
     ///
-    /// let rect = Rect2D::<f32>::from_size_and_origin(
-    ///     Size2D {
-    ///         width: 10.0,
-    ///         height: 10.0
-    ///     },
-    ///     Point2D {
-    ///         x: 2.5,
-    ///         y: 5.0,
-    ///     },
-    /// );
+    /// let empty = Rect2D::new(5, 5, 5, 10);
+    /// assert!(empty.is_empty());
     ///
-    /// assert_eq!(rect.left, 2.5);
-    /// assert_eq!(rect.right, 12.5);
-    /// assert_eq!(rect.top, 5.0);
-    /// assert_eq!(rect.bottom, 15.0);
+    /// let non_empty = Rect2D::new(0, 0, 10, 10);
+    /// assert!(!non_empty.is_empty());
+    ///
+    /// let nan = Rect2D::new(0.0, 0.0, f32::NAN, 10.0);
+    /// assert!(nan.is_empty());
     /// ```
-    pub fn from_size_and_origin(size: Size2D<T>, origin: Point2D<T>, corner_radius: T) -> Self
-    where
-        T: Add<Output = T>,
-    {
-        Self {
-            rect: Rect2D::from_size_and_origin(size, origin),
-            radius_x: corner_radius,
-            radius_y: corner_radius,
-        }
-    }
+    pub fn is_empty(&self) -> bool {
+        let has_nan = self.left != self.left
+            || self.top != self.top
+            || self.right != self.right
+            || self.bottom != self.bottom;
 
-    /// Returns the width of the rect.
-    pub fn width(&self) -> T {
-        self.rect.width()
+        has_nan || self.left >= self.right || self.top >= self.bottom
     }
 
-    /// Returns the height of the rect.
-    pub fn height(&self) -> T {
-        self.rect.height()
+    /// Returns whether `point` lies within this rectangle, inclusive of the
+    /// left/top edges and exclusive of the right/bottom edges, the same
+    /// half-open convention [`Self::from_size_and_origin`] builds rects
+    /// under.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Point2D, Rect2D};
+    ///
+    /// let rect = Rect2D::new(0, 0, 10, 10);
+    ///
+    /// assert!(rect.contains(Point2D::new(5, 5)));
+    /// assert!(rect.contains(Point2D::new(0, 0)));
+    /// assert!(!rect.contains(Point2D::new(10, 10)));
+    /// ```
+    pub fn contains(&self, point: Point2D<T, Unit>) -> bool {
+        point.x >= self.left && point.x < self.right && point.y >= self.top && point.y < self.bottom
     }
 
-    /// A generic interface which casts a [`RoundedRect2D`] from numeric
-    /// representation into another. The cast will never fail but may cause
-    /// narrowing or precision loss. The underlying cast operates the same as
-    /// the `as` keyword.
+    /// Returns whether this rectangle and `other` overlap by a non-empty
+    /// area.
     ///
     /// # Example
     ///
     /// ```
-    /// use ::win_geom::d2::{RoundedRect2D, Size2D, Point2D};
+    /// use ::win_geom::d2::Rect2D;
+    ///
+    /// let a = Rect2D::new(0, 0, 10, 10);
+    /// let b = Rect2D::new(5, 5, 15, 15);
+    /// let c = Rect2D::new(20, 20, 30, 30);
+    ///
+    /// assert!(a.intersects(&b));
+    /// assert!(!a.intersects(&c));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the overlapping region between this rectangle and `other`, or
+    /// `None` if they don't overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Rect2D;
+    ///
+    /// let a = Rect2D::new(0, 0, 10, 10);
+    /// let b = Rect2D::new(5, 5, 15, 15);
+    /// let c = Rect2D::new(20, 20, 30, 30);
+    ///
+    /// assert_eq!(a.intersection(&b), Some(Rect2D::new(5, 5, 10, 10)));
+    /// assert_eq!(a.intersection(&c), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let result = Self {
+            left: partial_max(self.left, other.left),
+            top: partial_max(self.top, other.top),
+            right: partial_min(self.right, other.right),
+            bottom: partial_min(self.bottom, other.bottom),
+            _unit: PhantomData,
+        };
+
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Returns the smallest rectangle containing both this rectangle and
+    /// `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Rect2D;
+    ///
+    /// let a = Rect2D::new(0, 0, 10, 10);
+    /// let b = Rect2D::new(5, 5, 15, 15);
+    ///
+    /// let bounds = a.union(&b);
+    /// assert_eq!(bounds, Rect2D::new(0, 0, 15, 15));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            left: partial_min(self.left, other.left),
+            top: partial_min(self.top, other.top),
+            right: partial_max(self.right, other.right),
+            bottom: partial_max(self.bottom, other.bottom),
+            _unit: PhantomData,
+        }
+    }
+}
+
+/// Margins on each side of a rectangle, used to grow or shrink one via
+/// [`Rect2D::outer_rect`]/[`Rect2D::inner_rect`] (and their
+/// [`RoundedRect2D`] equivalents).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct SideOffsets2D<T, Unit = UnknownUnit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// The margin added to/subtracted from the top edge.
+    pub top: T,
+    /// The margin added to/subtracted from the right edge.
+    pub right: T,
+    /// The margin added to/subtracted from the bottom edge.
+    pub bottom: T,
+    /// The margin added to/subtracted from the left edge.
+    pub left: T,
+    /// Zero-sized marker tagging which coordinate space these offsets apply
+    /// to. See the [module docs](self) for why this exists.
+    _unit: PhantomData<Unit>,
+}
+
+impl<T, Unit> SideOffsets2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Creates a new [`SideOffsets2D`] with a zero margin on every side.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`SideOffsets2D`] with the same margin, `offset`, on
+    /// every side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::SideOffsets2D;
+    ///
+    /// let offsets = SideOffsets2D::uniform(5);
+    ///
+    /// assert_eq!(offsets.top, 5);
+    /// assert_eq!(offsets.left, 5);
+    /// ```
+    pub fn uniform(offset: T) -> Self {
+        Self {
+            top: offset,
+            right: offset,
+            bottom: offset,
+            left: offset,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, Unit> Rect2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Returns a rectangle expanded outward by `offsets` on each side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Rect2D, SideOffsets2D};
+    ///
+    /// let rect = Rect2D::new(0, 0, 10, 10);
+    /// let grown = rect.outer_rect(SideOffsets2D::uniform(2));
+    ///
+    /// assert_eq!(grown, Rect2D::new(-2, -2, 12, 12));
+    /// ```
+    pub fn outer_rect(&self, offsets: SideOffsets2D<T, Unit>) -> Self {
+        Self {
+            left: self.left - offsets.left,
+            top: self.top - offsets.top,
+            right: self.right + offsets.right,
+            bottom: self.bottom + offsets.bottom,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns a rectangle contracted inward by `offsets` on each side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Rect2D, SideOffsets2D};
+    ///
+    /// let rect = Rect2D::new(0, 0, 10, 10);
+    /// let shrunk = rect.inner_rect(SideOffsets2D::uniform(2));
+    ///
+    /// assert_eq!(shrunk, Rect2D::new(2, 2, 8, 8));
+    /// ```
+    pub fn inner_rect(&self, offsets: SideOffsets2D<T, Unit>) -> Self {
+        Self {
+            left: self.left + offsets.left,
+            top: self.top + offsets.top,
+            right: self.right - offsets.right,
+            bottom: self.bottom - offsets.bottom,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns a rectangle expanded outward by `amount` on every side.
+    /// Shorthand for [`Self::outer_rect`] with a [`SideOffsets2D::uniform`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Rect2D;
+    ///
+    /// let rect = Rect2D::new(0, 0, 10, 10);
+    /// let grown = rect.inflate(2);
+    ///
+    /// assert_eq!(grown, Rect2D::new(-2, -2, 12, 12));
+    /// ```
+    pub fn inflate(&self, amount: T) -> Self {
+        self.outer_rect(SideOffsets2D::uniform(amount))
+    }
+
+    /// Returns a rectangle contracted inward by `amount` on every side.
+    /// Shorthand for [`Self::inner_rect`] with a [`SideOffsets2D::uniform`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Rect2D;
+    ///
+    /// let rect = Rect2D::new(0, 0, 10, 10);
+    /// let shrunk = rect.deflate(2);
+    ///
+    /// assert_eq!(shrunk, Rect2D::new(2, 2, 8, 8));
+    /// ```
+    pub fn deflate(&self, amount: T) -> Self {
+        self.inner_rect(SideOffsets2D::uniform(amount))
+    }
+}
+
+/// The smaller of two `PartialOrd` values, without requiring `Ord` (which
+/// `f32`/`f64` don't implement).
+fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// The larger of two `PartialOrd` values, without requiring `Ord` (which
+/// `f32`/`f64` don't implement).
+fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// 2D dimensional rectangle represented by its two corner points, compatible
+/// with any numeric representation.
+///
+/// Functionally equivalent to [`Rect2D`] - the two convert losslessly back
+/// and forth via `From` - but more convenient when working with corner
+/// [`Point2D`]s directly, e.g. hit-testing against a shape's bounding
+/// corners.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Box2D<T, Unit = UnknownUnit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// The top-left corner.
+    pub min: Point2D<T, Unit>,
+    /// The bottom-right corner.
+    pub max: Point2D<T, Unit>,
+}
+
+impl<T, Unit> Default for Box2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    fn default() -> Self {
+        Self {
+            min: Point2D::zero(),
+            max: Point2D::zero(),
+        }
+    }
+}
+
+impl<T, Unit> Box2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Creates a new [`Box2D`] from its two corner points.
+    pub fn new(min: Point2D<T, Unit>, max: Point2D<T, Unit>) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a new [`Box2D`] with zero area in whichever numeric type is
+    /// specified by `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::Box2D;
+    ///
+    /// let empty = Box2D::<f32>::zero();
+    ///
+    /// assert_eq!(empty.min.x, 0.0);
+    /// assert_eq!(empty.max.x, 0.0);
+    /// ```
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Returns the width of the box.
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x
+    }
+
+    /// Returns the height of the box.
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y
+    }
+
+    /// Returns the size (width and height) of the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Box2D, Point2D, Size2D};
+    ///
+    /// let b = Box2D::new(Point2D::new(2, 3), Point2D::new(10, 15));
+    ///
+    /// assert_eq!(b.size(), Size2D::new(8, 12));
+    /// ```
+    pub fn size(&self) -> Size2D<T, Unit> {
+        Size2D::new(self.width(), self.height())
+    }
+
+    /// Returns the point at the center of the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Box2D, Point2D};
+    ///
+    /// let b = Box2D::new(Point2D::new(0, 0), Point2D::new(10, 20));
+    ///
+    /// assert_eq!(b.center(), Point2D::new(5, 10));
+    /// ```
+    pub fn center(&self) -> Point2D<T, Unit> {
+        let two = T::one() + T::one();
+        Point2D::new(
+            (self.min.x + self.max.x) / two,
+            (self.min.y + self.max.y) / two,
+        )
+    }
+
+    /// A generic interface which casts a [`Box2D`] from numeric
+    /// representation into another. The cast will never fail but may cause
+    /// narrowing or precision loss. The underlying cast operates the same as
+    /// the `as` keyword.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Box2D, Point2D};
+    ///
+    /// let float_box = Box2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.3, 10.8));
+    ///
+    /// let int_box = float_box.cast::<i32>();
+    ///
+    /// assert_eq!(int_box.max.x, 10_i32);
+    /// ```
+    pub fn cast<U>(self) -> Box2D<U, Unit>
+    where
+        T: AsPrimitive<U>,
+        U: Num + Clone + Copy + Debug + 'static,
+    {
+        Box2D::<U, Unit> {
+            min: self.min.cast(),
+            max: self.max.cast(),
+        }
+    }
+
+    /// A fallible counterpart to [`Self::cast`]: converts a [`Box2D`] from
+    /// numeric representation into another, returning `None` if either
+    /// corner isn't exactly representable in `U`, rather than silently
+    /// wrapping or saturating.
+    pub fn try_cast<U>(self) -> Option<Box2D<U, Unit>>
+    where
+        T: ToPrimitive,
+        U: Num + Clone + Copy + Debug + NumCast,
+    {
+        Some(Box2D::<U, Unit> {
+            min: self.min.try_cast()?,
+            max: self.max.try_cast()?,
+        })
+    }
+}
+
+impl<T, Unit> Box2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug + PartialOrd,
+{
+    /// Returns the smallest [`Box2D`] that bounds every point in `points`,
+    /// or `None` if `points` is empty. Cheaper to build incrementally than
+    /// the equivalent [`Rect2D`] since each new point only needs a
+    /// componentwise `min`/`max`, not a width/height recomputation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Box2D, Point2D};
+    ///
+    /// let points = [Point2D::new(5, 5), Point2D::new(0, 10), Point2D::new(10, 2)];
+    /// let bounds = Box2D::from_points(points).unwrap();
+    ///
+    /// assert_eq!(bounds, Box2D::new(Point2D::new(0, 2), Point2D::new(10, 10)));
+    /// ```
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Point2D<T, Unit>>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut min = first;
+        let mut max = first;
+
+        for p in points {
+            min.x = partial_min(min.x, p.x);
+            min.y = partial_min(min.y, p.y);
+            max.x = partial_max(max.x, p.x);
+            max.y = partial_max(max.y, p.y);
+        }
+
+        Some(Self { min, max })
+    }
+}
+
+impl<T, Unit> From<Rect2D<T, Unit>> for Box2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Converts a [`Rect2D`]'s edges into the equivalent two corner points.
+    /// Lossless - the top-left/bottom-right corners are exactly the
+    /// rect's left/top and right/bottom edges.
+    fn from(rect: Rect2D<T, Unit>) -> Self {
+        Self {
+            min: Point2D::new(rect.left, rect.top),
+            max: Point2D::new(rect.right, rect.bottom),
+        }
+    }
+}
+
+impl<T, Unit> From<Box2D<T, Unit>> for Rect2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Converts a [`Box2D`]'s two corner points into the equivalent rect
+    /// edges. Lossless - the inverse of the `From<Rect2D<T>>` conversion
+    /// above.
+    fn from(b: Box2D<T, Unit>) -> Self {
+        Self {
+            left: b.min.x,
+            top: b.min.y,
+            right: b.max.x,
+            bottom: b.max.y,
+            _unit: PhantomData,
+        }
+    }
+}
+
+/// 2D dimensional rounded rectangle, compatible with any numeric
+/// representation. Contains the dimensions and corner radii of a rounded
+/// rectangle.
+///
+/// Each corner of the rectangle specified by rect is replaced with a quarter
+/// ellipse, with a radius in each direction specified by radiusX and radiusY.
+///
+/// If [`radius_x`] is greater than or equal to half the width of the rectangle,
+/// and [`radius_y`] is greater than or equal to one-half the height, then the
+/// rounded rectangle is an ellipse with the same width and height of rect.
+///
+/// # Direct2D Note
+///
+/// Even when both [`radius_x`] and [`radius_y`] are zero, a [`RoundedRect2D`]
+/// is different from a [`Rect2D`]. When stroked, the corners of the rounded
+/// rectangle are roundly joined, not mitered (square).
+///
+/// # Conversions
+///
+/// If _feature_ `"d2d"` is enabled, then a [`RoundedRect2D<f32>`] can be
+/// directly converted into a Direct2D `D2D1_ROUNDED_RECT ` struct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(bound(serialize = "T: ::serde::Serialize", deserialize = "T: ::serde::Deserialize<'de>"))
+)]
+#[repr(C)]
+pub struct RoundedRect2D<T, Unit = UnknownUnit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// The coordinates of the base rectangle.
+    pub rect: Rect2D<T, Unit>,
+    /// The x-radius for the quarter ellipse that is drawn to replace every
+    /// corner of the rectangle.
+    pub radius_x: T,
+    /// The y-radius for the quarter ellipse that is drawn to replace every
+    /// corner of the rectangle.
+    pub radius_y: T,
+}
+
+impl<T, Unit> Default for RoundedRect2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    fn default() -> Self {
+        Self {
+            rect: Rect2D::zero(),
+            radius_x: T::zero(),
+            radius_y: T::zero(),
+        }
+    }
+}
+
+impl<T, Unit> RoundedRect2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Creates a new [`RoundedRect2D`] from a base rect and corner radii.
+    pub fn new(rect: Rect2D<T, Unit>, radius_x: T, radius_y: T) -> Self {
+        Self {
+            rect,
+            radius_x,
+            radius_y,
+        }
+    }
+
+    /// Creates a new [`RoundedRect2D`] with zero area in whichever numeric
+    /// type is specified by `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::RoundedRect2D;
+    ///
+    /// let empty = RoundedRect2D::<f32>::zero();
+    ///
+    /// assert_eq!(empty.rect.left, 0.0);
+    /// assert_eq!(empty.rect.right, 0.0);
+    /// assert_eq!(empty.rect.top, 0.0);
+    /// assert_eq!(empty.rect.bottom, 0.0);
+    /// assert_eq!(empty.radius_x, 0.0);
+    /// assert_eq!(empty.radius_y, 0.0);
+    /// ```
+    pub fn zero() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, Unit> RoundedRect2D<T, Unit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Constructs a [`Rect2D`] with a given [`Size2D`], anchored with the
+    /// origin (top-left corner) rooted at `origin`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Rect2D, Size2D, Point2D};
+    ///
+    /// let rect = Rect2D::<f32>::from_size_and_origin(
+    ///     Size2D::new(10.0, 10.0),
+    ///     Point2D::new(2.5, 5.0),
+    /// );
+    ///
+    /// assert_eq!(rect.left, 2.5);
+    /// assert_eq!(rect.right, 12.5);
+    /// assert_eq!(rect.top, 5.0);
+    /// assert_eq!(rect.bottom, 15.0);
+    /// ```
+    pub fn from_size_and_origin(
+        size: Size2D<T, Unit>,
+        origin: Point2D<T, Unit>,
+        corner_radius: T,
+    ) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        Self {
+            rect: Rect2D::from_size_and_origin(size, origin),
+            radius_x: corner_radius,
+            radius_y: corner_radius,
+        }
+    }
+
+    /// Returns the width of the rect.
+    pub fn width(&self) -> T {
+        self.rect.width()
+    }
+
+    /// Returns the height of the rect.
+    pub fn height(&self) -> T {
+        self.rect.height()
+    }
+
+    /// Returns this rounded rect moved by `by`, with corner radii unchanged.
+    pub fn translate(&self, by: Vector2D<T, Unit>) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        Self {
+            rect: self.rect.translate(by),
+            ..*self
+        }
+    }
+
+    /// A generic interface which casts a [`RoundedRect2D`] from numeric
+    /// representation into another. The cast will never fail but may cause
+    /// narrowing or precision loss. The underlying cast operates the same as
+    /// the `as` keyword.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{RoundedRect2D, Size2D, Point2D};
     ///
     /// let float_rect = RoundedRect2D::<f32>::from_size_and_origin(
-    ///     Size2D {
-    ///         width: 10.0,
-    ///         height: 10.0
-    ///     },
+    ///     Size2D::new(10.0, 10.0),
     ///     Point2D::zero(),
     ///     8.5,
     /// );
@@ -499,17 +1358,65 @@ where
     ///
     /// assert_eq!(int_rect.radius_x, 8_i32);
     /// ```
-    pub fn cast<U>(self) -> RoundedRect2D<U>
+    pub fn cast<U>(self) -> RoundedRect2D<U, Unit>
     where
         T: AsPrimitive<U>,
         U: Num + Clone + Copy + Debug + 'static,
     {
-        RoundedRect2D::<U> {
+        RoundedRect2D::<U, Unit> {
             rect: self.rect.cast(),
             radius_x: self.radius_x.as_(),
             radius_y: self.radius_y.as_(),
         }
     }
+
+    /// A fallible counterpart to [`Self::cast`]: converts a [`RoundedRect2D`]
+    /// from numeric representation into another, returning `None` if the
+    /// rect or either radius isn't exactly representable in `U`, rather than
+    /// silently wrapping or saturating.
+    pub fn try_cast<U>(self) -> Option<RoundedRect2D<U, Unit>>
+    where
+        T: ToPrimitive,
+        U: Num + Clone + Copy + Debug + NumCast,
+    {
+        Some(RoundedRect2D::<U, Unit> {
+            rect: self.rect.try_cast()?,
+            radius_x: U::from(self.radius_x)?,
+            radius_y: U::from(self.radius_y)?,
+        })
+    }
+
+    /// Returns a rounded rect expanded outward by `offsets` on each side,
+    /// with corner radii unchanged.
+    pub fn outer_rect(&self, offsets: SideOffsets2D<T, Unit>) -> Self {
+        Self {
+            rect: self.rect.outer_rect(offsets),
+            ..*self
+        }
+    }
+
+    /// Returns a rounded rect contracted inward by `offsets` on each side,
+    /// with corner radii unchanged.
+    pub fn inner_rect(&self, offsets: SideOffsets2D<T, Unit>) -> Self {
+        Self {
+            rect: self.rect.inner_rect(offsets),
+            ..*self
+        }
+    }
+
+    /// Returns a rounded rect expanded outward by `amount` on every side,
+    /// with corner radii unchanged. Shorthand for [`Self::outer_rect`] with
+    /// a [`SideOffsets2D::uniform`].
+    pub fn inflate(&self, amount: T) -> Self {
+        self.outer_rect(SideOffsets2D::uniform(amount))
+    }
+
+    /// Returns a rounded rect contracted inward by `amount` on every side,
+    /// with corner radii unchanged. Shorthand for [`Self::inner_rect`] with
+    /// a [`SideOffsets2D::uniform`].
+    pub fn deflate(&self, amount: T) -> Self {
+        self.inner_rect(SideOffsets2D::uniform(amount))
+    }
 }
 
 /// 2D dimensional ellipse, compatible with any numeric representation. Contains
@@ -520,20 +1427,25 @@ where
 /// If _feature_ `"d2d"` is enabled, then a [`Ellipse2D<f32>`] can be
 /// directly converted into a Direct2D `D2D1_ELLIPSE` struct.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(bound(serialize = "T: ::serde::Serialize", deserialize = "T: ::serde::Deserialize<'de>"))
+)]
 #[repr(C)]
-pub struct Ellipse2D<T>
+pub struct Ellipse2D<T, Unit = UnknownUnit>
 where
     T: Num + Clone + Copy + Debug,
 {
     /// The center point of the ellipse.
-    pub center: Point2D<T>,
+    pub center: Point2D<T, Unit>,
     /// The X-radius of the ellipse.
     pub radius_x: T,
     /// The Y-radius of the ellipse.
     pub radius_y: T,
 }
 
-impl<T> Default for Ellipse2D<T>
+impl<T, Unit> Default for Ellipse2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -546,7 +1458,7 @@ where
     }
 }
 
-impl<T> Ellipse2D<T>
+impl<T, Unit> Ellipse2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -568,7 +1480,7 @@ where
     }
 }
 
-impl<T> Ellipse2D<T>
+impl<T, Unit> Ellipse2D<T, Unit>
 where
     T: Num + Clone + Copy + Debug,
 {
@@ -579,20 +1491,14 @@ where
     /// ```
     /// use ::win_geom::d2::{Ellipse2D, Point2D};
     ///
-    /// let circle = Ellipse2D::<f32>::new_circle(
-    ///     Point2D {
-    ///         x: 10.0,
-    ///         y: 10.0
-    ///     },
-    ///     20,
-    /// );
+    /// let circle = Ellipse2D::<f32>::new_circle(Point2D::new(10.0, 10.0), 20.0);
     ///
     /// assert_eq!(circle.center.x, 10.0);
     /// assert_eq!(circle.center.y, 10.0);
     /// assert_eq!(circle.radius_x, 20.0);
     /// assert_eq!(circle.radius_y, 20.0);
     /// ```
-    pub fn new_circle(center: Point2D<T>, radius: T) -> Self {
+    pub fn new_circle(center: Point2D<T, Unit>, radius: T) -> Self {
         Self {
             center,
             radius_x: radius,
@@ -600,6 +1506,25 @@ where
         }
     }
 
+    /// Returns this ellipse moved by `by`, with its radii unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Ellipse2D, Point2D, Vector2D};
+    ///
+    /// let circle = Ellipse2D::new_circle(Point2D::new(10.0, 10.0), 20.0)
+    ///     .translate(Vector2D::new(1.0, 2.0));
+    ///
+    /// assert_eq!(circle.center, Point2D::new(11.0, 12.0));
+    /// ```
+    pub fn translate(&self, by: Vector2D<T, Unit>) -> Self {
+        Self {
+            center: self.center.translate(by),
+            ..*self
+        }
+    }
+
     /// A generic interface which casts an [`Ellipse2D`] from numeric
     /// representation into another. The cast will never fail but may cause
     /// narrowing or precision loss. The underlying cast operates the same as
@@ -610,13 +1535,7 @@ where
     /// ```
     /// use ::win_geom::d2::{Ellipse2D, Point2D};
     ///
-    /// let circle = Ellipse2D::<f32>::new_circle(
-    ///     Point2D {
-    ///         x: 3.3,
-    ///         y: 4.4
-    ///     },
-    ///     8.8,
-    /// );
+    /// let circle = Ellipse2D::<f32>::new_circle(Point2D::new(3.3, 4.4), 8.8);
     ///
     /// // Convert our circle into an integer approximation.
     /// let int_circle = circle.cast::<i32>();
@@ -625,17 +1544,133 @@ where
     /// assert_eq!(int_circle.center.y, 4_i32);
     /// assert_eq!(int_circle.radius_x, 8_i32);
     /// ```
-    pub fn cast<U>(self) -> Ellipse2D<U>
+    pub fn cast<U>(self) -> Ellipse2D<U, Unit>
     where
         T: AsPrimitive<U>,
         U: Num + Clone + Copy + Debug + 'static,
     {
-        Ellipse2D::<U> {
+        Ellipse2D::<U, Unit> {
             center: self.center.cast(),
             radius_x: self.radius_x.as_(),
             radius_y: self.radius_y.as_(),
         }
     }
+
+    /// A fallible counterpart to [`Self::cast`]: converts an [`Ellipse2D`]
+    /// from numeric representation into another, returning `None` if the
+    /// center or either radius isn't exactly representable in `U`, rather
+    /// than silently wrapping or saturating.
+    pub fn try_cast<U>(self) -> Option<Ellipse2D<U, Unit>>
+    where
+        T: ToPrimitive,
+        U: Num + Clone + Copy + Debug + NumCast,
+    {
+        Some(Ellipse2D::<U, Unit> {
+            center: self.center.try_cast()?,
+            radius_x: U::from(self.radius_x)?,
+            radius_y: U::from(self.radius_y)?,
+        })
+    }
+}
+
+/// A scalar multiplier that converts a value tagged with the `Src` coordinate
+/// space into the equivalent value tagged with `Dst`, e.g.
+/// `Scale<f32, Dip, Device>` for the DPI factor between DIP and device-pixel
+/// space.
+///
+/// Multiplying a [`Point2D`], [`Size2D`], or [`Rect2D`] by a [`Scale`] (via
+/// `*`) re-tags it with `Dst` and scales every coordinate by
+/// [`Self::factor`], so the type system - not a runtime assertion - catches
+/// multiplying by a scale meant for a different pair of spaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scale<T, Src = UnknownUnit, Dst = UnknownUnit>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// The scalar multiplier converting a `Src`-tagged value into `Dst`.
+    pub factor: T,
+    _unit: PhantomData<(Src, Dst)>,
+}
+
+impl<T, Src, Dst> Scale<T, Src, Dst>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    /// Creates a new [`Scale`] with the given multiplier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Point2D, Scale};
+    ///
+    /// let scale = Scale::<f32>::new(1.5);
+    /// let scaled = Point2D::new(10.0, 20.0) * scale;
+    ///
+    /// assert_eq!(scaled.x, 15.0);
+    /// assert_eq!(scaled.y, 30.0);
+    /// ```
+    pub fn new(factor: T) -> Self {
+        Self {
+            factor,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the [`Scale`] which undoes this one, converting a
+    /// `Dst`-tagged value back into `Src`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::win_geom::d2::{Point2D, Scale};
+    ///
+    /// let scale = Scale::<f32>::new(1.5);
+    /// let round_tripped = Point2D::new(10.0, 20.0) * scale * scale.inverse();
+    ///
+    /// assert_eq!(round_tripped.x, 10.0);
+    /// assert_eq!(round_tripped.y, 20.0);
+    /// ```
+    pub fn inverse(&self) -> Scale<T, Dst, Src> {
+        Scale::new(T::one() / self.factor)
+    }
+}
+
+impl<T, Src, Dst> ::std::ops::Mul<Scale<T, Src, Dst>> for Point2D<T, Src>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    type Output = Point2D<T, Dst>;
+
+    fn mul(self, scale: Scale<T, Src, Dst>) -> Self::Output {
+        Point2D::new(self.x * scale.factor, self.y * scale.factor)
+    }
+}
+
+impl<T, Src, Dst> ::std::ops::Mul<Scale<T, Src, Dst>> for Size2D<T, Src>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    type Output = Size2D<T, Dst>;
+
+    fn mul(self, scale: Scale<T, Src, Dst>) -> Self::Output {
+        Size2D::new(self.width * scale.factor, self.height * scale.factor)
+    }
+}
+
+impl<T, Src, Dst> ::std::ops::Mul<Scale<T, Src, Dst>> for Rect2D<T, Src>
+where
+    T: Num + Clone + Copy + Debug,
+{
+    type Output = Rect2D<T, Dst>;
+
+    fn mul(self, scale: Scale<T, Src, Dst>) -> Self::Output {
+        Rect2D::new(
+            self.left * scale.factor,
+            self.top * scale.factor,
+            self.right * scale.factor,
+            self.bottom * scale.factor,
+        )
+    }
 }
 
 #[cfg(feature = "win32")]
@@ -643,16 +1678,50 @@ mod win32 {
     use super::*;
     use ::windows::Win32::Foundation::RECT;
 
-    impl From<Rect2D<i32>> for RECT {
-        fn from(val: Rect2D<i32>) -> Self {
+    impl<Unit> From<Rect2D<i32, Unit>> for RECT {
+        fn from(val: Rect2D<i32, Unit>) -> Self {
             // SAFETY: our `Rect2D` is modelled on the same memory layout as the
             // windows `RECT` and we restrict this conversion implementation to
-            // rectangles with `i32` representations.
+            // rectangles with `i32` representations. The phantom `Unit`
+            // marker is zero-sized and doesn't affect the layout.
             unsafe { ::std::mem::transmute(val) }
         }
     }
 }
 
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+    use super::*;
+
+    // SAFETY: each of these is `#[repr(C)]`, made up entirely of the POD
+    // numeric instantiation named and a zero-sized `PhantomData<Unit>`
+    // marker, which `bytemuck` already implements `Pod`/`Zeroable` for
+    // unconditionally - so the whole struct has no padding and no invalid
+    // bit patterns regardless of `Unit`.
+    macro_rules! impl_pod {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                unsafe impl<Unit: 'static> ::bytemuck::Zeroable for $ty {}
+                unsafe impl<Unit: 'static> ::bytemuck::Pod for $ty {}
+            )*
+        };
+    }
+
+    impl_pod!(
+        Point2D<f32, Unit>,
+        Point2D<i32, Unit>,
+        Point2D<u32, Unit>,
+        Size2D<f32, Unit>,
+        Size2D<i32, Unit>,
+        Size2D<u32, Unit>,
+        Rect2D<f32, Unit>,
+        Rect2D<i32, Unit>,
+        Rect2D<u32, Unit>,
+        RoundedRect2D<f32, Unit>,
+        Ellipse2D<f32, Unit>,
+    );
+}
+
 #[cfg(feature = "d2d")]
 mod d2d {
     use super::*;
@@ -661,48 +1730,57 @@ mod d2d {
         D2D1_ELLIPSE, D2D1_ROUNDED_RECT,
     };
 
-    impl From<Point2D<f32>> for D2D_POINT_2F {
-        fn from(val: Point2D<f32>) -> Self {
+    impl<Unit> From<Point2D<f32, Unit>> for D2D_POINT_2F {
+        fn from(val: Point2D<f32, Unit>) -> Self {
             // SAFETY: our `Point2D` is modelled on the same memory layout as
             // the Direct2D `D2D_POINT_2F` and we restrict this conversion
-            // implementation to sizes with `f32` representations.
+            // implementation to sizes with `f32` representations. The
+            // phantom `Unit` marker is zero-sized and doesn't affect the
+            // layout.
             unsafe { ::std::mem::transmute(val) }
         }
     }
 
-    impl From<Size2D<u32>> for D2D_SIZE_U {
-        fn from(val: Size2D<u32>) -> Self {
+    impl<Unit> From<Size2D<u32, Unit>> for D2D_SIZE_U {
+        fn from(val: Size2D<u32, Unit>) -> Self {
             // SAFETY: our `Size2D` is modelled on the same memory layout as the
             // Direct2D `D2D_SIZE_U` and we restrict this conversion
-            // implementation to sizes with `u32` representations.
+            // implementation to sizes with `u32` representations. The
+            // phantom `Unit` marker is zero-sized and doesn't affect the
+            // layout.
             unsafe { ::std::mem::transmute(val) }
         }
     }
 
-    impl From<Rect2D<f32>> for D2D_RECT_F {
-        fn from(val: Rect2D<f32>) -> Self {
+    impl<Unit> From<Rect2D<f32, Unit>> for D2D_RECT_F {
+        fn from(val: Rect2D<f32, Unit>) -> Self {
             // SAFETY: our `Rect2D` is modelled on the same memory layout as the
             // Direct2D `D2D_RECT_F` and we restrict this conversion
-            // implementation to rectangles with `f32` representations.
+            // implementation to rectangles with `f32` representations. The
+            // phantom `Unit` marker is zero-sized and doesn't affect the
+            // layout.
             unsafe { ::std::mem::transmute(val) }
         }
     }
 
-    impl From<RoundedRect2D<f32>> for D2D1_ROUNDED_RECT {
-        fn from(val: RoundedRect2D<f32>) -> Self {
+    impl<Unit> From<RoundedRect2D<f32, Unit>> for D2D1_ROUNDED_RECT {
+        fn from(val: RoundedRect2D<f32, Unit>) -> Self {
             // SAFETY: our `RoundedRect2D` is modelled on the same memory layout
             // as the Direct2D `D2D1_ROUNDED_RECT` and we restrict this
             // conversion implementation to rectangles with `f32`
-            // representations.
+            // representations. The phantom `Unit` marker is zero-sized and
+            // doesn't affect the layout.
             unsafe { ::std::mem::transmute(val) }
         }
     }
 
-    impl From<Ellipse2D<f32>> for D2D1_ELLIPSE {
-        fn from(val: Ellipse2D<f32>) -> Self {
+    impl<Unit> From<Ellipse2D<f32, Unit>> for D2D1_ELLIPSE {
+        fn from(val: Ellipse2D<f32, Unit>) -> Self {
             // SAFETY: our `Ellipse2D` is modelled on the same memory layout as
             // the Direct2D `D2D1_ELLIPSE` and we restrict this conversion
-            // implementation to ellipses with `f32` representations.
+            // implementation to ellipses with `f32` representations. The
+            // phantom `Unit` marker is zero-sized and doesn't affect the
+            // layout.
             unsafe { ::std::mem::transmute(val) }
         }
     }
@@ -8,6 +8,15 @@
 //!
 //! If _feature_ `"win32"` is enabled, then some primitives can be directly
 //! converted into a Win32 structures.
+//!
+//! If _feature_ `"serde"` is enabled, then [`Serialize`](::serde::Serialize)
+//! and [`Deserialize`](::serde::Deserialize) are derived for the primitives,
+//! useful for persisting window layouts and scene files.
+//!
+//! If _feature_ `"bytemuck"` is enabled, then [`Pod`](::bytemuck::Pod) and
+//! [`Zeroable`](::bytemuck::Zeroable) are implemented for the primitives'
+//! plain-old-data numeric instantiations, so slices of them can be safely
+//! cast to byte buffers for GPU upload or memory-mapped I/O.
 
 #![deny(rust_2018_idioms)]
 #![warn(missing_docs)]
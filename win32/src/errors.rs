@@ -1,6 +1,22 @@
 //! Crate-specific error and result types, plus common conversions.
 
-use ::windows::core::{Error as Win32Error, HRESULT};
+use ::windows::{
+    core::{Error as Win32Error, HRESULT, PWSTR},
+    Win32::{
+        Foundation::{
+            GetLastError, ERROR_ACCESS_DENIED, ERROR_ALREADY_EXISTS, ERROR_BROKEN_PIPE,
+            ERROR_FILE_EXISTS, ERROR_FILE_NOT_FOUND, ERROR_INSUFFICIENT_BUFFER, ERROR_NO_DATA,
+            ERROR_PATH_NOT_FOUND,
+        },
+        System::Diagnostics::Debug::{
+            FormatMessageW, FORMAT_MESSAGE_FROM_SYSTEM, FORMAT_MESSAGE_IGNORE_INSERTS,
+        },
+    },
+};
+
+/// Upper bound on how large the `FormatMessageW` buffer is allowed to grow in
+/// [`Error::system_message`] before giving up.
+const MAX_SYSTEM_MESSAGE_LEN: usize = 8192;
 
 /// Result type returned by functions that call into Win32 API.
 pub type Result<T> = ::std::result::Result<T, Error>;
@@ -11,7 +27,7 @@ pub type Result<T> = ::std::result::Result<T, Error>;
 #[derive(::thiserror::Error, Debug)]
 pub enum Error {
     /// An unexpected error occurred and was not handled internally.
-    #[error("unexpected win32 error in {function}. {context}")]
+    #[error("unexpected win32 error in {function}. {}", self.system_message())]
     Unexpected {
         /// The name of the function which failed. Typically provided to
         /// [`crate::chk`].
@@ -20,6 +36,10 @@ pub enum Error {
         /// conveniently print any Win32 error codes or system error messages
         /// which were gathered at the point of the error.
         context: Win32Error,
+        /// The `file!()`/`line!()` of the call site which triggered the
+        /// error, captured by [`crate::chk`]. Mirrors comedy's
+        /// `ErrorAndSource::file_line`.
+        location: Option<(&'static str, u32)>,
     },
 }
 
@@ -30,12 +50,101 @@ impl Error {
             Self::Unexpected { context, .. } => Some(context.code()),
         }
     }
+
+    /// Returns the `(file, line)` of the call site which triggered this
+    /// error, if captured.
+    pub fn location(&self) -> Option<(&'static str, u32)> {
+        match self {
+            Self::Unexpected { location, .. } => *location,
+        }
+    }
+
+    /// Looks up the OS's localized description for this error's underlying
+    /// Win32/HRESULT code via `FormatMessageW`, e.g. turning `0x80070005`
+    /// into "Access is denied." Falls back to the raw `context` display if
+    /// the system has no message registered for the code.
+    pub fn system_message(&self) -> String {
+        let Some(code) = self.code() else {
+            return match self {
+                Self::Unexpected { context, .. } => context.to_string(),
+            };
+        };
+
+        let mut buf: Vec<u16> = vec![0; 512];
+        loop {
+            let len = unsafe {
+                FormatMessageW(
+                    FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS,
+                    None,
+                    code.0 as u32,
+                    0,
+                    PWSTR(buf.as_mut_ptr()),
+                    buf.len() as u32,
+                    None,
+                )
+            };
+
+            if len != 0 {
+                return String::from_utf16_lossy(&buf[..len as usize])
+                    .trim_end_matches(['\r', '\n'])
+                    .to_string();
+            }
+
+            if unsafe { GetLastError() } == ERROR_INSUFFICIENT_BUFFER
+                && buf.len() < MAX_SYSTEM_MESSAGE_LEN
+            {
+                buf.resize(buf.len() * 2, 0);
+                continue;
+            }
+
+            return match self {
+                Self::Unexpected { context, .. } => context.to_string(),
+            };
+        }
+    }
+
+    /// Classifies this error's underlying Win32 code into a
+    /// [`std::io::ErrorKind`], so callers can branch on *why* a call failed
+    /// without matching raw HRESULT bits. Codes with no specific mapping
+    /// fall back to [`std::io::ErrorKind::Other`].
+    pub fn kind(&self) -> ::std::io::ErrorKind {
+        use ::std::io::ErrorKind;
+
+        let Some(code) = self.code() else {
+            return ErrorKind::Other;
+        };
+
+        if code == HRESULT::from_win32(ERROR_ACCESS_DENIED.0) {
+            ErrorKind::PermissionDenied
+        } else if code == HRESULT::from_win32(ERROR_ALREADY_EXISTS.0)
+            || code == HRESULT::from_win32(ERROR_FILE_EXISTS.0)
+        {
+            ErrorKind::AlreadyExists
+        } else if code == HRESULT::from_win32(ERROR_FILE_NOT_FOUND.0)
+            || code == HRESULT::from_win32(ERROR_PATH_NOT_FOUND.0)
+        {
+            ErrorKind::NotFound
+        } else if code == HRESULT::from_win32(ERROR_BROKEN_PIPE.0)
+            || code == HRESULT::from_win32(ERROR_NO_DATA.0)
+        {
+            ErrorKind::BrokenPipe
+        } else {
+            ErrorKind::Other
+        }
+    }
+}
+
+impl From<&Error> for ::std::io::ErrorKind {
+    fn from(err: &Error) -> Self {
+        err.kind()
+    }
 }
 
 /// Gets the last Win32 error (the Win32 equivalent of `errno`).
-pub(crate) fn get_last_err(f_name: &'static str) -> Error {
+pub(crate) fn get_last_err(f_name: &'static str, location: (&'static str, u32)) -> Error {
     Error::Unexpected {
         function: f_name,
         context: Win32Error::from_win32(),
+        location: Some(location),
     }
 }
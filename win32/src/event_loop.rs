@@ -0,0 +1,65 @@
+//! A typed event-loop subsystem which pumps a [`Window`]'s Win32 message
+//! queue and delivers decoded [`Event`]s to a user callback, instead of
+//! requiring callers to poll `Window`'s `clear_*_request` methods.
+
+use crate::{
+    errors::Result,
+    window::{Event, Window},
+};
+
+use ::windows::Win32::UI::WindowsAndMessaging::{
+    DispatchMessageW, GetMessageW, PostQuitMessage, TranslateMessage, MSG,
+};
+
+/// Pumps a single [`Window`]'s Win32 message queue, translating raw messages
+/// into a stream of [`Event`]s.
+///
+/// [`Window`]'s own `clear_close_request`/`clear_redraw_request`/
+/// `clear_theme_changed`/`clear_scale_factor_changed` methods remain usable,
+/// but every message they're derived from is also translated into an
+/// [`Event`] and delivered to [`Self::run`]'s callback as it's pumped -
+/// callers driven by an [`EventLoop`] should react to the corresponding
+/// [`Event`] variants instead of polling those methods themselves.
+pub struct EventLoop {
+    window: Window,
+}
+
+impl EventLoop {
+    /// Takes ownership of `window` to drive its message loop.
+    pub fn new(window: Window) -> Self {
+        Self { window }
+    }
+
+    /// The window this [`EventLoop`] pumps messages for.
+    pub fn window(&self) -> &Window {
+        &self.window
+    }
+
+    /// Runs the message loop, blocking until the window is closed.
+    ///
+    /// After every dispatched message, drains [`Window::drain_events`] and
+    /// invokes `callback` with each [`Event`] in the order it occurred. Once
+    /// the window reports a close request, posts `WM_QUIT` to end the loop,
+    /// then invokes `callback` one final time with [`Event::Destroyed`] as
+    /// `self.window` is dropped.
+    pub fn run(mut self, mut callback: impl FnMut(Event)) -> Result<()> {
+        let mut msg = MSG::default();
+        while unsafe { GetMessageW(&mut msg, None, 0, 0) }.as_bool() {
+            unsafe { TranslateMessage(&msg) };
+            unsafe { DispatchMessageW(&msg) };
+
+            for event in self.window.drain_events() {
+                callback(event);
+            }
+
+            if self.window.clear_close_request() {
+                unsafe {
+                    PostQuitMessage(0);
+                }
+            }
+        }
+
+        callback(Event::Destroyed);
+        Ok(())
+    }
+}
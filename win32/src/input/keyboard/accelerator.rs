@@ -0,0 +1,298 @@
+//! Parses human-readable keyboard shortcut strings (e.g. `"Ctrl+Shift+F5"`)
+//! into a typed [`Accelerator`], so games can define keymaps declaratively
+//! (and load them from a config/save file) instead of hand-matching
+//! [`KeyCode`]s.
+
+use super::{KeyCode, KeyEvent};
+
+use ::std::fmt;
+
+/// Which modifier keys an [`Accelerator`] requires, collapsed across
+/// left/right sides: a parsed `"Ctrl+S"` matches either the left or right
+/// Ctrl key, the way OS-level shortcuts usually do. Contrast with
+/// [`super::Modifiers`], which tracks the finer-grained, side-aware state of
+/// currently-held keys.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    /// Either Ctrl key is required.
+    pub ctrl: bool,
+    /// Either Alt key is required.
+    pub alt: bool,
+    /// Either Shift key is required.
+    pub shift: bool,
+    /// Either Windows/Super/Cmd key is required.
+    pub logo: bool,
+}
+
+/// A parsed keyboard shortcut, e.g. `"Ctrl+Shift+F5"` or `"Alt+Space"`,
+/// matched against incoming [`KeyEvent`]s via [`Accelerator::matches`].
+///
+/// Parse one from a string with `TryFrom<&str>`:
+///
+/// ```no_run
+/// # use ::win32::input::keyboard::Accelerator;
+/// let save_as: Accelerator = "Ctrl+Shift+S".try_into().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Accelerator {
+    /// The modifier keys which must be held.
+    pub mods: Modifiers,
+    /// The non-modifier key which must be pressed.
+    pub key: KeyCode,
+}
+
+impl Accelerator {
+    /// Returns whether `event` satisfies this accelerator: `event.key`
+    /// matches exactly, and the modifier keys held at the time (collapsed
+    /// across left/right sides) match this accelerator's [`Modifiers`]
+    /// exactly, neither missing a required modifier nor holding an
+    /// unrequested one.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        if event.key != self.key {
+            return false;
+        }
+
+        let held = Modifiers {
+            ctrl: event.modifiers.ctrl_left || event.modifiers.ctrl_right,
+            alt: event.modifiers.alt_left || event.modifiers.alt_right,
+            shift: event.modifiers.shift_left || event.modifiers.shift_right,
+            logo: event.modifiers.logo_left || event.modifiers.logo_right,
+        };
+
+        held == self.mods
+    }
+
+    /// Resolves the final, non-modifier token of an accelerator string to a
+    /// [`KeyCode`], accepting both exact [`KeyCode`] names (e.g. `"Space"`,
+    /// `"Tab"`, `"F5"`) and the punctuation symbols Windows otherwise names
+    /// after their layout-dependent OEM scan code (`,` `-` `.` `=` `;` `/`
+    /// `\` `` ` `` `[` `]`).
+    fn parse_key(token: &str) -> Option<KeyCode> {
+        Some(match token {
+            "," => KeyCode::OEMComma,
+            "-" => KeyCode::OEMMinus,
+            "." => KeyCode::OEMPeriod,
+            "=" => KeyCode::OEMPlus,
+            ";" => KeyCode::OEM1,
+            "/" => KeyCode::OEM2,
+            "`" => KeyCode::OEM3,
+            "[" => KeyCode::OEM4,
+            "\\" => KeyCode::OEM5,
+            "]" => KeyCode::OEM6,
+            _ => return KeyCode::try_from(token).ok(),
+        })
+    }
+}
+
+/// Error parsing an [`Accelerator`] from a string via `TryFrom<&str>`.
+#[derive(Clone, Debug, PartialEq, Eq, ::thiserror::Error)]
+pub enum ParseAcceleratorError {
+    /// A token before the final `+` wasn't a recognized modifier name
+    /// (`Ctrl`/`Control`, `Alt`, `Shift`, `Super`/`Win`/`Cmd`).
+    #[error("unknown modifier {token:?} in accelerator {accelerator:?}")]
+    UnknownModifier {
+        /// The unrecognized token.
+        token: String,
+        /// The full accelerator string being parsed.
+        accelerator: String,
+    },
+    /// The final token wasn't a recognized [`KeyCode`] name or supported
+    /// punctuation symbol.
+    #[error("unknown key {token:?} in accelerator {accelerator:?}")]
+    UnknownKey {
+        /// The unrecognized token.
+        token: String,
+        /// The full accelerator string being parsed.
+        accelerator: String,
+    },
+    /// The accelerator string was empty, or ended in a trailing `+` with no
+    /// key following it.
+    #[error("accelerator {accelerator:?} has no key")]
+    NoKey {
+        /// The full accelerator string being parsed.
+        accelerator: String,
+    },
+}
+
+impl TryFrom<&str> for Accelerator {
+    type Error = ParseAcceleratorError;
+
+    /// Parses an accelerator string such as `"Ctrl+Shift+F5"` or
+    /// `"Alt+Space"`. Modifier tokens are matched case-insensitively; the
+    /// final, non-modifier token is the key and is matched as described in
+    /// [`Accelerator::parse_key`].
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut tokens: Vec<&str> = s.split('+').map(str::trim).collect();
+
+        let key_token = tokens.pop().filter(|t| !t.is_empty()).ok_or_else(|| {
+            ParseAcceleratorError::NoKey {
+                accelerator: s.to_string(),
+            }
+        })?;
+
+        let mut mods = Modifiers::default();
+        for token in tokens {
+            match token.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => mods.ctrl = true,
+                "alt" => mods.alt = true,
+                "shift" => mods.shift = true,
+                "super" | "win" | "cmd" => mods.logo = true,
+                _ => {
+                    return Err(ParseAcceleratorError::UnknownModifier {
+                        token: token.to_string(),
+                        accelerator: s.to_string(),
+                    })
+                }
+            }
+        }
+
+        let key = Self::parse_key(key_token).ok_or_else(|| ParseAcceleratorError::UnknownKey {
+            token: key_token.to_string(),
+            accelerator: s.to_string(),
+        })?;
+
+        Ok(Accelerator { mods, key })
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.mods.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.mods.alt {
+            write!(f, "Alt+")?;
+        }
+        if self.mods.shift {
+            write!(f, "Shift+")?;
+        }
+        if self.mods.logo {
+            write!(f, "Super+")?;
+        }
+        write!(f, "{}", self.key.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::keyboard::{event::Modifiers as RawModifiers, KeyLocation, ScanCode};
+
+    #[test]
+    fn test_parse_basic() {
+        let accel = Accelerator::try_from("Ctrl+Shift+F5").unwrap();
+        assert_eq!(
+            accel.mods,
+            Modifiers {
+                ctrl: true,
+                shift: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(accel.key, KeyCode::F5);
+    }
+
+    #[test]
+    fn test_parse_is_case_insensitive() {
+        let accel = Accelerator::try_from("ctrl+ALT+space").unwrap();
+        assert_eq!(
+            accel.mods,
+            Modifiers {
+                ctrl: true,
+                alt: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(accel.key, KeyCode::Space);
+    }
+
+    #[test]
+    fn test_parse_super_aliases() {
+        for alias in ["Super", "Win", "Cmd"] {
+            let accel = Accelerator::try_from(format!("{alias}+,").as_str()).unwrap();
+            assert!(accel.mods.logo);
+            assert_eq!(accel.key, KeyCode::OEMComma);
+        }
+    }
+
+    #[test]
+    fn test_parse_punctuation_key() {
+        let accel = Accelerator::try_from("Ctrl+.").unwrap();
+        assert_eq!(accel.key, KeyCode::OEMPeriod);
+    }
+
+    #[test]
+    fn test_parse_no_modifiers() {
+        let accel = Accelerator::try_from("Escape").unwrap();
+        assert_eq!(accel.mods, Modifiers::default());
+        assert_eq!(accel.key, KeyCode::Escape);
+    }
+
+    #[test]
+    fn test_parse_unknown_modifier() {
+        let err = Accelerator::try_from("Fctrl+A").unwrap_err();
+        assert_eq!(
+            err,
+            ParseAcceleratorError::UnknownModifier {
+                token: "Fctrl".to_string(),
+                accelerator: "Fctrl+A".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_key() {
+        let err = Accelerator::try_from("Ctrl+Nonsense").unwrap_err();
+        assert_eq!(
+            err,
+            ParseAcceleratorError::UnknownKey {
+                token: "Nonsense".to_string(),
+                accelerator: "Ctrl+Nonsense".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_no_key() {
+        let err = Accelerator::try_from("Ctrl+").unwrap_err();
+        assert_eq!(
+            err,
+            ParseAcceleratorError::NoKey {
+                accelerator: "Ctrl+".to_string(),
+            }
+        );
+
+        let err = Accelerator::try_from("").unwrap_err();
+        assert_eq!(
+            err,
+            ParseAcceleratorError::NoKey {
+                accelerator: "".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let accel = Accelerator::try_from("Ctrl+Shift+F5").unwrap();
+        assert_eq!(accel.to_string(), "Ctrl+Shift+F5");
+    }
+
+    #[test]
+    fn test_matches() {
+        let accel = Accelerator::try_from("Ctrl+Shift+S").unwrap();
+
+        let mut event = KeyEvent {
+            key: KeyCode::S,
+            scancode: ScanCode::from_raw(0x1F),
+            location: KeyLocation::Standard,
+            repeat: false,
+            modifiers: RawModifiers::default(),
+        };
+        event.modifiers.ctrl_left = true;
+        event.modifiers.shift_right = true;
+        assert!(accel.matches(&event));
+
+        event.modifiers.ctrl_left = false;
+        assert!(!accel.matches(&event));
+    }
+}
@@ -2,12 +2,27 @@
 //! counterparts.
 
 use ::deku::prelude::*;
+use ::std::slice;
+use ::widestring::U16CStr;
 use ::windows::Win32::{
-    Foundation::{LPARAM, WPARAM},
-    UI::WindowsAndMessaging::{WM_CHAR, WM_KEYDOWN, WM_KEYUP, WM_SYSKEYDOWN, WM_SYSKEYUP},
+    Foundation::{HGLOBAL, HWND, LPARAM, WPARAM},
+    System::{
+        DataExchange::{CloseClipboard, GetClipboardData, OpenClipboard, CF_UNICODETEXT},
+        Memory::{GlobalLock, GlobalUnlock},
+    },
+    UI::{
+        Input::Ime::{
+            ImmGetCompositionStringW, ImmGetContext, ImmReleaseContext, GCS_COMPSTR,
+            GCS_CURSORPOS, GCS_RESULTSTR, IME_COMPOSITION_STRING,
+        },
+        WindowsAndMessaging::{
+            WM_CHAR, WM_IME_COMPOSITION, WM_IME_ENDCOMPOSITION, WM_IME_STARTCOMPOSITION,
+            WM_KEYDOWN, WM_KEYUP, WM_PASTE, WM_SYSKEYDOWN, WM_SYSKEYUP,
+        },
+    },
 };
 
-use super::{KeyCode, KeyEvent};
+use super::{KeyCode, RawKeyEvent, ScanCode};
 
 /// Win32 Keystroke message flags as defined here:
 /// https://learn.microsoft.com/en-us/windows/win32/inputdev/about-keyboard-input#keystroke-message-flags
@@ -65,42 +80,175 @@ pub(crate) struct Adapter {}
 impl Adapter {
     /// Indicates whether the [Adapter] handles the given message. If it does,
     /// [adapt] should be called and the message should be considered handled
-    /// regardless of whether a [KeyEvent] is generated.
+    /// regardless of whether a [RawKeyEvent] is generated.
     pub(crate) const fn handles_msg(umsg: u32, _wparam: WPARAM, _lparam: LPARAM) -> bool {
         matches!(
             umsg,
-            WM_KEYDOWN | WM_SYSKEYDOWN | WM_KEYUP | WM_SYSKEYUP | WM_CHAR
+            WM_KEYDOWN
+                | WM_SYSKEYDOWN
+                | WM_KEYUP
+                | WM_SYSKEYUP
+                | WM_CHAR
+                | WM_PASTE
+                | WM_IME_STARTCOMPOSITION
+                | WM_IME_COMPOSITION
+                | WM_IME_ENDCOMPOSITION
         )
     }
 
-    /// Adapts a Win32 windows procedure function into a [KeyEvent]. This
+    /// Adapts a Win32 windows procedure function into a [RawKeyEvent]. This
     /// function should only be called if [handles_msg] indicated that the
     /// [Adapter] will handle a wnd proc message with these parameters.
-    pub(crate) fn adapt(umsg: u32, wparam: WPARAM, lparam: LPARAM) -> Option<KeyEvent> {
+    ///
+    /// `hwnd` is only consulted for the `WM_IME_*` messages, which need it to
+    /// look up the window's input context via `ImmGetContext`.
+    pub(crate) fn adapt(
+        hwnd: HWND,
+        umsg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> Option<RawKeyEvent> {
         match umsg {
             WM_KEYDOWN | WM_SYSKEYDOWN => {
+                let flags = KeystrokeFlags::from(lparam);
                 KeyCode::try_from(wparam.0)
                     .ok()
-                    .map(|key_code| KeyEvent::KeyDown {
-                        key_code,
-                        flags: lparam.into(),
+                    .map(|key_code| RawKeyEvent::KeyDown {
+                        key_code: Self::disambiguate_modifier(key_code, &flags),
+                        flags,
                     })
             }
             WM_KEYUP | WM_SYSKEYUP => {
+                let flags = KeystrokeFlags::from(lparam);
                 KeyCode::try_from(wparam.0)
                     .ok()
-                    .map(|key_code| KeyEvent::KeyUp {
-                        key_code,
-                        flags: lparam.into(),
+                    .map(|key_code| RawKeyEvent::KeyUp {
+                        key_code: Self::disambiguate_modifier(key_code, &flags),
+                        flags,
                     })
             }
-            WM_CHAR => Some(KeyEvent::Input {
+            WM_CHAR => Some(RawKeyEvent::Input {
                 wchar: wparam.0 as u16,
                 flags: lparam.into(),
             }),
+            WM_PASTE => Self::resolve_clipboard_text().map(|text| RawKeyEvent::Paste { text }),
+            WM_IME_STARTCOMPOSITION => Some(RawKeyEvent::CompositionStart),
+            WM_IME_COMPOSITION => {
+                let flags = lparam.0 as u32;
+                if flags & GCS_RESULTSTR.0 as u32 != 0 {
+                    // The IME has committed a result string. Surface it as the
+                    // composition's end rather than waiting for the
+                    // `WM_IME_ENDCOMPOSITION` that follows, since that message
+                    // no longer has a composition string to retrieve.
+                    Some(RawKeyEvent::CompositionEnd {
+                        text: Self::composition_string(hwnd, GCS_RESULTSTR).unwrap_or_default(),
+                    })
+                } else if flags & GCS_COMPSTR.0 as u32 != 0 {
+                    Some(RawKeyEvent::CompositionUpdate {
+                        text: Self::composition_string(hwnd, GCS_COMPSTR).unwrap_or_default(),
+                        cursor: Self::composition_cursor(hwnd),
+                    })
+                } else {
+                    None
+                }
+            }
+            WM_IME_ENDCOMPOSITION => Some(RawKeyEvent::CompositionEnd {
+                text: String::new(),
+            }),
             _ => None,
         }
     }
+
+    /// Retrieves the composition string at `index` (`GCS_COMPSTR` for the
+    /// in-progress text, `GCS_RESULTSTR` for the just-committed text) from
+    /// `hwnd`'s input context.
+    fn composition_string(hwnd: HWND, index: IME_COMPOSITION_STRING) -> Option<String> {
+        unsafe {
+            let himc = ImmGetContext(hwnd);
+            if himc.0 == 0 {
+                return None;
+            }
+
+            let len = ImmGetCompositionStringW(himc, index, None, 0);
+            let text = if len > 0 {
+                let mut buf = vec![0u8; len as usize];
+                ImmGetCompositionStringW(
+                    himc,
+                    index,
+                    Some(buf.as_mut_ptr() as *mut _),
+                    len as u32,
+                );
+                let wide = slice::from_raw_parts(buf.as_ptr() as *const u16, buf.len() / 2);
+                Some(String::from_utf16_lossy(wide))
+            } else {
+                Some(String::new())
+            };
+
+            let _ = ImmReleaseContext(hwnd, himc);
+            text
+        }
+    }
+
+    /// Retrieves the cursor position within the in-progress composition
+    /// string, in UTF-16 code units from its start.
+    fn composition_cursor(hwnd: HWND) -> usize {
+        unsafe {
+            let himc = ImmGetContext(hwnd);
+            if himc.0 == 0 {
+                return 0;
+            }
+
+            let cursor = ImmGetCompositionStringW(himc, GCS_CURSORPOS, None, 0);
+
+            let _ = ImmReleaseContext(hwnd, himc);
+            cursor.max(0) as usize
+        }
+    }
+
+    /// Resolves the generic `Shift`/`Control`/`Menu` virtual key Windows
+    /// reports in `wParam` (which doesn't distinguish left from right) down
+    /// to its concrete [`KeyCode::LeftShift`]/[`KeyCode::RightShift`]/etc,
+    /// using `flags`' scan code (and extended-key bit) to look up the
+    /// side-specific virtual key via `MapVirtualKeyW`. Any other key is
+    /// returned unchanged.
+    fn disambiguate_modifier(key_code: KeyCode, flags: &KeystrokeFlags) -> KeyCode {
+        if !matches!(key_code, KeyCode::Shift | KeyCode::Control | KeyCode::Menu) {
+            return key_code;
+        }
+
+        let scan_code = flags.scan_code as u16 | if flags.is_extended_key { 0xE000 } else { 0 };
+
+        ScanCode::from_raw(scan_code)
+            .to_keycode()
+            .unwrap_or(key_code)
+    }
+
+    /// Reads the current clipboard contents as Unicode text, if any is
+    /// present in `CF_UNICODETEXT` format and decodes to valid UTF-16.
+    fn resolve_clipboard_text() -> Option<String> {
+        // `OpenClipboard(None)` associates the clipboard with the current
+        // task rather than a specific window, which is fine for a read-only
+        // peek at its contents.
+        unsafe { OpenClipboard(None) }.ok()?;
+
+        let text = (|| {
+            let handle = unsafe { GetClipboardData(CF_UNICODETEXT.0 as u32) }.ok()?;
+            let ptr = unsafe { GlobalLock(HGLOBAL(handle.0)) };
+            if ptr.is_null() {
+                return None;
+            }
+
+            let text = unsafe { U16CStr::from_ptr_str(ptr as *const u16) }
+                .to_string()
+                .ok();
+
+            let _ = unsafe { GlobalUnlock(HGLOBAL(handle.0)) };
+            text
+        })();
+
+        let _ = unsafe { CloseClipboard() };
+        text
+    }
 }
 
 #[cfg(test)]
@@ -113,11 +261,11 @@ mod tests {
     #[test]
     fn test_key_down() {
         // Event captured via `debug::DebugMsg` dump.
-        let event = Adapter::adapt(WM_KEYDOWN, WPARAM(0x48), LPARAM(0x230001)).unwrap();
+        let event = Adapter::adapt(HWND(0), WM_KEYDOWN, WPARAM(0x48), LPARAM(0x230001)).unwrap();
 
         assert_eq!(
             event,
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::H,
                 flags: KeystrokeFlags {
                     repeat_count: 1,
@@ -135,11 +283,11 @@ mod tests {
     #[test]
     fn test_char_event() {
         // Event captured via `debug::DebugMsg` dump.
-        let event = Adapter::adapt(WM_CHAR, WPARAM(0x68), LPARAM(0x230001)).unwrap();
+        let event = Adapter::adapt(HWND(0), WM_CHAR, WPARAM(0x68), LPARAM(0x230001)).unwrap();
 
         assert_eq!(
             event,
-            KeyEvent::Input {
+            RawKeyEvent::Input {
                 wchar: b'h' as u16,
                 flags: KeystrokeFlags {
                     repeat_count: 1,
@@ -157,12 +305,12 @@ mod tests {
     #[test]
     fn test_key_up() {
         // Event captured via `debug::DebugMsg` dump.
-        let event = Adapter::adapt(WM_KEYUP, WPARAM(0x48), LPARAM(0xC0230001))
+        let event = Adapter::adapt(HWND(0), WM_KEYUP, WPARAM(0x48), LPARAM(0xC0230001))
             .expect("Valid KEYDOWN event should be parsed");
 
         assert_eq!(
             event,
-            KeyEvent::KeyUp {
+            RawKeyEvent::KeyUp {
                 key_code: KeyCode::H,
                 flags: KeystrokeFlags {
                     repeat_count: 1,
@@ -180,11 +328,11 @@ mod tests {
     #[test]
     fn test_key_down_with_modifier() {
         // Event captured via `debug::DebugMsg` dump.
-        let event = Adapter::adapt(WM_SYSKEYDOWN, WPARAM(0x48), LPARAM(0x20230001)).unwrap();
+        let event = Adapter::adapt(HWND(0), WM_SYSKEYDOWN, WPARAM(0x48), LPARAM(0x20230001)).unwrap();
 
         assert_eq!(
             event,
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::H,
                 flags: KeystrokeFlags {
                     repeat_count: 1,
@@ -202,11 +350,11 @@ mod tests {
     #[test]
     fn test_key_up_with_modifiers() {
         // Event captured via `debug::DebugMsg` dump.
-        let event = Adapter::adapt(WM_SYSKEYUP, WPARAM(0x48), LPARAM(0xE0230001)).unwrap();
+        let event = Adapter::adapt(HWND(0), WM_SYSKEYUP, WPARAM(0x48), LPARAM(0xE0230001)).unwrap();
 
         assert_eq!(
             event,
-            KeyEvent::KeyUp {
+            RawKeyEvent::KeyUp {
                 key_code: KeyCode::H,
                 flags: KeystrokeFlags {
                     repeat_count: 1,
@@ -224,11 +372,11 @@ mod tests {
     #[test]
     fn test_key_down_with_repeat() {
         // Event captured via `debug::DebugMsg` dump.
-        let event = Adapter::adapt(WM_KEYDOWN, WPARAM(0x48), LPARAM(0x40230001)).unwrap();
+        let event = Adapter::adapt(HWND(0), WM_KEYDOWN, WPARAM(0x48), LPARAM(0x40230001)).unwrap();
 
         assert_eq!(
             event,
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::H,
                 flags: KeystrokeFlags {
                     repeat_count: 1,
@@ -241,4 +389,26 @@ mod tests {
             }
         );
     }
+
+    /// Starting an IME composition session.
+    #[test]
+    fn test_ime_composition_start() {
+        let event = Adapter::adapt(HWND(0), WM_IME_STARTCOMPOSITION, WPARAM(0), LPARAM(0)).unwrap();
+
+        assert_eq!(event, RawKeyEvent::CompositionStart);
+    }
+
+    /// Ending an IME composition session without ever retrieving the result
+    /// string (e.g. the composition was cancelled).
+    #[test]
+    fn test_ime_composition_end() {
+        let event = Adapter::adapt(HWND(0), WM_IME_ENDCOMPOSITION, WPARAM(0), LPARAM(0)).unwrap();
+
+        assert_eq!(
+            event,
+            RawKeyEvent::CompositionEnd {
+                text: String::new()
+            }
+        );
+    }
 }
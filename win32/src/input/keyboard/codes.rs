@@ -1,10 +1,34 @@
+//! Strongly-typed key codes: both the layout-dependent virtual [`KeyCode`]
+//! and its layout-independent physical [`ScanCode`] counterpart.
+
+use crate::invoke::chk;
+
+use ::std::char::REPLACEMENT_CHARACTER;
+use ::windows::{
+    core::PWSTR,
+    Win32::UI::Input::KeyboardAndMouse::{
+        GetKeyNameTextW, MapVirtualKeyW, ToUnicodeEx, MAPVK_VK_TO_VSC_EX, MAPVK_VSC_TO_VK_EX,
+        VK_CAPITAL, VK_CONTROL, VK_MENU, VK_SHIFT,
+    },
+};
+pub use ::windows::Win32::UI::Input::KeyboardAndMouse::HKL;
+
 /// Key codes for key-pressed and key-released events. These are not the same as
 /// the unicode characters which result from keyboard entry, and should
 /// therefore not be used for text input but instead for simple key
 /// pressed/released tracking (useful for instance if using the keyboard to
 /// control a game).
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ::strum::EnumIter)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    ::strum::EnumIter,
+    ::strum::EnumString,
+    ::strum::IntoStaticStr,
+)]
 pub enum KeyCode {
     /// Left mouse button
     LeftMouseButton = 0x01,
@@ -351,6 +375,158 @@ impl KeyCode {
     pub const fn value(&self) -> u8 {
         *self as u8
     }
+
+    /// A stable, layout-independent identifier for this key (e.g.
+    /// `"LeftShift"`, `"F13"`, `"OEMPlus"`), suitable for serializing user
+    /// keybinding configs to TOML/JSON and parsing them back with
+    /// [`KeyCode::try_from`]`::<&str>`.
+    ///
+    /// This is distinct from [`Self::display_label`], which returns an
+    /// OS-localized, human-readable label for the same key.
+    pub fn name(&self) -> &'static str {
+        (*self).into()
+    }
+
+    /// The OS-localized, human-readable label for this key (e.g. "Shift",
+    /// "Right Ctrl", "F13"), suitable for display in UI prompts such as
+    /// "press ... to continue".
+    ///
+    /// Unlike [`Self::name`], this label depends on the current keyboard
+    /// layout and system language, and should not be used for serialization.
+    pub fn display_label(&self) -> crate::errors::Result<String> {
+        let scancode = self.to_scancode().map(ScanCode::value).unwrap_or(0);
+        let is_extended = scancode & 0xFF00 != 0;
+
+        // The lParam expected by `GetKeyNameTextW` mirrors a keystroke
+        // message's lParam: bits 16-23 hold the scan code and bit 24 the
+        // extended-key flag.
+        let lparam = (i32::from(scancode & 0xFF) << 16) | (i32::from(is_extended) << 24);
+
+        let mut buf = [0u16; 64];
+        let len = chk!(last_err; GetKeyNameTextW(lparam, PWSTR(buf.as_mut_ptr()), buf.len() as i32))?;
+
+        Ok(String::from_utf16_lossy(&buf[..len as usize]))
+    }
+
+    /// Maps this virtual key to the physical [`ScanCode`] it occupies on the
+    /// current keyboard layout, or `None` if the key has no scancode (e.g.
+    /// the mouse button "keys").
+    ///
+    /// Uses `MAPVK_VK_TO_VSC_EX` rather than the non-`_EX` mapping so that
+    /// extended keys (right Ctrl/Alt, the arrow cluster, numpad) produce
+    /// their distinct 0xE0-prefixed scancodes instead of colliding with
+    /// their non-extended twins.
+    pub fn to_scancode(&self) -> Option<ScanCode> {
+        let scancode = unsafe { MapVirtualKeyW(self.value() as u32, MAPVK_VK_TO_VSC_EX) };
+
+        if scancode == 0 {
+            None
+        } else {
+            Some(ScanCode(scancode as u16))
+        }
+    }
+
+    /// Translates this virtual key to the Unicode text it produces given the
+    /// currently held `modifiers` and keyboard `layout`, mirroring what the
+    /// OS would deliver via `WM_CHAR`.
+    ///
+    /// Unlike [`Self::value`]/[`Self::to_scancode`], this is layout-aware and
+    /// safe to use for text input (the doc comment on [`KeyCode`] itself
+    /// warns against using the bare virtual key for that purpose).
+    pub fn to_text(&self, scancode: ScanCode, modifiers: TextModifiers, layout: HKL) -> TranslatedText {
+        let mut key_state = [0u8; 256];
+
+        if modifiers.shift {
+            key_state[VK_SHIFT.0 as usize] = 0x80;
+        }
+        if modifiers.ctrl || modifiers.alt_gr {
+            key_state[VK_CONTROL.0 as usize] = 0x80;
+        }
+        if modifiers.alt_gr {
+            key_state[VK_MENU.0 as usize] = 0x80;
+        }
+        if modifiers.caps_lock {
+            key_state[VK_CAPITAL.0 as usize] = 0x01;
+        }
+
+        let vk = self.value() as u32;
+        let scancode = scancode.value() as u32;
+
+        let mut buf = [0u16; 8];
+        let result = unsafe {
+            ToUnicodeEx(
+                vk,
+                scancode,
+                &key_state,
+                PWSTR(buf.as_mut_ptr()),
+                buf.len() as i32,
+                0,
+                layout,
+            )
+        };
+
+        match result {
+            0 => TranslatedText::None,
+            -1 => {
+                // The layout produced a dead key. Its combining character has
+                // been returned in `buf`, but the kernel also latched the
+                // dead-key state for the *next* translation. Re-issue the
+                // same translation once more (discarding the result) so that
+                // state is cleared and subsequent calls aren't corrupted.
+                let mut discard = [0u16; 8];
+                unsafe {
+                    ToUnicodeEx(
+                        vk,
+                        scancode,
+                        &key_state,
+                        PWSTR(discard.as_mut_ptr()),
+                        discard.len() as i32,
+                        0,
+                        layout,
+                    )
+                };
+
+                let ch = char::decode_utf16([buf[0]])
+                    .next()
+                    .and_then(|r| r.ok())
+                    .unwrap_or(REPLACEMENT_CHARACTER);
+                TranslatedText::DeadKey(ch)
+            }
+            n if n > 0 => TranslatedText::Text(String::from_utf16_lossy(&buf[..n as usize])),
+            _ => TranslatedText::None,
+        }
+    }
+}
+
+/// Which modifier keys are held down, used to select the correctly
+/// shifted/alt-gr'd character when translating a [`KeyCode`] to text via
+/// [`KeyCode::to_text`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextModifiers {
+    /// Either SHIFT key is held down.
+    pub shift: bool,
+    /// Either CTRL key is held down.
+    pub ctrl: bool,
+    /// The right-hand ALT key is held down (reported by Windows as
+    /// `Ctrl+Alt`, the so-called "AltGr" combination).
+    pub alt_gr: bool,
+    /// CAPS LOCK is currently toggled on.
+    pub caps_lock: bool,
+}
+
+/// The result of translating a [`KeyCode`] to text via [`KeyCode::to_text`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TranslatedText {
+    /// The key combination produces no text (e.g. a pure modifier key, or a
+    /// non-printing control key).
+    None,
+    /// The key combination produces the given text.
+    Text(String),
+    /// The key combination is a dead key: it produces no text by itself, but
+    /// will be combined with the next keystroke by the caller (e.g. `^` then
+    /// `e` produces `ê`). The dead key's own combining character is provided
+    /// so it can be displayed to the user while composition is in progress.
+    DeadKey(char),
 }
 
 impl TryFrom<u16> for KeyCode {
@@ -366,6 +542,16 @@ impl TryFrom<u16> for KeyCode {
     }
 }
 
+impl TryFrom<&str> for KeyCode {
+    type Error = ::strum::ParseError;
+
+    /// Parses a [`KeyCode`] back from the identifier previously returned by
+    /// [`KeyCode::name`]. Equivalent to `s.parse()`.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 /// An internal lookup table wich maps between Win32 virtual key codes and our
 /// strong `KeyCode` type.
 const LookupTable: [Option<KeyCode>; 256] = [
@@ -626,3 +812,38 @@ const LookupTable: [Option<KeyCode>; 256] = [
     Some(KeyCode::OEMClear),
     None,
 ];
+
+/// A physical key position, identified by its Win32 scancode rather than by
+/// the (layout-dependent) virtual key it currently produces.
+///
+/// Unlike [`KeyCode`], a [`ScanCode`] stays stable across keyboard layouts, so
+/// it's the right identity to bind against for physical-position input (e.g.
+/// "WASD"/"ZQSD" movement keys in a game), whereas [`KeyCode`] is the right
+/// choice when the virtual-key label itself matters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScanCode(u16);
+
+impl ScanCode {
+    /// Constructs a [`ScanCode`] directly from a raw Win32 scancode, e.g. one
+    /// decoded from the keystroke message flags in a `WM_KEYDOWN` `lParam`.
+    pub(crate) const fn from_raw(value: u16) -> Self {
+        Self(value)
+    }
+
+    pub const fn value(&self) -> u16 {
+        self.0
+    }
+
+    /// Maps this physical scancode back to the virtual [`KeyCode`] it
+    /// currently produces on the current keyboard layout, or `None` if the
+    /// scancode doesn't correspond to a known virtual key.
+    pub fn to_keycode(&self) -> Option<KeyCode> {
+        let vk = unsafe { MapVirtualKeyW(self.0 as u32, MAPVK_VSC_TO_VK_EX) };
+
+        if vk == 0 {
+            None
+        } else {
+            KeyCode::try_from(vk as u16).ok()
+        }
+    }
+}
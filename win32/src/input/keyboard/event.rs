@@ -0,0 +1,191 @@
+//! A typed, fully-decoded representation of a single key press/release,
+//! bundling the virtual key, physical scancode, keyboard-side location,
+//! repeat state and currently held modifiers into one value.
+
+use crate::{errors::Result, invoke::chk};
+
+use super::{KeyCode, KeystrokeFlags, ScanCode};
+
+use ::windows::Win32::{
+    Foundation::{LPARAM, WPARAM},
+    UI::Input::KeyboardAndMouse::{
+        GetKeyboardState, VIRTUAL_KEY, VK_CAPITAL, VK_LCONTROL, VK_LMENU, VK_LSHIFT, VK_LWIN,
+        VK_NUMLOCK, VK_RCONTROL, VK_RMENU, VK_RSHIFT, VK_RWIN, VK_SCROLL,
+    },
+};
+
+/// Which side of the keyboard a key occupies, for keys that come in
+/// left/right pairs (shift, ctrl, alt, logo) or on the numeric keypad.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyLocation {
+    /// A key with no left/right/numpad distinction, e.g. letters and digits.
+    Standard,
+    /// The left-hand variant of a paired key.
+    Left,
+    /// The right-hand variant of a paired key.
+    Right,
+    /// A key on the numeric keypad.
+    Numpad,
+}
+
+/// Which modifier keys are currently held down, tracked per left/right side
+/// since the two sides are independently addressable on Win32.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    /// The left SHIFT key is held down.
+    pub shift_left: bool,
+    /// The right SHIFT key is held down.
+    pub shift_right: bool,
+    /// The left CTRL key is held down.
+    pub ctrl_left: bool,
+    /// The right CTRL key is held down.
+    pub ctrl_right: bool,
+    /// The left ALT key is held down.
+    pub alt_left: bool,
+    /// The right ALT key is held down.
+    pub alt_right: bool,
+    /// The left Windows ("logo") key is held down.
+    pub logo_left: bool,
+    /// The right Windows ("logo") key is held down.
+    pub logo_right: bool,
+    /// Caps Lock is currently toggled on.
+    pub caps_lock: bool,
+    /// Num Lock is currently toggled on.
+    pub num_lock: bool,
+    /// Scroll Lock is currently toggled on.
+    pub scroll_lock: bool,
+}
+
+impl Modifiers {
+    /// Captures the currently held modifier keys via `GetKeyboardState`.
+    fn capture() -> Result<Self> {
+        let mut state = [0u8; 256];
+        chk!(bool; GetKeyboardState(&mut state))?;
+
+        let is_down = |vk: VIRTUAL_KEY| state[vk.0 as usize] & 0x80 != 0;
+        // For toggle keys, `GetKeyboardState` reports the toggled-on state in
+        // the byte's low-order bit rather than whether the key is held down.
+        let is_toggled = |vk: VIRTUAL_KEY| state[vk.0 as usize] & 0x01 != 0;
+
+        Ok(Self {
+            shift_left: is_down(VK_LSHIFT),
+            shift_right: is_down(VK_RSHIFT),
+            ctrl_left: is_down(VK_LCONTROL),
+            ctrl_right: is_down(VK_RCONTROL),
+            alt_left: is_down(VK_LMENU),
+            alt_right: is_down(VK_RMENU),
+            logo_left: is_down(VK_LWIN),
+            logo_right: is_down(VK_RWIN),
+            caps_lock: is_toggled(VK_CAPITAL),
+            num_lock: is_toggled(VK_NUMLOCK),
+            scroll_lock: is_toggled(VK_SCROLL),
+        })
+    }
+}
+
+/// A fully-decoded key press or release event.
+///
+/// Unlike the bare [`KeyCode`], this carries everything downstream
+/// message-loop code typically needs in one typed value: the physical
+/// [`ScanCode`], which side of the keyboard the key is on, whether this is an
+/// auto-repeated keystroke, and the modifier keys held at the time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyEvent {
+    /// The virtual key that was pressed or released.
+    pub key: KeyCode,
+    /// The physical scancode of the key, stable across keyboard layouts.
+    pub scancode: ScanCode,
+    /// Which side of the keyboard the key is on.
+    pub location: KeyLocation,
+    /// Whether this is an auto-repeated keystroke, i.e. the key was already
+    /// held down when this event was generated.
+    pub repeat: bool,
+    /// The modifier keys held down at the time of this event.
+    pub modifiers: Modifiers,
+}
+
+impl KeyEvent {
+    /// Decodes a raw `WM_KEYDOWN`/`WM_KEYUP` (or `WM_SYSKEYDOWN`/
+    /// `WM_SYSKEYUP`) `wParam`/`lParam` pair into a [`KeyEvent`].
+    ///
+    /// Returns `Ok(None)` if `wparam` doesn't correspond to a known
+    /// [`KeyCode`].
+    pub fn from_message(wparam: WPARAM, lparam: LPARAM) -> Result<Option<Self>> {
+        let Some(key) = KeyCode::try_from(wparam.0).ok() else {
+            return Ok(None);
+        };
+
+        let flags = KeystrokeFlags::from(lparam);
+        let scancode = ScanCode::from_raw(
+            flags.scan_code as u16 | if flags.is_extended_key { 0xE000 } else { 0 },
+        );
+
+        Ok(Some(Self {
+            key,
+            scancode,
+            location: Self::location_for(key, &flags),
+            repeat: flags.was_previous_state_down,
+            modifiers: Modifiers::capture()?,
+        }))
+    }
+
+    /// Derives the keyboard-side location of `key` from its [`KeyCode`]
+    /// variant (for keys with explicit left/right/numpad variants) or, for
+    /// the generic Shift/Ctrl/Alt keys, from the keystroke message flags.
+    ///
+    /// Shared with [`Keyboard`](super::Keyboard), which uses it to attribute
+    /// a generic Shift/Ctrl/Alt transition to the correct side of
+    /// [`Modifiers`] as it maintains its own running aggregate.
+    pub(crate) fn location_for(key: KeyCode, flags: &KeystrokeFlags) -> KeyLocation {
+        match key {
+            KeyCode::LeftShift
+            | KeyCode::LeftControl
+            | KeyCode::LeftAlt
+            | KeyCode::LeftWindows => KeyLocation::Left,
+
+            KeyCode::RightShift
+            | KeyCode::RightControl
+            | KeyCode::RightAlt
+            | KeyCode::RightWindows => KeyLocation::Right,
+
+            KeyCode::Numpad0
+            | KeyCode::Numpad1
+            | KeyCode::Numpad2
+            | KeyCode::Numpad3
+            | KeyCode::Numpad4
+            | KeyCode::Numpad5
+            | KeyCode::Numpad6
+            | KeyCode::Numpad7
+            | KeyCode::Numpad8
+            | KeyCode::Numpad9
+            | KeyCode::Multiply
+            | KeyCode::Add
+            | KeyCode::Separator
+            | KeyCode::Subtract
+            | KeyCode::Decimal
+            | KeyCode::Divide => KeyLocation::Numpad,
+
+            // The generic Shift key's lParam is never flagged extended;
+            // disambiguate left/right using its well-known scancodes.
+            KeyCode::Shift => {
+                if flags.scan_code == 0x36 {
+                    KeyLocation::Right
+                } else {
+                    KeyLocation::Left
+                }
+            }
+
+            // Ctrl and Alt share a single virtual key across both sides, but
+            // the right-hand key is always reported as "extended".
+            KeyCode::Control | KeyCode::Menu => {
+                if flags.is_extended_key {
+                    KeyLocation::Right
+                } else {
+                    KeyLocation::Left
+                }
+            }
+
+            _ => KeyLocation::Standard,
+        }
+    }
+}
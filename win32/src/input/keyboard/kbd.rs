@@ -1,48 +1,166 @@
 //! Keyboard state and text input tracking.
 
 use ::bitvec::prelude::*;
-use ::std::{char::REPLACEMENT_CHARACTER, collections::VecDeque};
-use ::tracing::trace;
+use ::std::{
+    char::REPLACEMENT_CHARACTER,
+    time::{Duration, Instant},
+};
 use ::widestring::WideChar;
 
-use super::{KeyCode, KeystrokeFlags};
-
-/// Length of the input queue, after which point the earliest characters are
-/// dropped.
-const INPUT_QUEUE_CAPACITY: usize = 32;
+use super::{KeyCode, KeyEvent, KeyLocation, KeystrokeFlags, Modifiers};
 
 const BACKSPACE: char = '\x08';
 
-/// An object which encapsulates the state of the input buffer.
-pub struct InputBuffer<I>
-where
-    I: ExactSizeIterator<Item = char>,
-{
-    chars: I,
-    n_backspaces: usize,
+/// Caps the number of repeats [`Keyboard::poll_repeats`] synthesizes in a
+/// single call, so that a long stall between polls (e.g. the process being
+/// suspended, or a slow frame) doesn't release a burst of queued-up repeats
+/// once polling resumes.
+const MAX_CATCH_UP_REPEATS: usize = 8;
+
+/// Configures [`Keyboard`]'s auto-repeat subsystem, set via
+/// [`Keyboard::configure_repeat`].
+///
+/// Auto-repeat is opt-in: until this is set, [`Keyboard::poll_repeats`] never
+/// yields anything, matching the previous behavior of simply ignoring
+/// `KeyDown` events whose `was_previous_state_down` flag is set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RepeatConfig {
+    /// How long a key must be held before it starts auto-repeating.
+    pub delay: Duration,
+    /// The steady-state interval between repeats once they've started.
+    pub rate: Duration,
+}
+
+/// Tracks the single most-recently-pressed repeatable key, matching how
+/// Windows (and every other OS) only ever auto-repeats one key at a time.
+struct RepeatState {
+    key: KeyCode,
+    /// When the key was first pressed.
+    press_time: Instant,
+    /// When the next repeat is due, `None` until the initial `delay` has
+    /// elapsed.
+    next_fire: Option<Instant>,
+}
+
+/// Returns whether `key` is a modifier key, which never auto-repeats on its
+/// own (only the keys pressed while it's held do).
+fn is_modifier_key(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::Shift
+            | KeyCode::Control
+            | KeyCode::Menu
+            | KeyCode::LeftShift
+            | KeyCode::RightShift
+            | KeyCode::LeftControl
+            | KeyCode::RightControl
+            | KeyCode::LeftAlt
+            | KeyCode::RightAlt
+            | KeyCode::LeftWindows
+            | KeyCode::RightWindows
+    )
+}
+
+/// Returns whether `wchar` is a UTF-16 high surrogate, i.e. the first half
+/// of a surrogate pair.
+fn is_high_surrogate(wchar: WideChar) -> bool {
+    (0xD800..=0xDBFF).contains(&wchar)
+}
+
+/// A line-editing text buffer with a cursor, so that arrow/Home/End/Delete
+/// edit in place rather than the previous model of only ever appending
+/// characters or trimming from the tail.
+struct LineBuffer {
+    chars: Vec<char>,
+    cursor: usize,
 }
 
-impl<I> InputBuffer<I>
-where
-    I: ExactSizeIterator<Item = char>,
-{
-    /// The number of backspaces which preceded any text in the [Self::chars]
-    /// buffer and should be removed from to any _previously_ drained input.
-    pub fn num_backspaces(&self) -> usize {
-        self.n_backspaces
+impl LineBuffer {
+    fn new() -> Self {
+        Self {
+            chars: Vec::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Inserts `c` at the cursor and advances the cursor past it.
+    fn insert(&mut self, c: char) {
+        self.chars.insert(self.cursor, c);
+        self.cursor += 1;
+    }
+
+    /// Deletes the character before the cursor, if any.
+    fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    /// Deletes the character at the cursor, if any.
+    fn delete(&mut self) {
+        if self.cursor < self.chars.len() {
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.chars.len());
+    }
+
+    fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    fn move_end(&mut self) {
+        self.cursor = self.chars.len();
+    }
+
+    fn clear(&mut self) {
+        self.chars.clear();
+        self.cursor = 0;
+    }
+}
+
+/// A view of the current line-editing buffer, returned by
+/// [`Keyboard::input_buffer`]: the full current line, where the cursor sits
+/// within it, and the most recent pasted run, if any.
+pub struct InputBuffer<'a> {
+    line: &'a [char],
+    cursor: usize,
+    paste: Option<String>,
+}
+
+impl InputBuffer<'_> {
+    /// The current line's contents, left to right. Any edits (inserts,
+    /// backspace, delete, pastes) have already been applied.
+    pub fn chars(&self) -> impl ExactSizeIterator<Item = char> + '_ {
+        self.line.iter().copied()
+    }
+
+    /// The cursor's position, as a character index into [`Self::chars`].
+    pub fn cursor(&self) -> usize {
+        self.cursor
     }
 
-    /// The current input buffer. Any backspace events which happened within
-    /// the buffer have already been applied to the buffer contents.
-    pub fn chars(&mut self) -> &mut I {
-        &mut self.chars
+    /// The most recently pasted run of text, if a paste was processed since
+    /// the last call to [`Keyboard::input_buffer`]. Its characters are
+    /// already reflected in [`Self::chars`]; this is surfaced separately so
+    /// consumers can treat a paste as one undoable unit rather than as a
+    /// burst of individual keystrokes.
+    pub fn paste(&self) -> Option<&str> {
+        self.paste.as_deref()
     }
 }
 
 /// A representation of a Win32 virtual key event. These are purely internal and
 /// are consumed by the `Keyboard` type.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) enum KeyEvent {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum RawKeyEvent {
     KeyDown {
         key_code: KeyCode,
         flags: KeystrokeFlags,
@@ -55,50 +173,127 @@ pub(crate) enum KeyEvent {
         wchar: WideChar,
         flags: KeystrokeFlags,
     },
+    /// A bulk run of pasted text, resolved from the clipboard. Inserted into
+    /// the line buffer atomically, bypassing the per-char control-character
+    /// filtering [`Keyboard::process_char_input`] applies to `Input`.
+    Paste {
+        text: String,
+    },
+    /// An IME composition session has started. No text is available yet;
+    /// [`CompositionUpdate`](Self::CompositionUpdate) follows as the user
+    /// continues composing.
+    CompositionStart,
+    /// The in-progress IME composition string changed. Not yet committed to
+    /// the line buffer; purely informational so the candidate text can be
+    /// rendered at `cursor`.
+    CompositionUpdate {
+        text: String,
+        cursor: usize,
+    },
+    /// The IME composition session ended. `text` is the composed result if
+    /// the composition was committed, or empty if it was cancelled. The
+    /// committed text itself still arrives separately via `WM_CHAR`/`Input`.
+    CompositionEnd {
+        text: String,
+    },
 }
 
 /// A simple abstraction over keyboard input to help track pressed keys and a
-/// queue of text input.
+/// line-editing buffer of text input.
+///
+/// Key state is double-buffered: [`Self::snapshot`] copies the current press
+/// state into the previous one, so [`Self::just_pressed`] can detect a
+/// transition exactly once per call to [`Self::snapshot`], no matter how many
+/// `WM_KEYDOWN`/`WM_KEYUP` messages land in between.
+///
+/// The aggregate [`Modifiers`] state is also maintained incrementally as
+/// `KeyDown`/`KeyUp` events are processed, available via [`Self::modifiers`]
+/// and [`Self::drain_modifiers_changed`], so consumers implementing
+/// shortcuts don't need to correlate left/right Ctrl scancodes themselves.
 pub struct Keyboard {
-    /// Bitfield which tracks the press state for the keyboard keys.
-    pressed: BitArr!(for 255, in usize, Lsb0),
-    /// A queue of printable input text which has been fully processed into
-    /// valid unicode.
-    input_queue: VecDeque<char>,
-    /// The number of pending backspace events which should be applied to any
-    /// previously retrieved text.
-    n_backspaces: usize,
+    /// Bitfield which tracks the press state for the keyboard keys as of the
+    /// most recent processed event.
+    current: BitArr!(for 255, in usize, Lsb0),
+    /// Snapshot of [`Self::current`] taken by the last call to
+    /// [`Self::snapshot`], used to detect edge-triggered transitions.
+    previous: BitArr!(for 255, in usize, Lsb0),
+    /// The current line of printable input text, fully decoded into valid
+    /// unicode, with its cursor position.
+    line: LineBuffer,
     /// High surrogate entry from a surrogate pair. This is `Some` pending
     /// receipt of the following low surrogate. Once the low surrogate arrives,
-    /// the pair can be converted into a character and appended to
-    /// `input_queue`.
+    /// the pair can be converted into a character and inserted into `line`.
     pending_surrogate: Option<WideChar>,
+    /// Auto-repeat configuration set via [`Self::configure_repeat`]. `None`
+    /// until configured, in which case [`Self::poll_repeats`] never fires.
+    repeat_config: Option<RepeatConfig>,
+    /// The currently-held repeatable key, if any, and its repeat schedule.
+    repeat: Option<RepeatState>,
+    /// The aggregate modifier state, maintained incrementally from the
+    /// `KeyDown`/`KeyUp` stream rather than polled via `GetKeyboardState`.
+    modifiers: Modifiers,
+    /// Whether [`Self::modifiers`] has changed since the last call to
+    /// [`Self::drain_modifiers_changed`].
+    modifiers_changed: bool,
+    /// The most recently pasted run of text, if a `Paste` event has been
+    /// processed since the last call to [`Self::input_buffer`]. Already
+    /// applied to `line` by the time it's set here.
+    pending_paste: Option<String>,
 }
 
 impl Keyboard {
     pub(crate) fn new() -> Self {
         Self {
-            pressed: bitarr![usize, Lsb0; 0; 255],
-            input_queue: VecDeque::with_capacity(INPUT_QUEUE_CAPACITY),
-            n_backspaces: 0,
+            current: bitarr![usize, Lsb0; 0; 255],
+            previous: bitarr![usize, Lsb0; 0; 255],
+            line: LineBuffer::new(),
             pending_surrogate: None,
+            repeat_config: None,
+            repeat: None,
+            modifiers: Modifiers::default(),
+            modifiers_changed: false,
+            pending_paste: None,
         }
     }
 
     /// Process an event from the Win32 system and update internal state. This
-    /// event will be reflected in the next user call to [is_key_pressed] or
-    pub(crate) fn process_evt(&mut self, evt: KeyEvent) {
+    /// event will be reflected in the next user call to [`Self::is_down`] or
+    pub(crate) fn process_evt(&mut self, evt: RawKeyEvent) {
         match evt {
-            KeyEvent::KeyDown { key_code, flags } => {
+            RawKeyEvent::KeyDown { key_code, flags } => {
+                self.apply_line_edit(key_code);
+
                 if !flags.was_previous_state_down {
                     *self.mut_bit_for_key(key_code).as_mut() = true;
+                    self.apply_modifier(key_code, &flags, true);
+
+                    if self.repeat_config.is_some() && !is_modifier_key(key_code) {
+                        self.repeat = Some(RepeatState {
+                            key: key_code,
+                            press_time: Instant::now(),
+                            next_fire: None,
+                        });
+                    }
                 }
             }
-            KeyEvent::KeyUp { key_code, .. } => {
+            RawKeyEvent::KeyUp { key_code, flags } => {
                 *self.mut_bit_for_key(key_code).as_mut() = false;
+                self.apply_modifier(key_code, &flags, false);
+
+                if self.repeat.as_ref().is_some_and(|r| r.key == key_code) {
+                    self.repeat = None;
+                }
             }
-            KeyEvent::Input { wchar, .. } => {
+            RawKeyEvent::Input { wchar, .. } => {
                 match self.pending_surrogate.take() {
+                    // A second high surrogate arrived before its partner low
+                    // surrogate. The stale one can never be completed, so it
+                    // becomes a replacement character and the new one takes
+                    // its place waiting for a low surrogate.
+                    Some(_high) if is_high_surrogate(wchar) => {
+                        self.process_char_input([REPLACEMENT_CHARACTER]);
+                        self.pending_surrogate = Some(wchar);
+                    }
                     Some(high) => {
                         let low = wchar;
                         // Combine surrogates & append to input queue. If anything fails at this
@@ -121,68 +316,212 @@ impl Keyboard {
                     },
                 }
             }
+            RawKeyEvent::Paste { text } => {
+                // Inserted directly rather than through `process_char_input`,
+                // so a pasted control character (e.g. a tab from pasted code)
+                // isn't silently dropped the way one typed character-by-character
+                // would be.
+                for c in text.chars() {
+                    self.line.insert(c);
+                }
+                self.pending_paste = Some(text);
+            }
+            // IME composition state is purely informational for rendering
+            // candidate text; the composed result still arrives through its
+            // own `Input`/`WM_CHAR` events, so `Keyboard` has nothing further
+            // to track here.
+            RawKeyEvent::CompositionStart
+            | RawKeyEvent::CompositionUpdate { .. }
+            | RawKeyEvent::CompositionEnd { .. } => {}
         }
     }
 
-    pub fn is_key_pressed(&self, key: KeyCode) -> bool {
-        *self.bit_for_key(key).as_ref()
+    /// Returns whether `key` is currently held down.
+    pub fn is_down(&self, key: KeyCode) -> bool {
+        *self.current_bit(key).as_ref()
     }
 
-    /// Drains all accumulated characters in the input queue and clears any
-    /// pending backspace events.
-    pub fn drain_input(&mut self) -> InputBuffer<impl ExactSizeIterator<Item = char> + '_> {
-        let n_backspaces = self.n_backspaces;
-        self.n_backspaces = 0;
+    /// Returns whether `key` transitioned from up to down since the last call
+    /// to [`Self::snapshot`]. Edge-triggered, so a key held across multiple
+    /// [`Self::snapshot`] calls without being released only reports `true`
+    /// once.
+    pub fn just_pressed(&self, key: KeyCode) -> bool {
+        *self.current_bit(key).as_ref() && !*self.previous_bit(key).as_ref()
+    }
 
+    /// Snapshots the current press state as the "previous" state, so that
+    /// [`Self::just_pressed`] can detect transitions that happen before the
+    /// next call to this method. Should be called once at the top of each
+    /// simulation step, before this step's window messages are processed.
+    pub fn snapshot(&mut self) {
+        self.previous = self.current;
+    }
+
+    /// Returns a view of the current line-editing buffer: its full text,
+    /// cursor position, and the most recent paste, if any. Unlike the
+    /// previous drain-based queue, this doesn't clear the line - it persists
+    /// across calls, edited in place by subsequent key events, until the
+    /// caller (or [`Self::reset`]) clears it. The pasted-run view, however,
+    /// is taken, so it's only reported once.
+    pub fn input_buffer(&mut self) -> InputBuffer<'_> {
         InputBuffer {
-            n_backspaces,
-            chars: self.input_queue.drain(..),
+            line: &self.line.chars,
+            cursor: self.line.cursor,
+            paste: self.pending_paste.take(),
         }
     }
 
     /// Reset all keyboard state.
     pub fn reset(&mut self) {
-        self.input_queue.clear();
+        self.line.clear();
         self.pending_surrogate = None;
-        self.pressed = BitArray::ZERO;
+        self.pending_paste = None;
+        self.current = BitArray::ZERO;
+        self.previous = BitArray::ZERO;
+        self.repeat = None;
+        self.modifiers = Modifiers::default();
+        self.modifiers_changed = false;
+    }
+
+    /// Returns the currently held modifier keys, tracked incrementally from
+    /// the `KeyDown`/`KeyUp` stream.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Returns the current [`Modifiers`] if they've changed since the last
+    /// call to this method, or [`None`] if the aggregate set is unchanged.
+    ///
+    /// Follows the same drain-and-clear shape as [`Self::input_buffer`], but
+    /// since only the latest aggregate value is ever meaningful to a
+    /// consumer, there's nothing to queue - just a pending change flag that
+    /// this clears.
+    pub fn drain_modifiers_changed(&mut self) -> Option<Modifiers> {
+        self.modifiers_changed.then(|| {
+            self.modifiers_changed = false;
+            self.modifiers
+        })
     }
 
-    /// Handles character input and appends or modifies the input queue. The
-    /// char iterator could contain only a single char, multiple characters, and
-    /// could include control characters such as backspace or delete.
-    /// [process_char_input] will account for deletion events.
+    /// Opts into key auto-repeat, synthesizing additional presses of the
+    /// held-down key via [`Self::poll_repeats`] after `delay` has elapsed,
+    /// at a steady-state cadence of `rate`.
+    pub fn configure_repeat(&mut self, delay: Duration, rate: Duration) {
+        self.repeat_config = Some(RepeatConfig { delay, rate });
+    }
+
+    /// Returns the auto-repeated keys due as of `now`, if any.
+    ///
+    /// Only the most-recently-pressed non-modifier key repeats, matching OS
+    /// behavior. Intended to be called once per frame/tick from the owning
+    /// message loop; a stall between calls longer than several repeat
+    /// intervals is clamped rather than replayed in full, to avoid a burst of
+    /// queued-up repeats once polling resumes.
+    pub fn poll_repeats(&mut self, now: Instant) -> impl Iterator<Item = KeyCode> {
+        let mut fires = Vec::new();
+
+        if let (Some(config), Some(state)) = (self.repeat_config, self.repeat.as_mut()) {
+            if state.next_fire.is_none() && now.duration_since(state.press_time) >= config.delay {
+                state.next_fire = Some(state.press_time + config.delay);
+            }
+
+            if let Some(mut next_fire) = state.next_fire {
+                while now >= next_fire && fires.len() < MAX_CATCH_UP_REPEATS {
+                    fires.push(state.key);
+                    next_fire += config.rate;
+                }
+
+                // Hit the catch-up cap with more repeats still owed (a long
+                // stall) - resync to `now` instead of leaving `next_fire` far
+                // in the past, which would just repeat the burst next poll.
+                if fires.len() == MAX_CATCH_UP_REPEATS && now >= next_fire {
+                    next_fire = now + config.rate;
+                }
+
+                state.next_fire = Some(next_fire);
+            }
+        }
+
+        fires.into_iter()
+    }
+
+    /// Handles character input, inserting into [`Self::line`] at the cursor.
+    /// The char iterator could contain only a single char, multiple
+    /// characters, and could include control characters such as backspace.
     fn process_char_input<I>(&mut self, chars: I)
     where
         I: IntoIterator<Item = char>,
     {
-        let chars = chars.into_iter();
         for c in chars {
             match c {
-                // TODO: detect delete
-                BACKSPACE => {
-                    if self.input_queue.pop_back().is_none() {
-                        self.n_backspaces += 1;
-                    }
-                }
+                BACKSPACE => self.line.backspace(),
                 // Drop any control characters that are not whitespace
                 _ if c.is_control() && !c.is_whitespace() => (),
-                _ => self.input_queue.push_back(c),
+                _ => self.line.insert(c),
             }
         }
+    }
 
-        // Trim queue to avoid growing continuously
-        while self.input_queue.len() >= INPUT_QUEUE_CAPACITY {
-            let char = self.input_queue.pop_front().unwrap();
-            trace!("Trimming keyboard input queue, dropped '{char}'.");
+    /// Applies the line-editing effect, if any, of a raw key-down to
+    /// [`Self::line`]. Arrow keys, Home/End and Delete arrive as
+    /// `WM_KEYDOWN` rather than `WM_CHAR`, so they're applied directly here
+    /// rather than through [`Self::process_char_input`]. Called for every
+    /// `WM_KEYDOWN`, including auto-repeats, so a held arrow key keeps
+    /// moving the cursor the same way it would in any text editor.
+    fn apply_line_edit(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Left => self.line.move_left(),
+            KeyCode::Right => self.line.move_right(),
+            KeyCode::Home => self.line.move_home(),
+            KeyCode::End => self.line.move_end(),
+            KeyCode::Delete => self.line.delete(),
+            _ => (),
         }
     }
 
-    fn bit_for_key(&self, key: KeyCode) -> impl AsRef<bool> + '_ {
-        self.pressed.get(key.value() as usize).unwrap()
+    /// Updates [`Self::modifiers`] for a Shift/Ctrl/Alt/Windows transition,
+    /// disambiguating the generic Shift/Ctrl/Alt virtual keys' side via
+    /// [`KeyEvent::location_for`], and sets [`Self::modifiers_changed`] if
+    /// the aggregate value actually changed. A no-op for any other key.
+    fn apply_modifier(&mut self, key_code: KeyCode, flags: &KeystrokeFlags, pressed: bool) {
+        let field = match (key_code, KeyEvent::location_for(key_code, flags)) {
+            (KeyCode::Shift | KeyCode::LeftShift, KeyLocation::Left) => {
+                &mut self.modifiers.shift_left
+            }
+            (KeyCode::Shift | KeyCode::RightShift, KeyLocation::Right) => {
+                &mut self.modifiers.shift_right
+            }
+            (KeyCode::Control | KeyCode::LeftControl, KeyLocation::Left) => {
+                &mut self.modifiers.ctrl_left
+            }
+            (KeyCode::Control | KeyCode::RightControl, KeyLocation::Right) => {
+                &mut self.modifiers.ctrl_right
+            }
+            (KeyCode::Menu | KeyCode::LeftAlt, KeyLocation::Left) => &mut self.modifiers.alt_left,
+            (KeyCode::Menu | KeyCode::RightAlt, KeyLocation::Right) => {
+                &mut self.modifiers.alt_right
+            }
+            (KeyCode::LeftWindows, _) => &mut self.modifiers.logo_left,
+            (KeyCode::RightWindows, _) => &mut self.modifiers.logo_right,
+            _ => return,
+        };
+
+        if *field != pressed {
+            *field = pressed;
+            self.modifiers_changed = true;
+        }
+    }
+
+    fn current_bit(&self, key: KeyCode) -> impl AsRef<bool> + '_ {
+        self.current.get(key.value() as usize).unwrap()
+    }
+
+    fn previous_bit(&self, key: KeyCode) -> impl AsRef<bool> + '_ {
+        self.previous.get(key.value() as usize).unwrap()
     }
 
     fn mut_bit_for_key(&mut self, key: KeyCode) -> impl AsMut<bool> + '_ {
-        self.pressed.get_mut(key.value() as usize).unwrap()
+        self.current.get_mut(key.value() as usize).unwrap()
     }
 }
 
@@ -194,7 +533,7 @@ mod tests {
     use ::strum::IntoEnumIterator;
     use ::widestring::u16str;
     use ::windows::Win32::{
-        Foundation::{LPARAM, WPARAM},
+        Foundation::{HWND, LPARAM, WPARAM},
         UI::WindowsAndMessaging::*,
     };
 
@@ -305,12 +644,12 @@ mod tests {
     fn test_key_pressed_basic() {
         let mut kbd = Keyboard::new();
 
-        assert!(!kbd.is_key_pressed(KeyCode::Up));
-        kbd.process_evt(KeyEvent::KeyDown {
+        assert!(!kbd.is_down(KeyCode::Up));
+        kbd.process_evt(RawKeyEvent::KeyDown {
             key_code: KeyCode::Up,
             flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
         });
-        assert!(kbd.is_key_pressed(KeyCode::Up));
+        assert!(kbd.is_down(KeyCode::Up));
     }
 
     /// Tests correct handling of a series of key down and key up events.
@@ -319,31 +658,31 @@ mod tests {
         let mut kbd = Keyboard::new();
 
         for key_code in KeyCode::iter() {
-            assert!(!kbd.is_key_pressed(key_code));
+            assert!(!kbd.is_down(key_code));
         }
 
         for evt in [
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::A,
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
             },
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::Left,
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
             },
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::Space,
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
             },
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::Left,
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Repeat),
             },
-            KeyEvent::KeyUp {
+            RawKeyEvent::KeyUp {
                 key_code: KeyCode::A,
                 flags: KeystrokeFlags::test_key_up_flags(KeyRepeat::Initial),
             },
-            KeyEvent::KeyDown {
+            RawKeyEvent::KeyDown {
                 key_code: KeyCode::Left,
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Repeat),
             },
@@ -353,42 +692,38 @@ mod tests {
 
         let expected_pressed = [KeyCode::Space, KeyCode::Left];
         for key_code in expected_pressed {
-            assert!(kbd.is_key_pressed(key_code));
+            assert!(kbd.is_down(key_code));
         }
         for key_code in KeyCode::iter().filter(|key_code| expected_pressed.contains(key_code).not())
         {
-            assert!(!kbd.is_key_pressed(key_code));
+            assert!(!kbd.is_down(key_code));
         }
     }
 
-    /// We expect that a basic stream of ASCII characters (less than the queue
-    /// size), should be collected and returned correctly.
+    /// We expect that a basic stream of ASCII characters should be inserted
+    /// into the line buffer and returned correctly, with the cursor left
+    /// after the last inserted character.
     #[test]
-    fn test_input_queue_basic() {
+    fn test_input_buffer_basic() {
         let mut kbd = Keyboard::new();
 
         // Test state before any events
-        let input: String = kbd.drain_input().chars().collect();
+        let input: String = kbd.input_buffer().chars().collect();
         assert!(
             input.is_empty(),
-            "Queue should be empty before first input key event event"
+            "Line should be empty before first input key event"
         );
 
-        // Add basic ASCII chars to queue
-        for evt in "Hello, world!".chars().map(|c| KeyEvent::Input {
+        for evt in "Hello, world!".chars().map(|c| RawKeyEvent::Input {
             flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
             wchar: c as _,
         }) {
             kbd.process_evt(evt);
         }
 
-        // Confirm queue state after events have been processed
-        let input: String = kbd.drain_input().chars().collect();
-        assert_eq!(&input, "Hello, world!");
-        assert!(
-            kbd.drain_input().chars().next().is_none(),
-            "Queue should be empty after last call to drain"
-        );
+        let buf = kbd.input_buffer();
+        assert_eq!(buf.chars().collect::<String>(), "Hello, world!");
+        assert_eq!(buf.cursor(), "Hello, world!".chars().count());
     }
 
     /// Test that valid unicode is handled correctly.
@@ -396,12 +731,12 @@ mod tests {
     /// We use a "Musical Symbol G Clef" character which requires surrogate
     /// pairs to encode in UTF16.
     #[test]
-    fn test_input_queue_unicode() {
+    fn test_input_buffer_unicode() {
         let mut kbd = Keyboard::new();
 
         for evt in [0xD834_u16, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063]
             .into_iter()
-            .map(|wchar| KeyEvent::Input {
+            .map(|wchar| RawKeyEvent::Input {
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
                 wchar,
             })
@@ -409,133 +744,97 @@ mod tests {
             kbd.process_evt(evt);
         }
 
-        let input: String = kbd.drain_input().chars().collect();
+        let input: String = kbd.input_buffer().chars().collect();
         assert_eq!(&input, "𝄞music");
     }
 
-    /// Test pending surrogate pair handling by enqueueing the high surrogate
-    /// and expecting that our drain method returns nothing until the following
-    /// low surrogate is enqueued.
+    /// Test pending surrogate pair handling: a lone high surrogate should
+    /// leave the line buffer unchanged until the following low surrogate
+    /// arrives.
     ///
     /// We use a "Musical Symbol G Clef" character which requires surrogate
     /// pairs to encode in UTF16.
     #[test]
-    fn test_input_queue_surrogate_pair_handling() {
+    fn test_input_buffer_surrogate_pair_handling() {
         let mut kbd = Keyboard::new();
 
-        kbd.process_evt(KeyEvent::Input {
+        kbd.process_evt(RawKeyEvent::Input {
             flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
             wchar: 0xD834,
         });
         assert!(
-            kbd.drain_input().chars().next().is_none(),
-            "Input queue should wait for following low surrogate before returning"
+            kbd.input_buffer().chars().next().is_none(),
+            "Line buffer should wait for following low surrogate before inserting"
         );
 
-        kbd.process_evt(KeyEvent::Input {
+        kbd.process_evt(RawKeyEvent::Input {
             flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
             wchar: 0xDD1E,
         });
 
-        let input: String = kbd.drain_input().chars().collect();
+        let input: String = kbd.input_buffer().chars().collect();
         assert_eq!(&input, "𝄞");
     }
 
     /// Test pending surrogate pair handling by enqueueing an out-of-order low
-    /// surrogate (high surrogates must precede low surrogates)
-    /// and expecting that our drain method immediately returns the replacement
-    /// character.
+    /// surrogate (high surrogates must precede low surrogates) and expecting
+    /// that it's immediately replaced with the replacement character.
     ///
     /// We use a "Musical Symbol G Clef" character which requires surrogate
     /// pairs to encode in UTF16.
     #[test]
-    fn test_input_queue_lone_low_surrogate() {
+    fn test_input_buffer_lone_low_surrogate() {
         let mut kbd = Keyboard::new();
 
-        for evt in [0xDD1E, 0x006d].into_iter().map(|wchar| KeyEvent::Input {
+        for evt in [0xDD1E, 0x006d].into_iter().map(|wchar| RawKeyEvent::Input {
             flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
             wchar,
         }) {
             kbd.process_evt(evt);
         }
 
-        let input: String = kbd.drain_input().chars().collect();
+        let input: String = kbd.input_buffer().chars().collect();
         assert_eq!(&input, "�m");
     }
 
-    // Test that several unicode characters requiring surrogate pairs are correctly
-    // captured.
+    /// Test that a second high surrogate arriving before its partner low
+    /// surrogate replaces the stale pending one, rather than discarding it
+    /// silently: the stale high surrogate becomes a replacement character,
+    /// and the new high surrogate waits for its own low surrogate.
     ///
-    /// We use alternating "Musical Symbol G Clef" and "Bridge at Night Emoji"
-    /// characters which both require surrogate pairs to encode in UTF16.
+    /// We use a "Musical Symbol G Clef" character which requires surrogate
+    /// pairs to encode in UTF16.
     #[test]
-    fn test_input_queue_multiple_surrogate_pair_characters() {
+    fn test_input_buffer_consecutive_high_surrogates() {
         let mut kbd = Keyboard::new();
 
-        for evt in u16str!("𝄞🌉𝄞🌉a𝄞b🌉c")
-            .as_slice()
-            .iter()
-            .map(|c| KeyEvent::Input {
+        for evt in [0xD834, 0xD834, 0xDD1E]
+            .into_iter()
+            .map(|wchar| RawKeyEvent::Input {
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
-                wchar: *c as _,
+                wchar,
             })
         {
             kbd.process_evt(evt);
         }
 
-        // Confirm queue state after events have been processed
-        let input: String = kbd.drain_input().chars().collect();
-        assert_eq!(&input, "𝄞🌉𝄞🌉a𝄞b🌉c");
-
-        assert!(
-            kbd.drain_input().chars().next().is_none(),
-            "Queue should be empty after last call to drain"
-        );
+        let input: String = kbd.input_buffer().chars().collect();
+        assert_eq!(&input, "�𝄞");
     }
 
-    /// Tests that our input buffer is trimmed to avoid continuous growth if it
-    /// is not regularly drained by the caller.
-    #[test]
-    fn test_input_queue_buffer_trim() {
-        let mut kbd = Keyboard::new();
-
-        // Add basic ASCII chars to queue
-        for evt in "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
-            .chars()
-            .map(|c| KeyEvent::Input {
-                flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
-                wchar: c as _,
-            })
-        {
-            kbd.process_evt(evt);
-        }
-
-        // Confirm queue state after events have been processed
-        let input: String = kbd.drain_input().chars().collect();
-        assert_eq!(&input, "vwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ");
-        assert_eq!(input.len(), INPUT_QUEUE_CAPACITY - 1);
-
-        assert!(
-            kbd.drain_input().chars().next().is_none(),
-            "Queue should be empty after last call to drain"
-        );
-    }
-
-    // Test that buffer trimming does not result in surrogate pair truncation.
-    // If the first character to be truncated is a high surrogate pair
-    // character, then the following low surrogate pair character should be
-    // trimmed too.
+    /// Test that several unicode characters requiring surrogate pairs are
+    /// correctly captured.
     ///
     /// We use alternating "Musical Symbol G Clef" and "Bridge at Night Emoji"
     /// characters which both require surrogate pairs to encode in UTF16.
     #[test]
-    fn test_input_queue_buffer_trim_unicode() {
+    fn test_input_buffer_multiple_surrogate_pair_characters() {
         let mut kbd = Keyboard::new();
 
-        for evt in u16str!("𝄞🌉1𝄞🌉2𝄞🌉3𝄞🌉4𝄞🌉5𝄞🌉6𝄞🌉7𝄞🌉8𝄞🌉9𝄞🌉0𝄞🌉A𝄞🌉B𝄞🌉C𝄞🌉")
+        for evt in u16str!("𝄞🌉𝄞🌉a𝄞b🌉c")
             .as_slice()
             .iter()
-            .map(|c| KeyEvent::Input {
+            .map(|c| RawKeyEvent::Input {
                 flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
                 wchar: *c as _,
             })
@@ -543,36 +842,29 @@ mod tests {
             kbd.process_evt(evt);
         }
 
-        // Confirm queue state after events have been processed
-        let input: String = kbd.drain_input().chars().collect();
-        assert_eq!(&input, "🌉4𝄞🌉5𝄞🌉6𝄞🌉7𝄞🌉8𝄞🌉9𝄞🌉0𝄞🌉A𝄞🌉B𝄞🌉C𝄞🌉");
-        assert_eq!(input.chars().count(), INPUT_QUEUE_CAPACITY - 1);
-
-        assert!(
-            kbd.drain_input().chars().next().is_none(),
-            "Queue should be empty after last call to drain"
-        );
+        let input: String = kbd.input_buffer().chars().collect();
+        assert_eq!(&input, "𝄞🌉𝄞🌉a𝄞b🌉c");
     }
 
     /// Test text entry for 'ö' ('"' + 'o' combo on international keyboard).
     ///
     /// Events were captured via debugging utils.
     #[test]
-    fn test_input_queue_international_input() {
+    fn test_input_buffer_international_input() {
         use super::super::Adapter;
         let mut kbd = Keyboard::new();
 
         for &(umsg, wparam, lparam) in event_samples::PRESS_RELEASE_INTERNATIONAL_UMLAUT {
-            if let Some(evt) = Adapter::adapt(umsg, WPARAM(wparam), LPARAM(lparam)) {
+            if let Some(evt) = Adapter::adapt(HWND(0), umsg, WPARAM(wparam), LPARAM(lparam)) {
                 kbd.process_evt(evt);
             }
         }
 
-        let input: String = kbd.drain_input().chars().collect();
+        let input: String = kbd.input_buffer().chars().collect();
         assert_eq!(input, "ö");
         for key_code in KeyCode::iter() {
             assert!(
-                !kbd.is_key_pressed(key_code),
+                !kbd.is_down(key_code),
                 "{key_code:?} key still pressed"
             );
         }
@@ -582,31 +874,28 @@ mod tests {
     ///
     /// Events captured using debug utils.
     #[test]
-    fn test_input_queue_emoji() {
+    fn test_input_buffer_emoji() {
         use super::super::Adapter;
         let mut kbd = Keyboard::new();
 
         for &(umsg, wparam, lparam) in event_samples::EMOJI_INPUT_OK_HAND {
-            if let Some(evt) = Adapter::adapt(umsg, WPARAM(wparam), LPARAM(lparam)) {
-                println!("{evt:#?}");
+            if let Some(evt) = Adapter::adapt(HWND(0), umsg, WPARAM(wparam), LPARAM(lparam)) {
                 kbd.process_evt(evt);
             }
         }
 
-        let input: String = kbd.drain_input().chars().collect();
+        let input: String = kbd.input_buffer().chars().collect();
         assert_eq!(input, "👌");
         for key_code in KeyCode::iter() {
             assert!(
-                !kbd.is_key_pressed(key_code),
+                !kbd.is_down(key_code),
                 "{key_code:?} key still pressed"
             );
         }
     }
 
-    /// Pressing backspace without any input in the queue should accumulate
-    /// pending delete backspace events that can be applied to previously
-    /// drained characters. If backspace is pressed while the input queue has
-    /// some input should result in pending input being removed.
+    /// Pressing backspace with an empty line is a no-op. Backspace while the
+    /// line has content removes the character before the cursor.
     #[test]
     fn test_backspace_key() {
         use super::super::Adapter;
@@ -623,15 +912,420 @@ mod tests {
         .into_iter()
         .flatten()
         {
-            if let Some(evt) = Adapter::adapt(umsg, WPARAM(wparam), LPARAM(lparam)) {
+            if let Some(evt) = Adapter::adapt(HWND(0), umsg, WPARAM(wparam), LPARAM(lparam)) {
                 kbd.process_evt(evt);
             }
         }
 
-        let mut state = kbd.drain_input();
-        assert_eq!(state.num_backspaces(), 2);
-        let input: String = state.chars().collect();
+        let input: String = kbd.input_buffer().chars().collect();
         assert_eq!(input, "ac");
     }
-    // TODO: delete key
+
+    /// Left/Right should move the cursor without changing the line, clamped
+    /// to the start/end rather than moving past it.
+    #[test]
+    fn test_cursor_movement_left_right() {
+        let mut kbd = Keyboard::new();
+
+        for c in "ab".chars() {
+            kbd.process_evt(RawKeyEvent::Input {
+                flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+                wchar: c as _,
+            });
+        }
+        assert_eq!(kbd.input_buffer().cursor(), 2);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Left,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(kbd.input_buffer().cursor(), 1);
+
+        // Clamped at the start.
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Left,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Left,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(kbd.input_buffer().cursor(), 0);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Right,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(kbd.input_buffer().cursor(), 1);
+
+        // Clamped at the end.
+        for _ in 0..3 {
+            kbd.process_evt(RawKeyEvent::KeyDown {
+                key_code: KeyCode::Right,
+                flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+            });
+        }
+        assert_eq!(kbd.input_buffer().cursor(), 2);
+
+        let input: String = kbd.input_buffer().chars().collect();
+        assert_eq!(input, "ab", "cursor movement should not modify the line");
+    }
+
+    /// Home/End should jump the cursor to the start/end of the line.
+    #[test]
+    fn test_cursor_movement_home_end() {
+        let mut kbd = Keyboard::new();
+
+        for c in "abc".chars() {
+            kbd.process_evt(RawKeyEvent::Input {
+                flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+                wchar: c as _,
+            });
+        }
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Home,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(kbd.input_buffer().cursor(), 0);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::End,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(kbd.input_buffer().cursor(), 3);
+    }
+
+    /// Inserting in the middle of the line, and deleting with the Delete key
+    /// at the cursor, should edit in place rather than only ever affecting
+    /// the tail.
+    #[test]
+    fn test_in_place_insert_and_delete() {
+        let mut kbd = Keyboard::new();
+
+        for c in "ac".chars() {
+            kbd.process_evt(RawKeyEvent::Input {
+                flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+                wchar: c as _,
+            });
+        }
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Left,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        kbd.process_evt(RawKeyEvent::Input {
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+            wchar: 'b' as _,
+        });
+
+        let buf = kbd.input_buffer();
+        assert_eq!(buf.chars().collect::<String>(), "abc");
+        assert_eq!(buf.cursor(), 2);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Left,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Delete,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+
+        let buf = kbd.input_buffer();
+        assert_eq!(buf.chars().collect::<String>(), "ac");
+        assert_eq!(buf.cursor(), 1);
+    }
+
+    /// [`Keyboard::reset`] should clear the line buffer and its cursor.
+    #[test]
+    fn test_input_buffer_cleared_on_reset() {
+        let mut kbd = Keyboard::new();
+
+        kbd.process_evt(RawKeyEvent::Input {
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+            wchar: 'a' as _,
+        });
+        kbd.reset();
+
+        let buf = kbd.input_buffer();
+        assert!(buf.chars().next().is_none());
+        assert_eq!(buf.cursor(), 0);
+    }
+
+    /// A paste should insert atomically at the cursor - including control
+    /// characters that a typed `WM_CHAR` would have dropped - and surface
+    /// separately via [`InputBuffer::paste`].
+    #[test]
+    fn test_paste_inserts_atomically() {
+        let mut kbd = Keyboard::new();
+
+        kbd.process_evt(RawKeyEvent::Input {
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+            wchar: 'a' as _,
+        });
+        kbd.process_evt(RawKeyEvent::Paste {
+            text: "b\tc".to_string(),
+        });
+
+        let buf = kbd.input_buffer();
+        assert_eq!(buf.chars().collect::<String>(), "ab\tc");
+        assert_eq!(buf.cursor(), 4);
+        assert_eq!(buf.paste(), Some("b\tc"));
+    }
+
+    /// [`InputBuffer::paste`] should only report a paste once, like
+    /// [`Keyboard::drain_modifiers_changed`].
+    #[test]
+    fn test_paste_reported_once() {
+        let mut kbd = Keyboard::new();
+
+        kbd.process_evt(RawKeyEvent::Paste {
+            text: "x".to_string(),
+        });
+
+        assert_eq!(kbd.input_buffer().paste(), Some("x"));
+        assert_eq!(kbd.input_buffer().paste(), None);
+    }
+
+    /// Auto-repeat is opt-in: without calling [`Keyboard::configure_repeat`],
+    /// [`Keyboard::poll_repeats`] should never fire, no matter how long the
+    /// key has been held.
+    #[test]
+    fn test_repeat_disabled_by_default() {
+        let mut kbd = Keyboard::new();
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::A,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+
+        let far_future = Instant::now() + Duration::from_secs(60);
+        assert_eq!(kbd.poll_repeats(far_future).next(), None);
+    }
+
+    /// Basic repeat cadence: no repeats before `delay` has elapsed, one right
+    /// at `delay`, then further repeats every `rate` after that.
+    #[test]
+    fn test_repeat_basic_cadence() {
+        let mut kbd = Keyboard::new();
+        let delay = Duration::from_millis(500);
+        let rate = Duration::from_millis(100);
+        kbd.configure_repeat(delay, rate);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::A,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        let press_time = Instant::now();
+
+        assert_eq!(
+            kbd.poll_repeats(press_time).next(),
+            None,
+            "should not repeat before `delay` has elapsed"
+        );
+        assert_eq!(
+            kbd.poll_repeats(press_time + delay / 2).next(),
+            None,
+            "should not repeat before `delay` has elapsed"
+        );
+
+        let fires: Vec<_> = kbd.poll_repeats(press_time + delay).collect();
+        assert_eq!(fires, [KeyCode::A], "should fire exactly once at `delay`");
+
+        let fires: Vec<_> = kbd
+            .poll_repeats(press_time + delay + rate * 2 + rate / 2)
+            .collect();
+        assert_eq!(
+            fires,
+            [KeyCode::A, KeyCode::A],
+            "should fire once per elapsed `rate` interval since the last poll"
+        );
+    }
+
+    /// A long stall between polls should be clamped to a bounded number of
+    /// catch-up repeats rather than replaying the entire backlog.
+    #[test]
+    fn test_repeat_catch_up_is_clamped() {
+        let mut kbd = Keyboard::new();
+        let delay = Duration::from_millis(500);
+        let rate = Duration::from_millis(10);
+        kbd.configure_repeat(delay, rate);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::A,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        let press_time = Instant::now();
+
+        // Simulate a huge stall - many multiples of `rate` past `delay`.
+        let fires: Vec<_> = kbd
+            .poll_repeats(press_time + delay + rate * 1000)
+            .collect();
+        assert_eq!(
+            fires.len(),
+            MAX_CATCH_UP_REPEATS,
+            "catch-up repeats should be capped rather than bursting the full backlog"
+        );
+    }
+
+    /// Releasing the repeating key should stop further repeats, even though
+    /// `next_fire` was already scheduled.
+    #[test]
+    fn test_repeat_cleared_on_key_up() {
+        let mut kbd = Keyboard::new();
+        let delay = Duration::from_millis(100);
+        let rate = Duration::from_millis(50);
+        kbd.configure_repeat(delay, rate);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::A,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        let press_time = Instant::now();
+        assert_eq!(
+            kbd.poll_repeats(press_time + delay).next(),
+            Some(KeyCode::A)
+        );
+
+        kbd.process_evt(RawKeyEvent::KeyUp {
+            key_code: KeyCode::A,
+            flags: KeystrokeFlags::test_key_up_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(
+            kbd.poll_repeats(press_time + delay + rate * 10).next(),
+            None,
+            "key up should clear the repeat state"
+        );
+    }
+
+    /// Only the most-recently-pressed key should repeat, matching OS
+    /// behavior, and modifier keys should never repeat on their own.
+    #[test]
+    fn test_repeat_tracks_only_last_pressed_non_modifier_key() {
+        let mut kbd = Keyboard::new();
+        let delay = Duration::from_millis(100);
+        let rate = Duration::from_millis(50);
+        kbd.configure_repeat(delay, rate);
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Shift,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::A,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::B,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        let press_time = Instant::now();
+
+        let fires: Vec<_> = kbd.poll_repeats(press_time + delay).collect();
+        assert_eq!(fires, [KeyCode::B]);
+    }
+
+    /// Pressing and releasing the explicit left/right variant of a modifier
+    /// key should set and clear the corresponding side of [`Modifiers`],
+    /// leaving the opposite side untouched.
+    #[test]
+    fn test_modifiers_explicit_side() {
+        let mut kbd = Keyboard::new();
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::LeftControl,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(
+            kbd.modifiers(),
+            Modifiers {
+                ctrl_left: true,
+                ..Default::default()
+            }
+        );
+
+        kbd.process_evt(RawKeyEvent::KeyUp {
+            key_code: KeyCode::LeftControl,
+            flags: KeystrokeFlags::test_key_up_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(kbd.modifiers(), Modifiers::default());
+    }
+
+    /// The generic Shift/Ctrl/Alt virtual keys disambiguate their side from
+    /// the keystroke flags, same as [`KeyEvent::location_for`].
+    #[test]
+    fn test_modifiers_generic_key_disambiguates_side() {
+        let mut kbd = Keyboard::new();
+
+        // Right Shift is reported via the generic `VK_SHIFT` with scan code
+        // 0x36, as captured in `PRESS_RELEASE_INTERNATIONAL_UMLAUT` above.
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::Shift,
+            flags: KeystrokeFlags {
+                scan_code: 0x36,
+                ..KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial)
+            },
+        });
+        assert_eq!(
+            kbd.modifiers(),
+            Modifiers {
+                shift_right: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    /// [`Keyboard::drain_modifiers_changed`] should only report a change
+    /// once, and only when the aggregate modifier set actually changed.
+    #[test]
+    fn test_modifiers_changed_drain() {
+        let mut kbd = Keyboard::new();
+
+        assert_eq!(
+            kbd.drain_modifiers_changed(),
+            None,
+            "no modifier keys have been touched yet"
+        );
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::LeftShift,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert_eq!(
+            kbd.drain_modifiers_changed(),
+            Some(Modifiers {
+                shift_left: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            kbd.drain_modifiers_changed(),
+            None,
+            "change should only be reported once"
+        );
+
+        // A repeated KeyDown for an already-held modifier shouldn't report
+        // a spurious change.
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::LeftShift,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Repeat),
+        });
+        assert_eq!(kbd.drain_modifiers_changed(), None);
+    }
+
+    /// [`Keyboard::reset`] should clear the aggregate modifier state.
+    #[test]
+    fn test_modifiers_cleared_on_reset() {
+        let mut kbd = Keyboard::new();
+
+        kbd.process_evt(RawKeyEvent::KeyDown {
+            key_code: KeyCode::LeftAlt,
+            flags: KeystrokeFlags::test_key_down_flags(KeyRepeat::Initial),
+        });
+        assert!(kbd.modifiers().alt_left);
+
+        kbd.reset();
+        assert_eq!(kbd.modifiers(), Modifiers::default());
+    }
 }
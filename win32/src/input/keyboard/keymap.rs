@@ -0,0 +1,274 @@
+//! A chord-based keybinding matcher layered over [`Keyboard`](super::Keyboard).
+//!
+//! Modeled on the keystroke matcher used by editors like Zed: bindings are
+//! stored as ordered sequences of [`Keystroke`]s so that multi-stroke chords
+//! (e.g. `Ctrl+K` followed by `Ctrl+C`) can be expressed the same way as a
+//! single-stroke binding, and [`Keymap::match_keystroke`] buffers keystrokes
+//! until they commit to a binding, diverge from all of them, or complete one.
+
+use super::{KeyCode, KeyEvent, Modifiers};
+
+/// A single keystroke in a [`Keymap`] binding: the key pressed, plus the
+/// modifiers required to be held down at the time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Keystroke {
+    /// The key that was pressed.
+    pub key: KeyCode,
+    /// The modifiers that must be held for this keystroke to match.
+    pub modifiers: Modifiers,
+}
+
+impl From<KeyEvent> for Keystroke {
+    fn from(event: KeyEvent) -> Self {
+        Self {
+            key: event.key,
+            modifiers: event.modifiers,
+        }
+    }
+}
+
+/// The result of feeding a [`Keystroke`] into [`Keymap::match_keystroke`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeymapMatch<A> {
+    /// The buffered keystrokes are a prefix of at least one binding, but
+    /// don't yet complete any. No action fires; wait for the next keystroke.
+    Pending,
+    /// The buffered keystrokes exactly match a binding.
+    Matched(A),
+    /// No binding's prefix matches the buffered keystrokes. They're handed
+    /// back so the caller can replay them, e.g. falling through to text
+    /// input.
+    None(Vec<Keystroke>),
+}
+
+struct Binding<A> {
+    sequence: Vec<Keystroke>,
+    action: A,
+}
+
+/// A chord-based keybinding matcher: maps sequences of [`Keystroke`]s to
+/// user-defined action identifiers.
+///
+/// Call [`Self::match_keystroke`] once per committed keystroke from the
+/// frame loop. [`Self::clear_pending_keystrokes`] should be called on focus
+/// changes so a stroke from before the change doesn't combine with one from
+/// after it.
+pub struct Keymap<A> {
+    bindings: Vec<Binding<A>>,
+    pending: Vec<Keystroke>,
+}
+
+impl<A: Clone> Keymap<A> {
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Binds `sequence` (one keystroke for a simple shortcut, several for a
+    /// multi-stroke chord) to `action`.
+    pub fn bind(&mut self, sequence: impl Into<Vec<Keystroke>>, action: A) {
+        self.bindings.push(Binding {
+            sequence: sequence.into(),
+            action,
+        });
+    }
+
+    /// Feeds a single committed keystroke into the matcher.
+    ///
+    /// Appends `keystroke` to the pending buffer and tests it against every
+    /// binding. If some binding's sequence is a strictly longer than the
+    /// buffer but still starts with it, the buffer stays pending - even if
+    /// it also exactly matches a shorter binding - so a short binding (e.g.
+    /// `Ctrl+K`) never shadows a longer chord built on top of it (e.g.
+    /// `Ctrl+K, Ctrl+C`). Otherwise, if the buffer exactly matches a
+    /// binding's sequence, that binding's action is returned and the buffer
+    /// is cleared. If no binding's sequence starts with the buffer, the
+    /// buffered keystrokes are drained and returned as a replay so the
+    /// caller can fall them through to, e.g., text input.
+    pub fn match_keystroke(&mut self, keystroke: Keystroke) -> KeymapMatch<A> {
+        self.pending.push(keystroke);
+
+        let still_pending = self.bindings.iter().any(|binding| {
+            binding.sequence.len() > self.pending.len() && binding.sequence.starts_with(&self.pending)
+        });
+
+        if still_pending {
+            return KeymapMatch::Pending;
+        }
+
+        if let Some(binding) = self
+            .bindings
+            .iter()
+            .find(|binding| binding.sequence == self.pending)
+        {
+            self.pending.clear();
+            return KeymapMatch::Matched(binding.action.clone());
+        }
+
+        KeymapMatch::None(::std::mem::take(&mut self.pending))
+    }
+
+    /// Discards any buffered keystrokes without emitting a replay. Should be
+    /// called when the receiving window loses focus, so a partial chord
+    /// isn't silently completed by keystrokes meant for something else.
+    pub fn clear_pending_keystrokes(&mut self) {
+        self.pending.clear();
+    }
+}
+
+impl<A: Clone> Default for Keymap<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Action {
+        Copy,
+        JumpToDefinition,
+    }
+
+    fn keystroke(key: KeyCode, ctrl: bool) -> Keystroke {
+        Keystroke {
+            key,
+            modifiers: Modifiers {
+                ctrl_left: ctrl,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// A single-stroke binding should match immediately.
+    #[test]
+    fn test_single_stroke_match() {
+        let mut keymap = Keymap::new();
+        keymap.bind([keystroke(KeyCode::C, true)], Action::Copy);
+
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::C, true)),
+            KeymapMatch::Matched(Action::Copy)
+        );
+    }
+
+    /// A multi-stroke chord should stay pending after its prefix, then match
+    /// once the full sequence has been entered.
+    #[test]
+    fn test_multi_stroke_chord() {
+        let mut keymap = Keymap::new();
+        keymap.bind(
+            [keystroke(KeyCode::K, true), keystroke(KeyCode::C, true)],
+            Action::Copy,
+        );
+
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::K, true)),
+            KeymapMatch::Pending
+        );
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::C, true)),
+            KeymapMatch::Matched(Action::Copy)
+        );
+    }
+
+    /// A keystroke that doesn't extend any pending prefix should be replayed
+    /// in full, and the pending buffer cleared afterwards.
+    #[test]
+    fn test_no_match_replays_buffered_keystrokes() {
+        let mut keymap = Keymap::new();
+        keymap.bind(
+            [keystroke(KeyCode::K, true), keystroke(KeyCode::C, true)],
+            Action::Copy,
+        );
+
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::K, true)),
+            KeymapMatch::Pending
+        );
+
+        let stray = keystroke(KeyCode::X, false);
+        assert_eq!(
+            keymap.match_keystroke(stray),
+            KeymapMatch::None(vec![keystroke(KeyCode::K, true), stray])
+        );
+
+        // The buffer should have been cleared by the replay.
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::C, true)),
+            KeymapMatch::None(vec![keystroke(KeyCode::C, true)])
+        );
+    }
+
+    /// Two bindings sharing a common first stroke should both stay pending
+    /// until the second stroke disambiguates them.
+    #[test]
+    fn test_disambiguates_shared_prefix() {
+        let mut keymap = Keymap::new();
+        keymap.bind(
+            [keystroke(KeyCode::K, true), keystroke(KeyCode::C, true)],
+            Action::Copy,
+        );
+        keymap.bind(
+            [keystroke(KeyCode::K, true), keystroke(KeyCode::D, true)],
+            Action::JumpToDefinition,
+        );
+
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::K, true)),
+            KeymapMatch::Pending
+        );
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::D, true)),
+            KeymapMatch::Matched(Action::JumpToDefinition)
+        );
+    }
+
+    /// A short binding that's also a strict prefix of a longer binding
+    /// should not shadow the longer chord: it should stay pending rather
+    /// than firing immediately, so the longer chord remains reachable.
+    #[test]
+    fn test_prefix_binding_does_not_shadow_longer_chord() {
+        let mut keymap = Keymap::new();
+        keymap.bind([keystroke(KeyCode::K, true)], Action::JumpToDefinition);
+        keymap.bind(
+            [keystroke(KeyCode::K, true), keystroke(KeyCode::C, true)],
+            Action::Copy,
+        );
+
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::K, true)),
+            KeymapMatch::Pending
+        );
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::C, true)),
+            KeymapMatch::Matched(Action::Copy)
+        );
+    }
+
+    /// [`Keymap::clear_pending_keystrokes`] should discard a partial chord
+    /// without emitting a replay, as on a focus change.
+    #[test]
+    fn test_clear_pending_keystrokes() {
+        let mut keymap = Keymap::new();
+        keymap.bind(
+            [keystroke(KeyCode::K, true), keystroke(KeyCode::C, true)],
+            Action::Copy,
+        );
+
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::K, true)),
+            KeymapMatch::Pending
+        );
+        keymap.clear_pending_keystrokes();
+
+        assert_eq!(
+            keymap.match_keystroke(keystroke(KeyCode::C, true)),
+            KeymapMatch::None(vec![keystroke(KeyCode::C, true)])
+        );
+    }
+}
@@ -1,9 +1,15 @@
 //! Input and state handling for keyboard events.
 
+mod accelerator;
 mod adapter;
 mod codes;
+mod event;
 mod kbd;
+mod keymap;
 
+pub use accelerator::*;
 pub(crate) use adapter::*;
 pub use codes::*;
+pub use event::*;
 pub use kbd::*;
+pub use keymap::*;
@@ -0,0 +1,5 @@
+//! Input device handling (keyboard, mouse, and in future gamepad).
+
+pub mod keyboard;
+pub mod mouse;
+pub mod raw_input;
@@ -0,0 +1,139 @@
+//! Adapter for Win32 mouse events into their strongly-typed Rust
+//! counterparts.
+
+use ::win_geom::d2::Point2D;
+use ::windows::Win32::{
+    Foundation::{LPARAM, WPARAM},
+    UI::WindowsAndMessaging::{
+        WHEEL_DELTA, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEHWHEEL,
+        WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_RBUTTONDOWN, WM_RBUTTONUP, WM_XBUTTONDOWN, WM_XBUTTONUP,
+        XBUTTON1,
+    },
+};
+
+use crate::input::keyboard::KeyCode;
+
+/// A decoded mouse event, as produced by [`Adapter::adapt`] and consumed by
+/// [`Mouse::process_evt`](super::Mouse::process_evt).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum RawMouseEvent {
+    /// The cursor moved within the window's client area.
+    Moved { position: Point2D<i32> },
+    /// A mouse button was pressed.
+    ButtonDown {
+        button: KeyCode,
+        position: Point2D<i32>,
+    },
+    /// A mouse button was released.
+    ButtonUp {
+        button: KeyCode,
+        position: Point2D<i32>,
+    },
+    /// The wheel was scrolled, either vertically or horizontally.
+    Wheel { delta: f32, horizontal: bool },
+}
+
+pub(crate) struct Adapter {}
+
+impl Adapter {
+    /// Indicates whether the [Adapter] handles the given message. If it does,
+    /// [adapt] should be called and the message should be considered handled
+    /// regardless of whether a [RawMouseEvent] is generated.
+    pub(crate) const fn handles_msg(umsg: u32, _wparam: WPARAM, _lparam: LPARAM) -> bool {
+        matches!(
+            umsg,
+            WM_MOUSEMOVE
+                | WM_LBUTTONDOWN
+                | WM_LBUTTONUP
+                | WM_RBUTTONDOWN
+                | WM_RBUTTONUP
+                | WM_MBUTTONDOWN
+                | WM_MBUTTONUP
+                | WM_XBUTTONDOWN
+                | WM_XBUTTONUP
+                | WM_MOUSEWHEEL
+                | WM_MOUSEHWHEEL
+        )
+    }
+
+    /// Adapts a Win32 windows procedure function into a [RawMouseEvent]. This
+    /// function should only be called if [handles_msg] indicated that the
+    /// [Adapter] will handle a wnd proc message with these parameters.
+    pub(crate) fn adapt(umsg: u32, wparam: WPARAM, lparam: LPARAM) -> Option<RawMouseEvent> {
+        match umsg {
+            WM_MOUSEMOVE => Some(RawMouseEvent::Moved {
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_LBUTTONDOWN => Some(RawMouseEvent::ButtonDown {
+                button: KeyCode::LeftMouseButton,
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_LBUTTONUP => Some(RawMouseEvent::ButtonUp {
+                button: KeyCode::LeftMouseButton,
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_RBUTTONDOWN => Some(RawMouseEvent::ButtonDown {
+                button: KeyCode::RightMouseButton,
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_RBUTTONUP => Some(RawMouseEvent::ButtonUp {
+                button: KeyCode::RightMouseButton,
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_MBUTTONDOWN => Some(RawMouseEvent::ButtonDown {
+                button: KeyCode::MiddleMouseButton,
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_MBUTTONUP => Some(RawMouseEvent::ButtonUp {
+                button: KeyCode::MiddleMouseButton,
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_XBUTTONDOWN => Some(RawMouseEvent::ButtonDown {
+                button: Self::x_button_from_wparam(wparam),
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_XBUTTONUP => Some(RawMouseEvent::ButtonUp {
+                button: Self::x_button_from_wparam(wparam),
+                position: Self::point_from_lparam(lparam),
+            }),
+            WM_MOUSEWHEEL => Some(RawMouseEvent::Wheel {
+                delta: Self::wheel_delta_from_wparam(wparam),
+                horizontal: false,
+            }),
+            WM_MOUSEHWHEEL => Some(RawMouseEvent::Wheel {
+                delta: Self::wheel_delta_from_wparam(wparam),
+                horizontal: true,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Decodes the client-area cursor position packed into `lParam` by
+    /// `GET_X_LPARAM`/`GET_Y_LPARAM`: signed 16-bit x/y in the low/high
+    /// words, sign-extended so positions outside the client area (e.g. while
+    /// the cursor is captured) decode correctly.
+    fn point_from_lparam(lparam: LPARAM) -> Point2D<i32> {
+        Point2D::new(
+            (lparam.0 & 0xFFFF) as i16 as i32,
+            ((lparam.0 >> 16) & 0xFFFF) as i16 as i32,
+        )
+    }
+
+    /// Decodes which `X` button a `WM_XBUTTONDOWN`/`WM_XBUTTONUP` message is
+    /// for from the high word of `wParam` (`GET_XBUTTON_WPARAM`).
+    fn x_button_from_wparam(wparam: WPARAM) -> KeyCode {
+        if ((wparam.0 >> 16) & 0xFFFF) as u16 == XBUTTON1 as u16 {
+            KeyCode::X1MouseButton
+        } else {
+            KeyCode::X2MouseButton
+        }
+    }
+
+    /// Decodes a `WM_MOUSEWHEEL`/`WM_MOUSEHWHEEL` rotation into a number of
+    /// "notches" (`GET_WHEEL_DELTA_WPARAM(wParam) / WHEEL_DELTA`), so a
+    /// consumer doesn't need to know the raw multiple.
+    fn wheel_delta_from_wparam(wparam: WPARAM) -> f32 {
+        let raw = ((wparam.0 >> 16) & 0xFFFF) as u16 as i16;
+        f32::from(raw) / WHEEL_DELTA as f32
+    }
+}
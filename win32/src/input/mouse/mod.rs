@@ -0,0 +1,7 @@
+//! Input and state handling for mouse events.
+
+mod adapter;
+mod mouse;
+
+pub(crate) use adapter::*;
+pub use mouse::*;
@@ -0,0 +1,102 @@
+//! Mouse button and cursor position tracking.
+
+use ::bitvec::prelude::*;
+use ::win_geom::d2::Point2D;
+
+use super::RawMouseEvent;
+use crate::input::keyboard::KeyCode;
+
+/// A simple abstraction over mouse input, mirroring
+/// [`Keyboard`](crate::input::keyboard::Keyboard)'s button-state tracking.
+///
+/// Button state is double-buffered the same way: [`Self::snapshot`] copies
+/// the current press state into the previous one, so [`Self::just_pressed`]
+/// can detect a transition exactly once per call, no matter how many
+/// `WM_*BUTTONDOWN`/`WM_*BUTTONUP` messages land in between.
+pub struct Mouse {
+    /// Bitfield which tracks the press state for each mouse button, indexed
+    /// by [`KeyCode::value`], as of the most recently processed event.
+    current: BitArr!(for 8, in u8, Lsb0),
+    /// Snapshot of [`Self::current`] taken by the last call to
+    /// [`Self::snapshot`], used to detect edge-triggered transitions.
+    previous: BitArr!(for 8, in u8, Lsb0),
+    /// The cursor's position within the window's client area, as of the most
+    /// recently processed `WM_MOUSEMOVE` or button message.
+    position: Point2D<i32>,
+}
+
+impl Mouse {
+    pub(crate) fn new() -> Self {
+        Self {
+            current: bitarr![u8, Lsb0; 0; 8],
+            previous: bitarr![u8, Lsb0; 0; 8],
+            position: Point2D::default(),
+        }
+    }
+
+    /// Process an event from the Win32 system and update internal state. This
+    /// event will be reflected in the next user call to [`Self::is_down`] or
+    /// [`Self::position`].
+    pub(crate) fn process_evt(&mut self, evt: RawMouseEvent) {
+        match evt {
+            RawMouseEvent::Moved { position } => self.position = position,
+            RawMouseEvent::ButtonDown { button, position } => {
+                *self.mut_bit_for_button(button).as_mut() = true;
+                self.position = position;
+            }
+            RawMouseEvent::ButtonUp { button, position } => {
+                *self.mut_bit_for_button(button).as_mut() = false;
+                self.position = position;
+            }
+            // The wheel carries no persistent state - only a momentary
+            // delta, already surfaced directly as an `Event`.
+            RawMouseEvent::Wheel { .. } => {}
+        }
+    }
+
+    /// Returns whether `button` is currently held down.
+    pub fn is_down(&self, button: KeyCode) -> bool {
+        *self.current_bit(button).as_ref()
+    }
+
+    /// Returns whether `button` transitioned from up to down since the last
+    /// call to [`Self::snapshot`]. Edge-triggered, so a button held across
+    /// multiple [`Self::snapshot`] calls without being released only reports
+    /// `true` once.
+    pub fn just_pressed(&self, button: KeyCode) -> bool {
+        *self.current_bit(button).as_ref() && !*self.previous_bit(button).as_ref()
+    }
+
+    /// Snapshots the current press state as the "previous" state, so that
+    /// [`Self::just_pressed`] can detect transitions that happen before the
+    /// next call to this method. Should be called once at the top of each
+    /// simulation step, before this step's window messages are processed.
+    pub fn snapshot(&mut self) {
+        self.previous = self.current;
+    }
+
+    /// The cursor's most recently observed position within the window's
+    /// client area.
+    pub const fn position(&self) -> Point2D<i32> {
+        self.position
+    }
+
+    /// Reset all mouse state.
+    pub fn reset(&mut self) {
+        self.current = BitArray::ZERO;
+        self.previous = BitArray::ZERO;
+        self.position = Point2D::default();
+    }
+
+    fn current_bit(&self, button: KeyCode) -> impl AsRef<bool> + '_ {
+        self.current.get(button.value() as usize).unwrap()
+    }
+
+    fn previous_bit(&self, button: KeyCode) -> impl AsRef<bool> + '_ {
+        self.previous.get(button.value() as usize).unwrap()
+    }
+
+    fn mut_bit_for_button(&mut self, button: KeyCode) -> impl AsMut<bool> + '_ {
+        self.current.get_mut(button.value() as usize).unwrap()
+    }
+}
@@ -0,0 +1,160 @@
+//! Registers for and decodes the Win32 Raw Input API's `WM_INPUT` messages.
+
+use crate::{errors::Result, invoke::chk};
+
+use ::std::mem::size_of;
+use ::windows::Win32::{
+    Devices::HumanInterfaceDevice::{
+        HID_USAGE_GENERIC_KEYBOARD, HID_USAGE_GENERIC_MOUSE, HID_USAGE_PAGE_GENERIC,
+    },
+    Foundation::{HWND, LPARAM},
+    UI::{
+        Input::{
+            GetRawInputData, RegisterRawInputDevices, HRAWINPUT, RAWINPUT, RAWINPUTDEVICE,
+            RAWINPUTHEADER, RAWMOUSE, RIDEV_INPUTSINK, RID_INPUT, RIM_TYPEKEYBOARD,
+            RIM_TYPEMOUSE, RI_KEY_BREAK, RI_KEY_E0, RI_MOUSE_WHEEL,
+        },
+        WindowsAndMessaging::{WHEEL_DELTA, WM_INPUT},
+    },
+};
+
+/// A single keyboard keystroke read directly from a `RAWINPUT` packet,
+/// bypassing the window-message path's message coalescing and auto-repeat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawInputKeyEvent {
+    /// The key's physical scan code, as reported by the device.
+    pub scan_code: u16,
+    /// The key's virtual-key code, as reported by the device.
+    pub vkey: u16,
+    /// Whether this is a key-up (`true`) or key-down (`false`) transition.
+    pub is_key_release: bool,
+    /// Whether the key belongs to the "extended" set (e.g. the right-hand
+    /// Ctrl/Alt, or the arrow/navigation cluster).
+    pub is_extended: bool,
+}
+
+/// A single mouse movement/button/wheel sample read directly from a
+/// `RAWINPUT` packet.
+///
+/// `dx`/`dy` are relative motion deltas straight from the device, unaffected
+/// by cursor acceleration, sensitivity, or clamping at the desktop edge -
+/// unlike the absolute, accelerated coordinates `WM_MOUSEMOVE` reports,
+/// these are suitable for FPS-style camera control.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawInputMouseEvent {
+    /// Relative horizontal motion since the last sample, in device counts.
+    pub dx: i32,
+    /// Relative vertical motion since the last sample, in device counts.
+    pub dy: i32,
+    /// The raw button transition flags reported by the device (the
+    /// `RI_MOUSE_*` bitmask), e.g. `RI_MOUSE_LEFT_BUTTON_DOWN`.
+    pub buttons: u32,
+    /// Wheel rotation in notches, decoded the same way as the
+    /// window-message wheel event, or `0.0` if this sample carries no wheel
+    /// movement.
+    pub wheel: f32,
+}
+
+/// A decoded Raw Input sample, as produced by [`Adapter::adapt`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RawInputEvent {
+    /// A keyboard keystroke.
+    Keyboard(RawInputKeyEvent),
+    /// A mouse movement/button/wheel sample.
+    Mouse(RawInputMouseEvent),
+}
+
+pub(crate) struct Adapter {}
+
+impl Adapter {
+    /// Registers `hwnd` for Raw Input keyboard and mouse notifications via
+    /// `RegisterRawInputDevices`, using `RIDEV_INPUTSINK` so samples keep
+    /// arriving even while the window isn't in the foreground.
+    pub(crate) fn register(hwnd: HWND) -> Result<()> {
+        let devices = [
+            RAWINPUTDEVICE {
+                usUsagePage: HID_USAGE_PAGE_GENERIC,
+                usUsage: HID_USAGE_GENERIC_MOUSE,
+                dwFlags: RIDEV_INPUTSINK,
+                hwndTarget: hwnd,
+            },
+            RAWINPUTDEVICE {
+                usUsagePage: HID_USAGE_PAGE_GENERIC,
+                usUsage: HID_USAGE_GENERIC_KEYBOARD,
+                dwFlags: RIDEV_INPUTSINK,
+                hwndTarget: hwnd,
+            },
+        ];
+
+        chk!(bool; RegisterRawInputDevices(&devices, size_of::<RAWINPUTDEVICE>() as u32))
+    }
+
+    /// Indicates whether the [`Adapter`] handles the given message.
+    pub(crate) const fn handles_msg(umsg: u32) -> bool {
+        umsg == WM_INPUT
+    }
+
+    /// Reads and decodes the `RAWINPUT` packet referenced by a `WM_INPUT`
+    /// message's `lParam` via `GetRawInputData`. This function should only
+    /// be called if [`Self::handles_msg`] indicated the `Adapter` will
+    /// handle a message with these parameters.
+    pub(crate) fn adapt(lparam: LPARAM) -> Option<RawInputEvent> {
+        let mut raw = RAWINPUT::default();
+        let mut size = size_of::<RAWINPUT>() as u32;
+
+        let written = unsafe {
+            GetRawInputData(
+                HRAWINPUT(lparam.0),
+                RID_INPUT,
+                Some(&mut raw as *mut _ as *mut _),
+                &mut size,
+                size_of::<RAWINPUTHEADER>() as u32,
+            )
+        };
+
+        // `GetRawInputData` returns `u32::MAX` on failure and `0` if there
+        // was nothing to copy; either way there's no packet to decode.
+        if written == u32::MAX || written == 0 {
+            return None;
+        }
+
+        if raw.header.dwType == RIM_TYPEKEYBOARD.0 {
+            let kbd = unsafe { raw.data.keyboard };
+            Some(RawInputEvent::Keyboard(RawInputKeyEvent {
+                scan_code: kbd.MakeCode,
+                vkey: kbd.VKey,
+                is_key_release: kbd.Flags as u32 & RI_KEY_BREAK != 0,
+                is_extended: kbd.Flags as u32 & RI_KEY_E0 != 0,
+            }))
+        } else if raw.header.dwType == RIM_TYPEMOUSE.0 {
+            let mouse = unsafe { raw.data.mouse };
+            Some(RawInputEvent::Mouse(RawInputMouseEvent {
+                dx: mouse.lLastX,
+                dy: mouse.lLastY,
+                buttons: Self::button_flags(&mouse),
+                wheel: Self::wheel_delta(&mouse),
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Reads the union-packed `usButtonFlags` field, widened to a plain
+    /// bitmask callers can match against the `RI_MOUSE_*` constants without
+    /// touching the union themselves.
+    fn button_flags(mouse: &RAWMOUSE) -> u32 {
+        unsafe { mouse.Anonymous.Anonymous.usButtonFlags as u32 }
+    }
+
+    /// Decodes a wheel sample's rotation into a number of "notches", the
+    /// same way [`super::super::mouse::Adapter`] does for `WM_MOUSEWHEEL`, or
+    /// `0.0` if this sample didn't carry a wheel movement.
+    fn wheel_delta(mouse: &RAWMOUSE) -> f32 {
+        if Self::button_flags(mouse) & RI_MOUSE_WHEEL as u32 == 0 {
+            return 0.0;
+        }
+
+        let data = unsafe { mouse.Anonymous.Anonymous.usButtonData } as i16;
+        f32::from(data) / WHEEL_DELTA as f32
+    }
+}
@@ -0,0 +1,16 @@
+//! High-frequency, unbuffered keyboard and mouse input via the Win32 Raw
+//! Input API.
+//!
+//! Window messages like `WM_KEYDOWN` and `WM_MOUSEMOVE` coalesce and, for
+//! keys, auto-repeat - adequate for UI, but not for precise game input. This
+//! module decodes `WM_INPUT` packets directly instead, giving
+//! [`RawInputKeyEvent`]s with no repeat suppression and
+//! [`RawInputMouseEvent`]s whose `dx`/`dy` come straight from the device's
+//! relative motion fields, unaffected by cursor acceleration or the desktop
+//! edge. See [`super::keyboard`] and [`super::mouse`] for the ordinary,
+//! window-message-driven input path most applications should prefer.
+
+mod adapter;
+
+pub(crate) use adapter::{Adapter, RawInputEvent};
+pub use adapter::{RawInputKeyEvent, RawInputMouseEvent};
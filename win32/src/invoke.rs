@@ -5,7 +5,10 @@ use crate::errors::*;
 use ::std::num::{NonZeroIsize, NonZeroU16};
 use ::windows::{
     core::{Result as Win32Result, PCSTR, PCWSTR, PSTR, PWSTR},
-    Win32::Foundation::{GetLastError, SetLastError, BOOL, HWND, WIN32_ERROR},
+    Win32::Foundation::{
+        GetLastError, SetLastError, BOOL, ERROR_INSUFFICIENT_BUFFER, ERROR_MORE_DATA, HWND,
+        WIN32_ERROR,
+    },
 };
 
 /// Invokes a Win32 function with the provided argument and checks the return
@@ -19,6 +22,9 @@ use ::windows::{
 /// - bool
 /// - res
 ///
+/// The call site's `file!()`/`line!()` is captured automatically and
+/// attached to any resulting [`Error`].
+///
 /// ### Usage
 ///
 /// ```
@@ -33,13 +39,104 @@ macro_rules! chk {
         ::paste::paste! {
             $crate::invoke:: [< check_ $check >] (
                 || unsafe { [<$fn>]( $( $param, )* ) } ,
-                ::std::stringify!([<$fn>])
+                ::std::stringify!([<$fn>]),
+                (::std::file!(), ::std::line!()),
             )
         }
     }
 }
 pub use chk;
 
+/// Invokes a Win32 function that fills a caller-provided buffer and reports
+/// back the number of elements written or required, growing the buffer and
+/// retrying via [`check_buf`] until the call fits.
+///
+/// Since the buffer and its length are typically threaded into the middle of
+/// a Win32 call's argument list (and their exact types vary per API), `$f`
+/// is a closure taking the current buffer as `&mut [$elem_ty]` and returning
+/// the number of elements written (or required, if it didn't fit) as a
+/// `u32`, matching Win32's own size-probing convention.
+///
+/// ### Usage
+///
+/// ```
+/// use ::win32::invoke;
+/// use ::windows::{core::PWSTR, Win32::UI::WindowsAndMessaging::GetWindowTextW};
+///
+/// # let hwnd = Default::default();
+/// let text: Vec<u16> = invoke::chk_buf!(u16; "GetWindowTextW", |buf: &mut [u16]| unsafe {
+///     GetWindowTextW(hwnd, PWSTR(buf.as_mut_ptr()), buf.len() as i32) as u32
+/// }).unwrap();
+/// ```
+#[macro_export]
+macro_rules! chk_buf {
+    ($elem_ty:ty ; $name:expr , $f:expr) => {
+        $crate::invoke::check_buf::<$elem_ty, _>(
+            $f,
+            $name,
+            (::std::file!(), ::std::line!()),
+            $crate::invoke::DEFAULT_BUF_CAPACITY,
+        )
+    };
+}
+pub use chk_buf;
+
+/// Initial element count used by [`chk_buf`] before any growth.
+pub const DEFAULT_BUF_CAPACITY: usize = 4096;
+
+/// Upper bound on how many times [`check_buf`] will double its buffer before
+/// giving up, guarding against APIs that never report a fitting size.
+const MAX_BUF_RETRIES: u32 = 8;
+
+/// Invokes a Win32 API which fills a caller-provided buffer, managing the
+/// size-probing retry loop common to APIs like `GetWindowTextW`,
+/// `FormatMessageW`, and registry queries.
+///
+/// `f` is invoked with a zeroed buffer of `initial_capacity` elements and
+/// must return the number of elements it wrote (if the buffer was big
+/// enough) or the number it required (if not). If the returned length is
+/// `>=` the buffer's current capacity, or the last error is
+/// `ERROR_INSUFFICIENT_BUFFER`/`ERROR_MORE_DATA`, the buffer is doubled and
+/// `f` is invoked again; otherwise the buffer is truncated to the returned
+/// length and returned.
+///
+/// Can be used with [crate::chk_buf] by specifying the element type and a
+/// closure, e.g.: `chk_buf!(u16; "GetWindowTextW", |buf| ...)`
+pub fn check_buf<T, F>(
+    mut f: F,
+    f_name: &'static str,
+    location: (&'static str, u32),
+    initial_capacity: usize,
+) -> Result<Vec<T>>
+where
+    T: Clone + Default,
+    F: FnMut(&mut [T]) -> u32,
+{
+    let mut capacity = initial_capacity.max(1);
+
+    for _ in 0..=MAX_BUF_RETRIES {
+        let mut buf = vec![T::default(); capacity];
+
+        unsafe { SetLastError(WIN32_ERROR(0)) };
+        let len = f(&mut buf) as usize;
+        let last_err = unsafe { GetLastError() };
+
+        let needs_growth = len >= capacity
+            || last_err == ERROR_INSUFFICIENT_BUFFER
+            || last_err == ERROR_MORE_DATA;
+
+        if needs_growth {
+            capacity *= 2;
+            continue;
+        }
+
+        buf.truncate(len);
+        return Ok(buf);
+    }
+
+    Err(get_last_err(f_name, location))
+}
+
 macro_rules! impl_nonzero {
     ($num:ty => $nonzero:ty) => {
         ::paste::paste! {
@@ -50,11 +147,15 @@ macro_rules! impl_nonzero {
             #[doc = ""]
             #[doc = "Can be used with [crate::chk] by specifying `nonzero_" $num "`"]
             #[doc = "as the type of check, e.g.: `chk!(nonzero_" $num "; ...)`"]
-            pub fn [<check_nonzero_ $num>]<F>(f: F, f_name: &'static str) -> Result<$nonzero>
+            pub fn [<check_nonzero_ $num>]<F>(
+                f: F,
+                f_name: &'static str,
+                location: (&'static str, u32),
+            ) -> Result<$nonzero>
             where
                 F: FnOnce() -> $num,
             {
-                <$nonzero>::new(f()).ok_or_else(|| get_last_err(f_name))
+                <$nonzero>::new(f()).ok_or_else(|| get_last_err(f_name, location))
             }
         }
     };
@@ -69,7 +170,7 @@ impl_nonzero!(isize => NonZeroIsize);
 ///
 /// Can be used with [crate::chk] by specifying `last_err` as the type of check,
 /// e.g.: `chk!(last_err; ...)`
-pub fn check_last_err<F, R>(f: F, f_name: &'static str) -> Result<R>
+pub fn check_last_err<F, R>(f: F, f_name: &'static str, location: (&'static str, u32)) -> Result<R>
 where
     F: FnOnce() -> R,
 {
@@ -83,6 +184,7 @@ where
         Err(Error::Unexpected {
             function: f_name,
             context: last_err.to_hresult().into(),
+            location: Some(location),
         })
     }
 }
@@ -92,11 +194,11 @@ where
 ///
 /// Can be used with [crate::chk] by specifying `bool` as the type of check,
 /// e.g.: `chk!(bool; ...)`
-pub fn check_bool<F>(f: F, f_name: &'static str) -> Result<()>
+pub fn check_bool<F>(f: F, f_name: &'static str, location: (&'static str, u32)) -> Result<()>
 where
     F: FnOnce() -> BOOL,
 {
-    f().ok().map_err(|_| get_last_err(f_name))
+    f().ok().map_err(|_| get_last_err(f_name, location))
 }
 
 /// Invokes a Win32 API which defines success by Win32 results. Maps
@@ -104,13 +206,14 @@ where
 ///
 /// Can be used with [crate::chk] by specifying `res` as the type of check,
 /// e.g.: `chk!(res; ...)`
-pub fn check_res<F, V>(f: F, f_name: &'static str) -> Result<V>
+pub fn check_res<F, V>(f: F, f_name: &'static str, location: (&'static str, u32)) -> Result<V>
 where
     F: FnOnce() -> Win32Result<V>,
 {
     f().map_err(|e| Error::Unexpected {
         function: f_name,
         context: e.into(),
+        location: Some(location),
     })
 }
 
@@ -119,7 +222,7 @@ where
 ///
 /// Can be used with [crate::chk] by specifying `ptr` as the type of check,
 /// e.g.: `chk!(ptr; ...)`
-pub fn check_ptr<F, P>(f: F, f_name: &'static str) -> Result<P>
+pub fn check_ptr<F, P>(f: F, f_name: &'static str, location: (&'static str, u32)) -> Result<P>
 where
     F: FnOnce() -> P,
     P: Win32Pointer,
@@ -127,7 +230,7 @@ where
     let ptr = f();
 
     if ptr.is_null() {
-        Err(get_last_err(f_name))
+        Err(get_last_err(f_name, location))
     } else {
         Ok(ptr)
     }
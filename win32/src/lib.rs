@@ -16,10 +16,18 @@
 
 pub mod debug;
 pub mod errors;
+pub mod event_loop;
 pub mod input;
 pub mod invoke;
+pub mod proc;
+pub mod signals;
 pub mod types;
 pub mod window;
 
 pub use errors::*;
+pub use input::keyboard::KeyCode;
 pub use types::*;
+
+/// Re-exported so callers can call [`KeyCode::iter`] without depending on
+/// `strum` directly.
+pub use ::strum::IntoEnumIterator;
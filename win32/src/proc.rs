@@ -5,9 +5,12 @@ use ::std::{
     cell::RefCell,
     rc::{Rc, Weak},
 };
-use ::windows::Win32::System::{
-    Com::{CoInitializeEx, CoUninitialize, COINIT_APARTMENTTHREADED},
-    Memory::{HeapEnableTerminationOnCorruption, HeapSetInformation},
+use ::windows::Win32::{
+    System::{
+        Com::{CoInitializeEx, CoUninitialize, COINIT_APARTMENTTHREADED},
+        Memory::{HeapEnableTerminationOnCorruption, HeapSetInformation},
+    },
+    UI::HiDpi::{SetProcessDpiAwarenessContext, DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2},
 };
 
 /// Enables the terminate-on-corruption feature. If the heap manager detects an
@@ -21,6 +24,21 @@ pub fn enable_heap_protection() -> bool {
     unsafe { HeapSetInformation(None, HeapEnableTerminationOnCorruption, None, 0).as_bool() }
 }
 
+/// Opts the whole process into per-monitor-v2 DPI awareness.
+///
+/// Without this, Windows treats the process as DPI-unaware and silently
+/// bitmap-stretches every window to the system DPI, which is what makes an
+/// unaware window look blurry after being dragged onto a differently-scaled
+/// monitor. This must be called before any window is created; changing DPI
+/// awareness after the fact has no effect.
+///
+/// Returns `true` if per-monitor-v2 awareness was successfully set, and
+/// `false` if the OS version doesn't support it (pre-Creators Update),
+/// leaving the process at its default awareness level.
+pub fn enable_per_monitor_dpi_awareness() -> bool {
+    unsafe { SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2) }.is_ok()
+}
+
 thread_local! {
     static COM_LIBRARY_HANDLE: RefCell<Weak<ComLibraryHandle>> = RefCell::new(Weak::new());
 }
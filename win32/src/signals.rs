@@ -0,0 +1,61 @@
+//! Console control event trapping (Ctrl+C, Ctrl+Break, console close), wired
+//! into graceful window shutdown.
+//!
+//! `SetConsoleCtrlHandler` invokes its callback on a dedicated OS thread
+//! created by the console subsystem, not the thread that owns our windows.
+//! Since [`crate::window::Window`] is `!Send`/`!Sync`, the handler must never
+//! touch a [`Window`](crate::window::Window) or its inner state directly -
+//! instead it only posts `WM_CLOSE` to each live window's `HWND`, gathered
+//! from a thread-safe registry, and lets that window's owning thread handle
+//! the close the same way it would a user click on the close button.
+
+use crate::{errors::Result, invoke::chk};
+
+use ::lazy_static::lazy_static;
+use ::parking_lot::Mutex;
+use ::windows::Win32::{
+    Foundation::{HWND, LPARAM, WPARAM},
+    System::Console::SetConsoleCtrlHandler,
+    UI::WindowsAndMessaging::{PostMessageW, WM_CLOSE},
+};
+
+lazy_static! {
+    /// Every window's `HWND`, registered so [`ctrl_handler`] can post
+    /// `WM_CLOSE` to them from the console control thread without touching
+    /// any `!Send` state.
+    static ref REGISTERED_HWNDS: Mutex<Vec<HWND>> = Default::default();
+}
+
+/// Registers `hwnd` to receive `WM_CLOSE` on a trapped console control
+/// event. Called once a window's `HWND` is known.
+pub(crate) fn register(hwnd: HWND) {
+    REGISTERED_HWNDS.lock().push(hwnd);
+}
+
+/// Unregisters `hwnd`, e.g. once its window has been destroyed.
+pub(crate) fn unregister(hwnd: HWND) {
+    REGISTERED_HWNDS.lock().retain(|&h| h != hwnd);
+}
+
+/// Installs a process-wide console control handler which posts `WM_CLOSE` to
+/// every registered window's `HWND` on Ctrl+C, Ctrl+Break, or the console
+/// window being closed, so a directx app running from a console terminates
+/// its windows cleanly instead of being hard-killed.
+///
+/// Only one handler needs to be installed per process, regardless of how
+/// many windows are created afterwards.
+pub fn install_ctrl_handler() -> Result<()> {
+    chk!(bool; SetConsoleCtrlHandler(Some(ctrl_handler), true))?;
+    Ok(())
+}
+
+extern "system" fn ctrl_handler(_ctrl_type: u32) -> ::windows::Win32::Foundation::BOOL {
+    for &hwnd in REGISTERED_HWNDS.lock().iter() {
+        let _ = unsafe { PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0)) };
+    }
+
+    // Report the event as handled so the OS doesn't forcibly terminate the
+    // process before our windows have had a chance to close on their own
+    // threads.
+    ::windows::Win32::Foundation::BOOL(1)
+}
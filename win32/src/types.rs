@@ -4,7 +4,7 @@ use ::std::{
     ffi::{CString, NulError},
     fmt::{self, Display},
 };
-use ::windows::core::PCSTR;
+use ::windows::core::{PCSTR, PCWSTR, PWSTR};
 
 /// An integer reference for a compiled windows resource (icons, cursors, etc).
 pub type ResourceId = isize;
@@ -62,3 +62,90 @@ mod winstr {
         }
     }
 }
+
+pub use widewinstr::*;
+mod widewinstr {
+    use super::*;
+
+    /// Returned when a string passed to [`WideWinString`]'s [`TryFrom`] impl
+    /// contains an embedded null character, which can't be represented in a
+    /// null-terminated wide string.
+    #[derive(Clone, Debug, ::thiserror::Error)]
+    #[error("string contains an embedded null character")]
+    pub struct ContainsNulError;
+
+    /// The Win32 API often encodes plain integer values in a PCWSTR, so we
+    /// allow for this in our inner type.
+    #[derive(Clone, Debug)]
+    enum Inner {
+        String(Vec<u16>),
+        Int(isize),
+    }
+
+    /// A convenience type which can be constructed from `&str` or `String`
+    /// and which can convert itself into a `PWSTR`/`PCWSTR`.
+    ///
+    /// This is the wide (UTF-16) counterpart to [`WinString`], for use with
+    /// the `...W` family of Win32 entry points, which properly handle
+    /// non-Latin text rather than lossily transcoding it through `CString`.
+    #[derive(Clone, Debug)]
+    pub struct WideWinString(Inner);
+
+    impl WideWinString {
+        pub fn from_resource_id(i: ResourceId) -> Self {
+            Self(Inner::Int(i))
+        }
+    }
+
+    impl Display for WideWinString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.0 {
+                Inner::String(s) => {
+                    write!(f, "{}", String::from_utf16_lossy(&s[..s.len() - 1]))
+                }
+                Inner::Int(i) => write!(f, "{}_isize", i),
+            }
+        }
+    }
+
+    impl TryFrom<&str> for WideWinString {
+        type Error = ContainsNulError;
+
+        fn try_from(s: &str) -> Result<Self, Self::Error> {
+            let mut wide: Vec<u16> = s.encode_utf16().collect();
+
+            if wide.contains(&0) {
+                return Err(ContainsNulError);
+            }
+
+            wide.push(0);
+            Ok(Self(Inner::String(wide)))
+        }
+    }
+
+    impl From<String> for WideWinString {
+        fn from(s: String) -> Self {
+            let mut wide: Vec<u16> = s.encode_utf16().take_while(|&c| c != 0).collect();
+            wide.push(0);
+            Self(Inner::String(wide))
+        }
+    }
+
+    impl From<&WideWinString> for PWSTR {
+        fn from(s: &WideWinString) -> Self {
+            match &s.0 {
+                Inner::String(s) => Self(s.as_ptr() as *mut u16),
+                Inner::Int(i) => Self(*i as _),
+            }
+        }
+    }
+
+    impl From<&WideWinString> for PCWSTR {
+        fn from(s: &WideWinString) -> Self {
+            match &s.0 {
+                Inner::String(s) => Self::from_raw(s.as_ptr()),
+                Inner::Int(i) => Self(*i as _),
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Process-lifetime management for Win32 message loops spanning multiple
+//! windows.
+//!
+//! [`crate::event_loop::EventLoop`] owns and pumps messages for a single
+//! [`Window`](super::Window). [`Application`] is the multi-window
+//! counterpart: it tracks every window created on the current thread via a
+//! live-window count, and drives the shared Win32 message loop until the
+//! last one has been destroyed.
+
+use super::inner::WindowInner;
+
+use ::std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use ::windows::Win32::UI::WindowsAndMessaging::{
+    DispatchMessageW, GetMessageW, PostQuitMessage, TranslateMessage, MSG,
+};
+
+thread_local! {
+    /// Every window created on this thread, tracked weakly so that
+    /// [`Application::close_all_windows`] can reach them without keeping
+    /// them alive itself. Windows are never removed once destroyed; dead
+    /// entries are simply skipped.
+    static LIVE_WINDOWS: RefCell<Vec<Weak<WindowInner>>> = RefCell::new(Vec::new());
+}
+
+/// The number of windows created on this thread that have not yet received
+/// `WM_NCDESTROY`.
+static LIVE_WINDOW_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Registers a newly-created window, incrementing the live-window count.
+/// Called once from [`WindowInner::new`](super::inner::WindowInner::new).
+pub(super) fn register(window: &Rc<WindowInner>) {
+    LIVE_WINDOWS.with(|windows| windows.borrow_mut().push(Rc::downgrade(window)));
+    LIVE_WINDOW_COUNT.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Decrements the live-window count, returning the new count. Called from
+/// the `WM_NCDESTROY` arm of `WindowInner::handle_message` as the window is
+/// torn down on the Win32 side.
+pub(super) fn window_destroyed() -> usize {
+    LIVE_WINDOW_COUNT.fetch_sub(1, Ordering::SeqCst) - 1
+}
+
+/// Drives the process-wide Win32 message loop across every window on the
+/// current thread, for apps with more than one top-level [`Window`](super::Window)
+/// (a single-window app can use the simpler [`crate::event_loop::EventLoop`]
+/// instead).
+pub struct Application {
+    _private: (),
+}
+
+impl Application {
+    /// Runs the Win32 message loop, blocking until every window created on
+    /// this thread has been destroyed (or [`Self::quit`] is called), at
+    /// which point `WM_QUIT` is posted and this returns.
+    pub fn run() {
+        let mut msg = MSG::default();
+        while unsafe { GetMessageW(&mut msg, None, 0, 0) }.as_bool() {
+            unsafe { TranslateMessage(&msg) };
+            unsafe { DispatchMessageW(&msg) };
+        }
+    }
+
+    /// Posts `WM_QUIT`, ending the next [`Self::run`] iteration even if
+    /// windows are still open. Safe to call from a message handler, or from
+    /// another thread/task that only has access to a raw `HWND`.
+    pub fn quit() {
+        unsafe { PostQuitMessage(0) };
+    }
+
+    /// Destroys every window created on this thread that hasn't already been
+    /// dropped, so a caller without a `Window` handle of its own (e.g. a
+    /// console control handler, or a headless task) can shut the UI down
+    /// cleanly.
+    pub fn close_all_windows() {
+        LIVE_WINDOWS.with(|windows| {
+            for window in windows.borrow().iter() {
+                if let Some(window) = window.upgrade() {
+                    let _ = window.destroy();
+                }
+            }
+        });
+    }
+}
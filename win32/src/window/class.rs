@@ -16,7 +16,7 @@ use ::windows::{
         System::LibraryLoader::GetModuleHandleW,
         UI::WindowsAndMessaging::{
             LoadCursorW, LoadImageW, RegisterClassExW, UnregisterClassW, CS_HREDRAW, CS_VREDRAW,
-            HICON, IDC_ARROW, IMAGE_ICON, LR_DEFAULTSIZE, WNDCLASSEXW,
+            HCURSOR, HICON, IDC_ARROW, IMAGE_CURSOR, IMAGE_ICON, LR_DEFAULTSIZE, WNDCLASSEXW,
         },
     },
 };
@@ -44,6 +44,7 @@ impl WindowClass {
     pub(super) fn get_or_create(
         class_name_prefix: &str,
         icon_id: Option<ResourceId>,
+        cursor_id: Option<ResourceId>,
         wnd_proc_setup: WndProc,
     ) -> Result<Arc<Self>> {
         let mut registry = WINDOW_REGISTRATIONS.lock();
@@ -51,12 +52,16 @@ impl WindowClass {
         if let Some(icon) = icon_id {
             class_name.write_fmt(format_args!("-{icon}")).unwrap();
         }
+        if let Some(cursor) = cursor_id {
+            class_name.write_fmt(format_args!("-cursor{cursor}")).unwrap();
+        }
         let class_name =
             U16CString::from_str(class_name).expect("Null byte found in window class name");
 
         match registry.entry(class_name) {
             Entry::Vacant(entry) => {
-                let class = Self::register(entry.key().clone(), icon_id, wnd_proc_setup)?;
+                let class =
+                    Self::register(entry.key().clone(), icon_id, cursor_id, wnd_proc_setup)?;
                 entry.insert(Arc::downgrade(&class));
                 Ok(class)
             }
@@ -64,7 +69,8 @@ impl WindowClass {
                 if let Some(strong_ref) = entry.get().upgrade() {
                     Ok(strong_ref)
                 } else {
-                    let class = Self::register(entry.key().clone(), icon_id, wnd_proc_setup)?;
+                    let class =
+                        Self::register(entry.key().clone(), icon_id, cursor_id, wnd_proc_setup)?;
                     entry.insert(Arc::downgrade(&class));
                     Ok(class)
                 }
@@ -79,6 +85,7 @@ impl WindowClass {
     fn register(
         class_name: U16CString,
         icon_id: Option<ResourceId>,
+        cursor_id: Option<ResourceId>,
         wnd_proc_setup: WndProc,
     ) -> Result<Arc<Self>> {
         debug!(
@@ -87,12 +94,22 @@ impl WindowClass {
         );
 
         let module = chk!(res; GetModuleHandleW(None))?;
-        let cursor = chk!(res;
-            LoadCursorW(
-                HINSTANCE::default(),
-                IDC_ARROW
-            )
-        )?;
+        let cursor = match cursor_id {
+            Some(resource_id) => HCURSOR(
+                chk!(res;
+                    LoadImageW(
+                        module,
+                        resource_id.into_pcwstr(),
+                        IMAGE_CURSOR,
+                        0,
+                        0,
+                        LR_DEFAULTSIZE
+                    )
+                )?
+                .0,
+            ),
+            None => chk!(res; LoadCursorW(HINSTANCE::default(), IDC_ARROW))?,
+        };
         let icon = icon_id
             .map(|resource_id: ResourceId| {
                 chk!(res;
@@ -0,0 +1,64 @@
+//! Stock system cursor identifiers.
+
+use ::windows::{
+    core::PCWSTR,
+    Win32::UI::WindowsAndMessaging::{
+        IDC_APPSTARTING, IDC_ARROW, IDC_CROSS, IDC_HAND, IDC_IBEAM, IDC_NO, IDC_SIZEALL,
+        IDC_SIZENESW, IDC_SIZENS, IDC_SIZENWSE, IDC_SIZEWE, IDC_WAIT,
+    },
+};
+
+/// One of the system's built-in cursors, loadable via `LoadCursorW`.
+///
+/// Used by [`Window::set_cursor`](super::Window::set_cursor) to change the
+/// cursor shown over a window's client area at runtime. For a custom cursor
+/// resource baked into the window class itself, see
+/// [`WindowStyle::with_cursor`](super::WindowStyle::with_cursor).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Cursor {
+    /// The default pointer arrow.
+    #[default]
+    Arrow,
+    /// A pointing hand, typically used over links/buttons.
+    Hand,
+    /// A text-editing I-beam.
+    IBeam,
+    /// The hourglass/spinner shown while blocking on a short operation.
+    Wait,
+    /// An arrow with a small hourglass, shown during a background operation.
+    AppStarting,
+    /// A crosshair, typically used for precise selection.
+    Cross,
+    /// A "not allowed" slashed circle.
+    No,
+    /// A four-way arrow, for moving/resizing in any direction.
+    SizeAll,
+    /// A vertical resize arrow.
+    SizeNs,
+    /// A horizontal resize arrow.
+    SizeWe,
+    /// A diagonal resize arrow along the northeast/southwest axis.
+    SizeNesw,
+    /// A diagonal resize arrow along the northwest/southeast axis.
+    SizeNwse,
+}
+
+impl Cursor {
+    /// The `IDC_*` resource identifier `LoadCursorW` expects for this cursor.
+    pub(super) const fn idc(self) -> PCWSTR {
+        match self {
+            Self::Arrow => IDC_ARROW,
+            Self::Hand => IDC_HAND,
+            Self::IBeam => IDC_IBEAM,
+            Self::Wait => IDC_WAIT,
+            Self::AppStarting => IDC_APPSTARTING,
+            Self::Cross => IDC_CROSS,
+            Self::No => IDC_NO,
+            Self::SizeAll => IDC_SIZEALL,
+            Self::SizeNs => IDC_SIZENS,
+            Self::SizeWe => IDC_SIZEWE,
+            Self::SizeNesw => IDC_SIZENESW,
+            Self::SizeNwse => IDC_SIZENWSE,
+        }
+    }
+}
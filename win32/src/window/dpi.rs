@@ -72,10 +72,7 @@ impl DPI {
         T: Num + Clone + Copy + Debug + AsPrimitive<f32> + 'static,
         f32: AsPrimitive<T>,
     {
-        Size2D {
-            width: self.scale_dip(size.width),
-            height: self.scale_dip(size.height),
-        }
+        Size2D::new(self.scale_dip(size.width), self.scale_dip(size.height))
     }
 
     /// Scale a [`Rect2D`] representing Device Independent Pixels (DIP) by the
@@ -91,11 +88,62 @@ impl DPI {
         T: Num + Clone + Copy + Debug + AsPrimitive<f32> + 'static,
         f32: AsPrimitive<T>,
     {
-        Rect2D {
-            left: self.scale_dip(rect.left),
-            right: self.scale_dip(rect.right),
-            top: self.scale_dip(rect.top),
-            bottom: self.scale_dip(rect.bottom),
-        }
+        Rect2D::new(
+            self.scale_dip(rect.left),
+            self.scale_dip(rect.top),
+            self.scale_dip(rect.right),
+            self.scale_dip(rect.bottom),
+        )
+    }
+
+    /// The ratio of this DPI to the Windows default of 96, e.g. `1.5` at
+    /// 144 dpi (150% scaling). Equivalent to [`Self::scale_dip`] applied to
+    /// `1.0`, but named to match the `scale_factor` terminology callers
+    /// converting between logical and physical coordinates expect.
+    pub fn scale_factor(&self) -> f32 {
+        self.0 / 96.0
+    }
+
+    /// Unscale a raw pixel dimension by the DPI to an equivalent Device
+    /// Independent Pixel (DIP).
+    ///
+    /// The inverse of [`Self::scale_dip`]: useful for converting physical
+    /// pixel coordinates, e.g. from a mouse message's `lParam`, back into the
+    /// DIPs the rest of the application works in.
+    pub fn unscale_dip<T>(&self, pixels: T) -> T
+    where
+        T: Num + Clone + Copy + Debug + AsPrimitive<f32> + 'static,
+        f32: AsPrimitive<T>,
+    {
+        (pixels.as_() / (self.0 / 96.0)).ceil().as_()
+    }
+
+    /// Unscale a [`Size2D`] representing raw pixel dimensions by the DPI to
+    /// an equivalent [`Size2D`] in Device Independent Pixels (DIP).
+    ///
+    /// The inverse of [`Self::scale_size`].
+    pub fn unscale_size<T>(&self, size: Size2D<T>) -> Size2D<T>
+    where
+        T: Num + Clone + Copy + Debug + AsPrimitive<f32> + 'static,
+        f32: AsPrimitive<T>,
+    {
+        Size2D::new(self.unscale_dip(size.width), self.unscale_dip(size.height))
+    }
+
+    /// Unscale a [`Rect2D`] representing raw pixel dimensions by the DPI to
+    /// an equivalent [`Rect2D`] in Device Independent Pixels (DIP).
+    ///
+    /// The inverse of [`Self::scale_rect`].
+    pub fn unscale_rect<T>(&self, rect: Rect2D<T>) -> Rect2D<T>
+    where
+        T: Num + Clone + Copy + Debug + AsPrimitive<f32> + 'static,
+        f32: AsPrimitive<T>,
+    {
+        Rect2D::new(
+            self.unscale_dip(rect.left),
+            self.unscale_dip(rect.top),
+            self.unscale_dip(rect.right),
+            self.unscale_dip(rect.bottom),
+        )
     }
 }
@@ -0,0 +1,144 @@
+//! `IDropTarget` implementation which translates OLE drag-and-drop
+//! notifications for dropped files into [`Event`]s.
+
+use crate::window::{Event, WindowInner};
+
+use ::std::{path::PathBuf, rc::Weak};
+use ::widestring::U16CString;
+use ::windows::{
+    core::implement,
+    Win32::{
+        Foundation::POINT,
+        System::{
+            Com::{IDataObject, FORMATETC, TYMED_HGLOBAL},
+            Ole::{
+                IDropTarget, IDropTarget_Impl, DROPEFFECT, DROPEFFECT_COPY, DROPEFFECT_NONE,
+                DVASPECT_CONTENT,
+            },
+        },
+        UI::Shell::{DragQueryFileW, HDROP},
+    },
+};
+
+/// Clipboard format identifying an `HDROP` handle of dropped file paths, as
+/// defined by `WinUser.h`.
+const CF_HDROP: u16 = 15;
+
+/// An `IDropTarget` COM object registered (via `RegisterDragDrop`) against a
+/// single window's `HWND` to receive file drag-and-drop notifications.
+///
+/// Holds only a [`Weak`] reference back to the [`WindowInner`] it was
+/// registered for, so an in-flight drag operation never keeps a window alive
+/// past its last `Window` handle being dropped; notifications that arrive
+/// after that are silently ignored.
+#[implement(IDropTarget)]
+pub(super) struct DropTarget {
+    window: Weak<WindowInner>,
+}
+
+impl DropTarget {
+    /// Wraps `window` in a COM `IDropTarget` ready to pass to
+    /// `RegisterDragDrop`.
+    pub(super) fn new(window: Weak<WindowInner>) -> IDropTarget {
+        DropTarget { window }.into()
+    }
+}
+
+impl IDropTarget_Impl for DropTarget_Impl {
+    fn DragEnter(
+        &self,
+        pdataobj: Option<&IDataObject>,
+        _grfkeystate: u32,
+        _pt: &POINT,
+        pdweffect: *mut DROPEFFECT,
+    ) -> ::windows::core::Result<()> {
+        let path = pdataobj.and_then(first_dropped_file);
+
+        if let (Some(window), Some(path)) = (self.window.upgrade(), path.clone()) {
+            window.push_event(Event::HoveredFile(path));
+        }
+
+        unsafe { *pdweffect = if path.is_some() { DROPEFFECT_COPY } else { DROPEFFECT_NONE } };
+        Ok(())
+    }
+
+    fn DragOver(
+        &self,
+        _grfkeystate: u32,
+        _pt: &POINT,
+        pdweffect: *mut DROPEFFECT,
+    ) -> ::windows::core::Result<()> {
+        unsafe { *pdweffect = DROPEFFECT_COPY };
+        Ok(())
+    }
+
+    fn DragLeave(&self) -> ::windows::core::Result<()> {
+        if let Some(window) = self.window.upgrade() {
+            window.push_event(Event::HoveredFileCancelled);
+        }
+
+        Ok(())
+    }
+
+    fn Drop(
+        &self,
+        pdataobj: Option<&IDataObject>,
+        _grfkeystate: u32,
+        _pt: &POINT,
+        pdweffect: *mut DROPEFFECT,
+    ) -> ::windows::core::Result<()> {
+        let files = pdataobj.map(dropped_files).unwrap_or_default();
+
+        if let Some(window) = self.window.upgrade() {
+            for path in files.iter().cloned() {
+                window.push_event(Event::DroppedFile(path));
+            }
+        }
+
+        unsafe { *pdweffect = if files.is_empty() { DROPEFFECT_NONE } else { DROPEFFECT_COPY } };
+        Ok(())
+    }
+}
+
+/// Extracts every file path carried by a drop's `CF_HDROP` clipboard data, or
+/// an empty [`Vec`] if `data` doesn't carry `CF_HDROP` data.
+fn dropped_files(data: &IDataObject) -> Vec<PathBuf> {
+    let Some(hdrop) = query_hdrop(data) else {
+        return Vec::new();
+    };
+
+    let file_count = unsafe { DragQueryFileW(hdrop, u32::MAX, None) };
+
+    (0..file_count)
+        .filter_map(|i| {
+            let len = unsafe { DragQueryFileW(hdrop, i, None) } as usize;
+            let mut buf = vec![0u16; len + 1];
+            let written = unsafe { DragQueryFileW(hdrop, i, Some(&mut buf)) };
+            (written > 0).then(|| {
+                buf.truncate(written as usize);
+                PathBuf::from(U16CString::from_vec_truncate(buf).to_string_lossy())
+            })
+        })
+        .collect()
+}
+
+/// Convenience for drag-enter previews, which only ever surface the first
+/// hovered file as [`Event::HoveredFile`].
+fn first_dropped_file(data: &IDataObject) -> Option<PathBuf> {
+    dropped_files(data).into_iter().next()
+}
+
+/// Retrieves the `HDROP` handle from a data object's `CF_HDROP` format, if
+/// it carries one.
+fn query_hdrop(data: &IDataObject) -> Option<HDROP> {
+    let format = FORMATETC {
+        cfFormat: CF_HDROP,
+        ptd: ::std::ptr::null_mut(),
+        dwAspect: DVASPECT_CONTENT.0 as u32,
+        lindex: -1,
+        tymed: TYMED_HGLOBAL.0 as u32,
+    };
+
+    let medium = unsafe { data.GetData(&format) }.ok()?;
+    Some(HDROP(unsafe { medium.u.hGlobal }.0))
+}
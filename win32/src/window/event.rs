@@ -0,0 +1,135 @@
+//! Decoded, strongly-typed window events.
+
+use crate::{
+    input::{
+        keyboard::KeyCode,
+        raw_input::{RawInputKeyEvent, RawInputMouseEvent},
+    },
+    window::DPI,
+};
+
+use ::std::path::PathBuf;
+use ::win_geom::d2::{Point2D, Size2D};
+
+/// Whether a key or mouse button transitioned to pressed or released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyState {
+    /// The key/button was just pressed (or is auto-repeating while held).
+    Pressed,
+    /// The key/button was just released.
+    Released,
+}
+
+/// A single decoded window event, translated from a raw Win32 message as
+/// `WindowInner` handles it.
+///
+/// These are pushed into a per-window queue and drained by
+/// [`Window::drain_events`](super::Window::drain_events), most conveniently
+/// via [`crate::event_loop::EventLoop`].
+#[derive(Clone, Debug)]
+pub enum Event {
+    /// The window's client area was resized to the given size.
+    Resized(Size2D<i32>),
+    /// The window has been asked to close, typically because the user
+    /// clicked its close button. The window is not actually destroyed until
+    /// the owning [`Window`](super::Window) is dropped.
+    CloseRequested,
+    /// The window's contents need to be repainted.
+    RedrawRequested,
+    /// A keyboard key was pressed or released.
+    KeyboardInput {
+        /// The key that changed state.
+        key: KeyCode,
+        /// Whether the key was pressed or released.
+        state: KeyState,
+    },
+    /// A mouse button was pressed or released.
+    MouseInput {
+        /// The mouse button that changed state.
+        button: KeyCode,
+        /// Whether the button was pressed or released.
+        state: KeyState,
+    },
+    /// The mouse cursor moved within the window's client area.
+    CursorMoved(Point2D<i32>),
+    /// The mouse wheel was scrolled.
+    MouseWheel {
+        /// The number of notches scrolled, positive for up/right and
+        /// negative for down/left. Usually `1.0`/`-1.0` per detent, but high
+        /// resolution wheels can report fractional values.
+        delta: f32,
+        /// Whether this is a horizontal scroll (the shift-tilt wheel or a
+        /// horizontal trackpad gesture) rather than the usual vertical one.
+        horizontal: bool,
+    },
+    /// The window's DPI changed, typically because it was dragged to a
+    /// monitor with a different scale factor.
+    ///
+    /// [`Window::dpi`](super::Window::dpi) already reflects `new_dpi`, and
+    /// [`Window::size`](super::Window::size) already reflects `new_size`, by
+    /// the time this is observed. Consumers rendering with a DPI-dependent
+    /// device (e.g. a Direct2D render target) should use this to resize and
+    /// rebuild their device resources at the new scale, rather than waiting
+    /// for the separate [`Self::Resized`] this also triggers.
+    DpiChanged {
+        /// The window's new DPI.
+        new_dpi: DPI,
+        /// The window's new client area size, in DIPs, after being
+        /// repositioned to fit the new DPI.
+        new_size: Size2D<i32>,
+    },
+    /// A file is being dragged over the window's client area, having just
+    /// entered it or moved within it. Only emitted for windows that opted in
+    /// via [`Window::enable_file_drop`](super::Window::enable_file_drop).
+    HoveredFile(PathBuf),
+    /// A file being dragged over the window's client area left it, or the
+    /// drag operation was otherwise cancelled, without being dropped.
+    HoveredFileCancelled,
+    /// A file was dropped onto the window's client area.
+    DroppedFile(PathBuf),
+    /// The window gained (`true`) or lost (`false`) keyboard focus.
+    Focused(bool),
+    /// An IME composition session has started, e.g. the user began typing
+    /// with an East-Asian input method. No candidate text is available yet.
+    CompositionStart,
+    /// The in-progress IME composition string changed. `cursor` is the
+    /// caret position within `text`, in UTF-16 code units, useful for
+    /// positioning a candidate window. This is purely for rendering; the
+    /// eventual committed text still arrives as ordinary
+    /// [`Self::KeyboardInput`]-driven character input.
+    CompositionUpdate {
+        /// The current, uncommitted composition string.
+        text: String,
+        /// The caret position within `text`, in UTF-16 code units.
+        cursor: usize,
+    },
+    /// The IME composition session ended, either because it was committed
+    /// or cancelled.
+    CompositionEnd {
+        /// The composed result, or empty if the composition was cancelled.
+        text: String,
+    },
+    /// The OS session is ending, via `WM_ENDSESSION`, typically because the
+    /// user is logging off or the system is shutting down/restarting.
+    /// There's no way to cancel this once received; the process should save
+    /// any state and prepare to exit.
+    SessionEnding {
+        /// `true` if the user is logging off without shutting down the
+        /// system.
+        logoff: bool,
+    },
+    /// The window has been destroyed. Sent once, as the last event of an
+    /// [`EventLoop`](crate::event_loop::EventLoop) run.
+    Destroyed,
+    /// A keyboard keystroke read directly from the Raw Input API, bypassing
+    /// the message-coalescing and auto-repeat of [`Self::KeyboardInput`].
+    /// Only emitted for windows that opted in via
+    /// [`Window::enable_raw_input`](super::Window::enable_raw_input).
+    RawKeyInput(RawInputKeyEvent),
+    /// A mouse movement/button/wheel sample read directly from the Raw
+    /// Input API, with unaccelerated relative motion deltas unlike
+    /// [`Self::CursorMoved`]'s absolute, accelerated coordinates. Only
+    /// emitted for windows that opted in via
+    /// [`Window::enable_raw_input`](super::Window::enable_raw_input).
+    RawMouseInput(RawInputMouseEvent),
+}
@@ -1,34 +1,55 @@
 use crate::{
     errors::*,
-    input::keyboard::{Adapter as KbdAdapter, Keyboard},
+    input::{
+        keyboard::{Adapter as KbdAdapter, Keyboard, RawKeyEvent},
+        mouse::{Adapter as MouseAdapter, Mouse, RawMouseEvent},
+        raw_input::{Adapter as RawInputAdapter, RawInputEvent},
+    },
     invoke::chk,
     types::*,
-    window::WindowClass,
+    window::{
+        Cursor, DropTarget, Event, KeyState, Monitor, NextMessageAction, Theme, WindowClass,
+        WindowStyle, DPI,
+    },
 };
 
 use ::geom::d2::{Point2D, Rect2D, Size2D};
 use ::parking_lot::RwLock;
 use ::std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
     ops::DerefMut,
-    rc::Rc,
+    rc::{Rc, Weak},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
 };
 use ::tracing::debug;
-use ::widestring::U16CString;
+use ::widestring::{U16CStr, U16CString};
 use ::windows::{
     core::PCWSTR,
     Win32::{
-        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
-        System::LibraryLoader::GetModuleHandleW,
+        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM},
+        Graphics::{
+            Dwm::{DwmSetWindowAttribute, DWMWA_USE_IMMERSIVE_DARK_MODE},
+            Gdi::{BeginPaint, ClientToScreen, EndPaint, PAINTSTRUCT},
+        },
+        System::{
+            LibraryLoader::GetModuleHandleW,
+            Ole::{IDropTarget, RegisterDragDrop, RevokeDragDrop},
+        },
         UI::WindowsAndMessaging::{
-            AdjustWindowRectEx, CreateWindowExW, DefWindowProcW, DestroyWindow, GetWindowLongPtrW,
-            SetWindowLongPtrW, ShowWindow, CREATESTRUCTW, CW_USEDEFAULT, GWLP_USERDATA,
-            GWLP_WNDPROC, SW_SHOWNORMAL, WINDOW_EX_STYLE, WM_CLOSE, WM_NCCREATE, WM_NCDESTROY,
-            WS_OVERLAPPEDWINDOW,
+            AdjustWindowRectEx, ClipCursor, CreateWindowExW, DefWindowProcW, DestroyWindow,
+            GetClientRect, GetWindowLongPtrW, GetWindowRect, LoadCursorW, PostQuitMessage,
+            ReleaseCapture, SetCapture, SetCursor, SetWindowLongPtrW, SetWindowPos, ShowCursor,
+            ShowWindow,
+            CREATESTRUCTW, CW_USEDEFAULT, ENDSESSION_LOGOFF, GWLP_USERDATA, GWLP_WNDPROC,
+            GWL_EXSTYLE, GWL_STYLE, HCURSOR, HTCLIENT, SW_SHOWNORMAL, SWP_FRAMECHANGED,
+            SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOZORDER, WINDOW_EX_STYLE, WINDOW_STYLE, WM_CLOSE,
+            WM_DPICHANGED, WM_ENDSESSION, WM_KILLFOCUS, WM_NCCREATE, WM_NCDESTROY, WM_PAINT,
+            WM_SETCURSOR, WM_SETFOCUS, WM_SETTINGCHANGE, WM_SIZE, WS_EX_NOREDIRECTIONBITMAP,
+            WS_MAXIMIZEBOX, WS_OVERLAPPEDWINDOW, WS_POPUP, WS_THICKFRAME, WS_VISIBLE,
         },
     },
 };
@@ -41,16 +62,70 @@ pub(super) struct WindowInner {
     /// A handle to our corresponding Win32 window. If zero, the window has been
     /// destroyed on the Win32 size.
     hwnd: Cell<isize>,
-    /// Fixed size for our window's client area.
-    size: Size2D<i32>,
+    /// The window's client area, most recently observed via `WM_SIZE`.
+    size: Cell<Size2D<i32>>,
     /// The Window's title, as it appears in the Windows title bar.
     title: String,
     /// Stores an outstanding close request from the Win32 side. This must
     /// either be actioned by dropping the top level window, or the close
     /// request can be cleared if it is to be ignored.
     close_request: AtomicBool,
+    /// Set when `WM_PAINT` reports the window needs to be repainted, until
+    /// cleared by [`Self::clear_redraw_request`].
+    redraw_requested: Cell<bool>,
     /// Keyboard and text input state.
     keyboard: RwLock<Keyboard>,
+    /// Mouse button and cursor position state.
+    mouse: RwLock<Mouse>,
+    /// The currently applied window theme (light/dark title bar).
+    theme: Cell<Theme>,
+    /// Set when `WM_SETTINGCHANGE` reports the system theme has changed,
+    /// until cleared by [`Self::clear_theme_changed`].
+    theme_changed: Cell<bool>,
+    /// The window's most recently observed DPI, cached so it survives
+    /// monitor changes without a fresh `GetDpiForWindow` call on every read.
+    dpi: Cell<DPI>,
+    /// Set when `WM_DPICHANGED` reports the window's scale factor has
+    /// changed, until cleared by [`Self::clear_scale_factor_changed`].
+    scale_factor_changed: Cell<bool>,
+    /// Queue of [`Event`]s translated from Win32 messages, awaiting
+    /// [`Self::drain_events`].
+    events: RefCell<VecDeque<Event>>,
+    /// The `IDropTarget` registered via [`Self::enable_file_drop`], if any.
+    /// Kept alive here since `RegisterDragDrop` only borrows it; revoked and
+    /// dropped in [`Self::destroy`].
+    drop_target: RefCell<Option<IDropTarget>>,
+    /// The cursor most recently set via [`Self::set_cursor`], re-applied on
+    /// every `WM_SETCURSOR` while the pointer is over the client area.
+    cursor: Cell<isize>,
+    /// Whether the cursor is currently shown, toggled by
+    /// [`Self::set_cursor_visible`].
+    cursor_visible: Cell<bool>,
+    /// Whether the cursor is currently confined to the client area, toggled
+    /// by [`Self::set_cursor_grab`]. `ClipCursor` is process-wide and has no
+    /// notion of window focus, so this is released on `WM_KILLFOCUS` and
+    /// re-applied on `WM_SETFOCUS` to avoid trapping the cursor while the
+    /// user has alt-tabbed to another window.
+    cursor_grabbed: Cell<bool>,
+    /// The window's placement and style from just before it entered
+    /// fullscreen via [`Self::set_fullscreen`], restored when it exits.
+    /// `None` while windowed.
+    windowed_placement: RefCell<Option<WindowedPlacement>>,
+    /// User-installed handlers from [`Window::on_message`](super::Window::on_message),
+    /// keyed by `umsg`, consulted before this crate's own handling of a
+    /// message.
+    message_handlers: RefCell<HashMap<u32, Rc<dyn Fn(HWND, WPARAM, LPARAM) -> NextMessageAction>>>,
+}
+
+/// The window's placement and style as of entering fullscreen, saved so
+/// [`WindowInner::set_fullscreen`] can restore it on exit.
+struct WindowedPlacement {
+    /// The window's rect (in screen coordinates, including chrome) before
+    /// it was resized to cover the target monitor.
+    rect: RECT,
+    /// The value of the `GWL_STYLE` window long before [`WS_POPUP`] replaced
+    /// it for the borderless fullscreen window.
+    style: isize,
 }
 
 impl WindowInner {
@@ -59,38 +134,87 @@ impl WindowInner {
         size: Size2D<i32>,
         title: &str,
         icon_id: Option<ResourceId>,
+        theme: Theme,
+        style: WindowStyle,
     ) -> Result<Rc<Self>> {
         debug!(wnd_title = %title, "Creating window inner");
 
+        let default_cursor = chk!(res; LoadCursorW(HINSTANCE::default(), Cursor::default().idc()))?;
+
         let this = Rc::new(Self {
             title: title.to_string(),
-            window_class: WindowClass::get_or_create("MainWindow", icon_id, Self::wnd_proc_setup)?,
+            window_class: WindowClass::get_or_create(
+                "MainWindow",
+                icon_id,
+                style.cursor_id,
+                Self::wnd_proc_setup,
+            )?,
             hwnd: Default::default(),
-            size,
+            size: Cell::new(size),
             close_request: AtomicBool::new(false),
+            redraw_requested: Cell::new(false),
             keyboard: RwLock::new(Keyboard::new()),
+            mouse: RwLock::new(Mouse::new()),
+            theme: Cell::new(theme),
+            theme_changed: Cell::new(false),
+            dpi: Cell::new(DPI::from(96.0)),
+            scale_factor_changed: Cell::new(false),
+            events: RefCell::new(VecDeque::new()),
+            drop_target: RefCell::new(None),
+            cursor: Cell::new(default_cursor.0),
+            cursor_visible: Cell::new(true),
+            cursor_grabbed: Cell::new(false),
+            windowed_placement: RefCell::new(None),
+            message_handlers: RefCell::new(HashMap::new()),
         });
 
+        super::application::register(&this);
+
+        let ex_style = if style.composition {
+            WS_EX_NOREDIRECTIONBITMAP
+        } else {
+            WINDOW_EX_STYLE::default()
+        };
+        let window_style = if style.fixed_size {
+            WINDOW_STYLE(WS_OVERLAPPEDWINDOW.0 & !WS_THICKFRAME.0 & !WS_MAXIMIZEBOX.0)
+        } else {
+            WS_OVERLAPPEDWINDOW
+        };
+
         let hwnd = {
             let module = chk!(res; GetModuleHandleW(None))?;
             let mut rect = Rect2D::from_size_with_origin(size, Point2D::default()).into();
             chk!(bool; AdjustWindowRectEx(
                 &mut rect,
-                WS_OVERLAPPEDWINDOW,
+                window_style,
                 false,
-                WINDOW_EX_STYLE::default()
+                ex_style
             ))?;
             let title = U16CString::from_str(title).expect("Window name contained null byte");
 
+            let width = rect.right - rect.left;
+            let height = rect.bottom - rect.top;
+            let (x, y) = if let Some(position) = style.position {
+                (position.x, position.y)
+            } else if let Some(monitor) = &style.monitor {
+                let bounds = monitor.bounds();
+                (
+                    bounds.left + (bounds.width() - width) / 2,
+                    bounds.top + (bounds.height() - height) / 2,
+                )
+            } else {
+                (CW_USEDEFAULT, CW_USEDEFAULT)
+            };
+
             chk!(ptr; CreateWindowExW(
-                    WINDOW_EX_STYLE::default(),
+                    ex_style,
                     PCWSTR::from_raw(this.window_class.class_name().as_ptr()),
                     PCWSTR::from_raw(title.as_ptr()),
-                    WS_OVERLAPPEDWINDOW,
-                    CW_USEDEFAULT,
-                    CW_USEDEFAULT,
-                    rect.right - rect.left,
-                    rect.bottom - rect.top,
+                    window_style,
+                    x,
+                    y,
+                    width,
+                    height,
                     None,
                     None,
                     module,
@@ -99,6 +223,8 @@ impl WindowInner {
             )?
         };
         unsafe { ShowWindow(hwnd, SW_SHOWNORMAL) };
+        this.apply_theme(hwnd, theme);
+        this.dpi.set(DPI::detect(hwnd));
 
         // Note: We don't store `hwnd` in `this` here. Instead we store the
         // handle when if first appears in the window proc function.
@@ -108,8 +234,8 @@ impl WindowInner {
 
     /// The size of the client area of our Win32 window. The window chrome
     /// is in addition to this siz3.
-    pub(super) const fn size(&self) -> Size2D<i32> {
-        self.size
+    pub(super) fn size(&self) -> Size2D<i32> {
+        self.size.get()
     }
 
     pub(super) fn title(&self) -> &str {
@@ -138,7 +264,279 @@ impl WindowInner {
         self.keyboard.write()
     }
 
+    pub fn mouse(&self) -> impl DerefMut<Target = Mouse> + '_ {
+        self.mouse.write()
+    }
+
+    /// Returns whether the window has requested to redraw, and immediately
+    /// clears this request.
+    pub(super) fn clear_redraw_request(&self) -> bool {
+        self.redraw_requested.replace(false)
+    }
+
+    /// Drains all events translated from Win32 messages since this was last
+    /// called, in the order they occurred.
+    pub(super) fn drain_events(&self) -> Vec<Event> {
+        self.events.borrow_mut().drain(..).collect()
+    }
+
+    /// Pushes `event` onto the queue drained by [`Self::drain_events`].
+    ///
+    /// Used by [`handle_message`](Self::handle_message) itself, and by
+    /// [`DropTarget`], which translates `IDropTarget` callbacks from outside
+    /// the normal window procedure.
+    pub(super) fn push_event(&self, event: Event) {
+        self.events.borrow_mut().push_back(event);
+    }
+
+    /// Opts this window into file drag-and-drop notifications (see
+    /// [`Event::HoveredFile`]/[`Event::HoveredFileCancelled`]/
+    /// [`Event::DroppedFile`]) by registering an `IDropTarget` against its
+    /// `hwnd` via `RegisterDragDrop`.
+    ///
+    /// The calling thread must already hold a
+    /// [`ComLibraryHandle`](crate::proc::ComLibraryHandle), as for any other
+    /// COM API this crate uses.
+    pub(super) fn enable_file_drop(&self, weak: Weak<Self>) -> Result<()> {
+        let target = DropTarget::new(weak);
+        chk!(res; RegisterDragDrop(self.hwnd(), &target))?;
+        self.drop_target.borrow_mut().replace(target);
+        Ok(())
+    }
+
+    /// Opts this window into high-frequency Raw Input notifications (see
+    /// [`Event::RawKeyInput`]/[`Event::RawMouseInput`]) by registering it
+    /// for the keyboard and mouse usage pages via `RegisterRawInputDevices`.
+    pub(super) fn enable_raw_input(&self) -> Result<()> {
+        RawInputAdapter::register(self.hwnd())
+    }
+
+    /// The window's currently applied theme (light/dark title bar).
+    pub(super) fn current_theme(&self) -> Theme {
+        self.theme.get()
+    }
+
+    /// Sets the window's title bar to match `theme`.
+    pub(super) fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.apply_theme(self.hwnd(), theme);
+    }
+
+    /// Returns whether the system theme has changed since this was last
+    /// called, and immediately clears the flag.
+    pub(super) fn clear_theme_changed(&self) -> bool {
+        self.theme_changed.swap(false, Ordering::SeqCst)
+    }
+
+    /// The window's most recently observed DPI.
+    pub(super) fn dpi(&self) -> DPI {
+        self.dpi.get()
+    }
+
+    /// Returns whether the window's scale factor has changed since this was
+    /// last called, and immediately clears the flag.
+    pub(super) fn clear_scale_factor_changed(&self) -> bool {
+        self.scale_factor_changed.swap(false, Ordering::SeqCst)
+    }
+
+    /// Sets the cursor shown while the pointer is over the window's client
+    /// area, re-applying it immediately if the pointer is there now.
+    /// Installs `handler` to intercept `umsg`, replacing any handler
+    /// previously installed for the same message. See
+    /// [`Window::on_message`](super::Window::on_message).
+    pub(super) fn set_message_handler(
+        &self,
+        umsg: u32,
+        handler: impl Fn(HWND, WPARAM, LPARAM) -> NextMessageAction + 'static,
+    ) {
+        self.message_handlers
+            .borrow_mut()
+            .insert(umsg, Rc::new(handler));
+    }
+
+    pub(super) fn set_cursor(&self, cursor: Cursor) -> Result<()> {
+        let handle = chk!(res; LoadCursorW(HINSTANCE::default(), cursor.idc()))?;
+        self.cursor.set(handle.0);
+        unsafe { SetCursor(handle) };
+        Ok(())
+    }
+
+    /// Shows or hides the cursor while it is over this window.
+    ///
+    /// `ShowCursor` maintains a process-wide display counter rather than a
+    /// boolean, so this only calls it on an actual state transition to avoid
+    /// the counter drifting out of sync with [`Self::cursor_visible`].
+    pub(super) fn set_cursor_visible(&self, visible: bool) {
+        if self.cursor_visible.replace(visible) != visible {
+            unsafe { ShowCursor(visible) };
+        }
+    }
+
+    /// Confines and captures the cursor to/from the window, or releases it.
+    ///
+    /// `SetCapture` routes all mouse input to this window even if the
+    /// pointer strays outside its client area (e.g. while dragging), and
+    /// `ClipCursor` additionally confines the pointer itself so it can't be
+    /// moved onto another monitor or window mid-drag. Both are released on
+    /// `WM_KILLFOCUS` and re-applied on `WM_SETFOCUS`, since neither is
+    /// tied to window focus on its own.
+    pub(super) fn set_cursor_grab(&self, grab: bool) -> Result<()> {
+        self.cursor_grabbed.set(grab);
+        if grab {
+            unsafe { SetCapture(self.hwnd()) };
+            self.clip_cursor_to_client()
+        } else {
+            let _ = unsafe { ReleaseCapture() };
+            chk!(bool; ClipCursor(None))?;
+            Ok(())
+        }
+    }
+
+    /// Resizes the window so its client area becomes `size`, keeping its
+    /// current position. Re-runs `AdjustWindowRectEx` against the window's
+    /// current style so the outer (chrome-inclusive) size stays correct.
+    pub(super) fn set_dimension(&self, size: Size2D<i32>) -> Result<()> {
+        let window_style = chk!(last_err; GetWindowLongPtrW(self.hwnd(), GWL_STYLE))?;
+        let ex_style = chk!(last_err; GetWindowLongPtrW(self.hwnd(), GWL_EXSTYLE))?;
+
+        let mut rect = Rect2D::from_size_with_origin(size, Point2D::default()).into();
+        chk!(bool; AdjustWindowRectEx(
+            &mut rect,
+            WINDOW_STYLE(window_style as u32),
+            false,
+            WINDOW_EX_STYLE(ex_style as u32)
+        ))?;
+
+        chk!(bool; SetWindowPos(
+            self.hwnd(),
+            None,
+            0,
+            0,
+            rect.right - rect.left,
+            rect.bottom - rect.top,
+            SWP_NOZORDER | SWP_NOMOVE,
+        ))?;
+
+        Ok(())
+    }
+
+    /// Switches the window between windowed and borderless-fullscreen mode.
+    ///
+    /// `Some(monitor)` saves the window's current placement and style, then
+    /// swaps `GWL_STYLE` from `WS_OVERLAPPEDWINDOW` to `WS_POPUP` and resizes
+    /// the window to cover `monitor`'s bounds. `None` restores the saved
+    /// placement and style, if the window was fullscreen.
+    ///
+    /// `SetWindowPos` dispatches `WM_SIZE` synchronously, so the render
+    /// target resize path (driven by [`Event::Resized`]) runs as part of
+    /// this call, the same way it does for an ordinary user resize.
+    pub(super) fn set_fullscreen(&self, monitor: Option<Monitor>) -> Result<()> {
+        match monitor {
+            Some(monitor) => {
+                let style = match *self.windowed_placement.borrow() {
+                    Some(ref placement) => placement.style,
+                    None => {
+                        let mut rect = RECT::default();
+                        chk!(bool; GetWindowRect(self.hwnd(), &mut rect))?;
+                        let style = chk!(last_err; GetWindowLongPtrW(self.hwnd(), GWL_STYLE))?;
+                        *self.windowed_placement.borrow_mut() =
+                            Some(WindowedPlacement { rect, style });
+                        style
+                    }
+                };
+
+                // Keep whichever visibility bit the windowed style had;
+                // `WS_POPUP` alone would otherwise clear it and hide the
+                // window.
+                let fullscreen_style = (style as u32 & WS_VISIBLE.0) | WS_POPUP.0;
+                chk!(last_err; SetWindowLongPtrW(self.hwnd(), GWL_STYLE, fullscreen_style as isize))?;
+
+                let bounds = monitor.bounds();
+                chk!(bool; SetWindowPos(
+                    self.hwnd(),
+                    None,
+                    bounds.left,
+                    bounds.top,
+                    bounds.width(),
+                    bounds.height(),
+                    SWP_NOZORDER | SWP_FRAMECHANGED,
+                ))?;
+            }
+            None => {
+                if let Some(placement) = self.windowed_placement.borrow_mut().take() {
+                    chk!(last_err; SetWindowLongPtrW(self.hwnd(), GWL_STYLE, placement.style))?;
+                    chk!(bool; SetWindowPos(
+                        self.hwnd(),
+                        None,
+                        placement.rect.left,
+                        placement.rect.top,
+                        placement.rect.right - placement.rect.left,
+                        placement.rect.bottom - placement.rect.top,
+                        SWP_NOZORDER | SWP_FRAMECHANGED,
+                    ))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Confines the cursor to the window's current client area via
+    /// `ClipCursor`.
+    fn clip_cursor_to_client(&self) -> Result<()> {
+        let mut rect = RECT::default();
+        chk!(bool; GetClientRect(self.hwnd(), &mut rect))?;
+
+        let mut top_left = POINT {
+            x: rect.left,
+            y: rect.top,
+        };
+        let mut bottom_right = POINT {
+            x: rect.right,
+            y: rect.bottom,
+        };
+        unsafe {
+            ClientToScreen(self.hwnd(), &mut top_left);
+            ClientToScreen(self.hwnd(), &mut bottom_right);
+        }
+
+        let screen_rect = RECT {
+            left: top_left.x,
+            top: top_left.y,
+            right: bottom_right.x,
+            bottom: bottom_right.y,
+        };
+        chk!(bool; ClipCursor(Some(&screen_rect)))?;
+        Ok(())
+    }
+
+    /// Applies `theme` to the window's title bar via
+    /// `DWMWA_USE_IMMERSIVE_DARK_MODE`.
+    fn apply_theme(&self, hwnd: HWND, theme: Theme) {
+        let use_dark_mode = ::windows::Win32::Foundation::BOOL::from(theme == Theme::DarkMode);
+        let result = unsafe {
+            DwmSetWindowAttribute(
+                hwnd,
+                DWMWA_USE_IMMERSIVE_DARK_MODE,
+                &use_dark_mode as *const _ as *const _,
+                ::std::mem::size_of_val(&use_dark_mode) as u32,
+            )
+        };
+        if let Err(e) = result {
+            ::tracing::error!(error = %e, "Failed to set immersive dark mode attribute");
+        }
+    }
+
     pub(super) fn destroy(&self) -> Result<()> {
+        if self.drop_target.borrow_mut().take().is_some() {
+            chk!(res; RevokeDragDrop(self.hwnd()))?;
+        }
+
+        if self.cursor_grabbed.get() {
+            let _ = unsafe { ReleaseCapture() };
+            let _ = chk!(bool; ClipCursor(None));
+        }
+
         chk!(bool; DestroyWindow(self.hwnd()))?;
         Ok(())
     }
@@ -154,18 +552,165 @@ impl WindowInner {
     fn handle_message(&self, umsg: u32, wparam: WPARAM, lparam: LPARAM) -> bool {
         ::tracing::trace!(msg = %crate::debug::msgs::DebugMsg::new(umsg, wparam, lparam));
 
+        let user_handler = self.message_handlers.borrow().get(&umsg).cloned();
+        if let Some(handler) = user_handler {
+            if handler(self.hwnd(), wparam, lparam) == NextMessageAction::DontForward {
+                return true;
+            }
+        }
+
+        if MouseAdapter::handles_msg(umsg, wparam, lparam) {
+            if let Some(event) = MouseAdapter::adapt(umsg, wparam, lparam) {
+                if let Some(mouse_event) = Self::mouse_input_event(&event) {
+                    self.events.borrow_mut().push_back(mouse_event);
+                }
+                self.mouse.write().process_evt(event);
+            }
+            return true;
+        }
+
         if KbdAdapter::handles_msg(umsg, wparam, lparam) {
-            if let Some(event) = KbdAdapter::adapt(umsg, wparam, lparam) {
+            if let Some(event) = KbdAdapter::adapt(self.hwnd(), umsg, wparam, lparam) {
+                if let Some(kb_event) = Self::keyboard_input_event(&event) {
+                    self.events.borrow_mut().push_back(kb_event);
+                }
                 self.keyboard.write().process_evt(event);
             }
             return true;
         }
 
+        if RawInputAdapter::handles_msg(umsg) {
+            if let Some(event) = RawInputAdapter::adapt(lparam) {
+                self.events.borrow_mut().push_back(match event {
+                    RawInputEvent::Keyboard(e) => Event::RawKeyInput(e),
+                    RawInputEvent::Mouse(e) => Event::RawMouseInput(e),
+                });
+            }
+            // Let `DefWindowProcW` also see this, per Microsoft's guidance,
+            // so the system can release the input packet's resources.
+            return false;
+        }
+
         match umsg {
             WM_CLOSE => {
                 self.close_request.store(true, Ordering::SeqCst);
+                self.events.borrow_mut().push_back(Event::CloseRequested);
                 true
             }
+            WM_ENDSESSION => {
+                if wparam.0 != 0 {
+                    let logoff = (lparam.0 as u32 & ENDSESSION_LOGOFF) != 0;
+                    self.events
+                        .borrow_mut()
+                        .push_back(Event::SessionEnding { logoff });
+                }
+                // Let `DefWindowProcW` also see this, per Microsoft's guidance.
+                false
+            }
+            WM_SIZE => {
+                let width = (lparam.0 & 0xFFFF) as i32;
+                let height = ((lparam.0 >> 16) & 0xFFFF) as i32;
+                let new_size = Size2D::new(width, height);
+
+                self.size.set(new_size);
+                self.events.borrow_mut().push_back(Event::Resized(new_size));
+
+                false
+            }
+            WM_PAINT => {
+                // Validate the update region so Windows doesn't keep
+                // re-posting `WM_PAINT` for the same area; actual painting is
+                // left to whoever observes `Event::RedrawRequested`.
+                let mut paint_struct = PAINTSTRUCT::default();
+                unsafe {
+                    BeginPaint(self.hwnd(), &mut paint_struct);
+                    EndPaint(self.hwnd(), &paint_struct);
+                }
+
+                self.redraw_requested.set(true);
+                self.events.borrow_mut().push_back(Event::RedrawRequested);
+
+                true
+            }
+            WM_SETTINGCHANGE => {
+                let is_color_set_change = (lparam.0 != 0)
+                    && unsafe { U16CStr::from_ptr_str(lparam.0 as *const u16) }
+                        .to_string_lossy()
+                        == "ImmersiveColorSet";
+
+                if is_color_set_change {
+                    let theme = Theme::detect();
+                    debug!(?theme, "System theme changed");
+                    self.theme.set(theme);
+                    self.theme_changed.set(true);
+                    self.apply_theme(self.hwnd(), theme);
+                }
+
+                // Still forward to the default procedure, since other parts of
+                // the system may also be observing this message.
+                false
+            }
+            WM_DPICHANGED => {
+                // The new DPI is packed into both halves of `wparam`; either
+                // works since X and Y DPI are always equal on Windows.
+                let dpi = DPI::from((wparam.0 & 0xFFFF) as f32);
+                debug!(%dpi, "Window DPI changed");
+                self.dpi.set(dpi);
+                self.scale_factor_changed.set(true);
+
+                // `lparam` points to a RECT with the suggested window
+                // position/size at the new DPI; move/resize in place so the
+                // window stays the same logical size on the new monitor.
+                // `SetWindowPos` dispatches `WM_SIZE` synchronously, so
+                // `self.size` already reflects the new client area by the
+                // time it returns.
+                if lparam.0 != 0 {
+                    let suggested = unsafe { *(lparam.0 as *const RECT) };
+                    let _ = chk!(bool; SetWindowPos(
+                        self.hwnd(),
+                        None,
+                        suggested.left,
+                        suggested.top,
+                        suggested.right - suggested.left,
+                        suggested.bottom - suggested.top,
+                        SWP_NOZORDER | SWP_NOACTIVATE,
+                    ));
+                }
+
+                self.events.borrow_mut().push_back(Event::DpiChanged {
+                    new_dpi: dpi,
+                    new_size: self.size.get(),
+                });
+
+                true
+            }
+            WM_SETCURSOR => {
+                // Only override the cursor while the pointer is over the
+                // client area; elsewhere (e.g. resize borders) Windows knows
+                // better than we do.
+                if (lparam.0 & 0xFFFF) as u32 == HTCLIENT.0 as u32 {
+                    unsafe { SetCursor(HCURSOR(self.cursor.get())) };
+                    true
+                } else {
+                    false
+                }
+            }
+            WM_SETFOCUS => {
+                if self.cursor_grabbed.get() {
+                    unsafe { SetCapture(self.hwnd()) };
+                    let _ = self.clip_cursor_to_client();
+                }
+                self.events.borrow_mut().push_back(Event::Focused(true));
+                false
+            }
+            WM_KILLFOCUS => {
+                if self.cursor_grabbed.get() {
+                    let _ = unsafe { ReleaseCapture() };
+                    let _ = chk!(bool; ClipCursor(None));
+                }
+                self.events.borrow_mut().push_back(Event::Focused(false));
+                false
+            }
             WM_NCDESTROY => {
                 debug!(wnd_title = %self.title, "Destroying window inner");
 
@@ -175,9 +720,17 @@ impl WindowInner {
                     .unwrap() as *const Self;
                 let _ = unsafe { Rc::from_raw(self_) };
 
+                crate::signals::unregister(self.hwnd());
+
                 // Clear our window handle now that we're destroyed.
                 self.hwnd.set(0);
 
+                // If this was the last live window, end any running
+                // `Application::run` loop the same way it would end its own.
+                if super::application::window_destroyed() == 0 {
+                    unsafe { PostQuitMessage(0) };
+                }
+
                 // forward to default procedure too
                 false
             }
@@ -185,6 +738,46 @@ impl WindowInner {
         }
     }
 
+    /// Maps a [`RawKeyEvent`] to the corresponding [`Event`]. Returns `None`
+    /// for [`RawKeyEvent::Input`] and [`RawKeyEvent::Paste`], which carry
+    /// decoded text rather than a discrete key state change.
+    fn keyboard_input_event(event: &RawKeyEvent) -> Option<Event> {
+        match event.clone() {
+            RawKeyEvent::KeyDown { key_code, .. } => Some(Event::KeyboardInput {
+                key: key_code,
+                state: KeyState::Pressed,
+            }),
+            RawKeyEvent::KeyUp { key_code, .. } => Some(Event::KeyboardInput {
+                key: key_code,
+                state: KeyState::Released,
+            }),
+            RawKeyEvent::Input { .. } | RawKeyEvent::Paste { .. } => None,
+            RawKeyEvent::CompositionStart => Some(Event::CompositionStart),
+            RawKeyEvent::CompositionUpdate { text, cursor } => {
+                Some(Event::CompositionUpdate { text, cursor })
+            }
+            RawKeyEvent::CompositionEnd { text } => Some(Event::CompositionEnd { text }),
+        }
+    }
+
+    /// Maps a [`RawMouseEvent`] to the corresponding [`Event`].
+    fn mouse_input_event(event: &RawMouseEvent) -> Option<Event> {
+        match *event {
+            RawMouseEvent::Moved { position } => Some(Event::CursorMoved(position)),
+            RawMouseEvent::ButtonDown { button, .. } => Some(Event::MouseInput {
+                button,
+                state: KeyState::Pressed,
+            }),
+            RawMouseEvent::ButtonUp { button, .. } => Some(Event::MouseInput {
+                button,
+                state: KeyState::Released,
+            }),
+            RawMouseEvent::Wheel { delta, horizontal } => {
+                Some(Event::MouseWheel { delta, horizontal })
+            }
+        }
+    }
+
     /// C-function Win32 window procedure performs one-time setup of the
     /// structures on the Win32 side to associate our Rust object with the Win32
     /// object.
@@ -208,6 +801,7 @@ impl WindowInner {
             // our message loop is single threaded so no other window activity
             // could be happening.
             unsafe { (*self_).hwnd.set(hwnd.0) };
+            crate::signals::register(hwnd);
 
             chk!(last_err; SetWindowLongPtrW(hwnd, GWLP_USERDATA, self_ as _)).unwrap();
             chk!(last_err; SetWindowLongPtrW(hwnd, GWLP_WNDPROC, (Self::wnd_proc_thunk as usize) as isize))
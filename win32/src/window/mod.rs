@@ -117,16 +117,28 @@
 //!                      └──────────────────────────────────────────────┘
 //! ```
 
+mod application;
 pub(crate) mod class;
+mod cursor;
 mod dpi;
+mod drop_target;
+mod event;
 pub(crate) mod inner;
+mod monitor;
 mod wnd;
 
+pub use application::*;
 use class::*;
+pub use cursor::*;
 pub use dpi::*;
+use drop_target::*;
+pub use event::*;
 use inner::*;
+pub use monitor::*;
 pub use wnd::*;
 
+use ::windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+
 /// The system theme, either light or dark.
 ///
 /// Windows supports Light and Dark themes as a personalization option in
@@ -146,3 +158,39 @@ pub enum Theme {
     /// light backgrounds.
     LightMode,
 }
+
+impl Theme {
+    /// Detects the current system theme by reading `AppsUseLightTheme` from
+    /// `HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize`.
+    ///
+    /// Falls back to [`Theme::LightMode`] if the value cannot be read, which
+    /// matches Windows' own default when personalization hasn't been
+    /// configured.
+    pub fn detect() -> Self {
+        let subkey = ::widestring::U16CString::from_str(
+            "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+        )
+        .unwrap();
+        let value_name = ::widestring::U16CString::from_str("AppsUseLightTheme").unwrap();
+
+        let mut data: u32 = 0;
+        let mut data_len = ::std::mem::size_of::<u32>() as u32;
+
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                ::windows::core::PCWSTR::from_raw(subkey.as_ptr()),
+                ::windows::core::PCWSTR::from_raw(value_name.as_ptr()),
+                RRF_RT_REG_DWORD,
+                None,
+                Some(&mut data as *mut _ as *mut _),
+                Some(&mut data_len),
+            )
+        };
+
+        match result {
+            Ok(()) if data == 0 => Self::DarkMode,
+            _ => Self::LightMode,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Monitor enumeration and per-monitor metadata.
+
+use crate::{errors::Result, invoke::chk, window::DPI};
+
+use ::widestring::U16CStr;
+use ::win_geom::d2::{Rect2D, Size2D};
+use ::windows::Win32::{
+    Foundation::{BOOL, HWND, LPARAM, POINT, RECT},
+    Graphics::Gdi::{
+        EnumDisplayMonitors, GetMonitorInfoW, MonitorFromPoint, MonitorFromWindow, HDC, HMONITOR,
+        MONITORINFOEXW, MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTOPRIMARY, MONITORINFOF_PRIMARY,
+    },
+    UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
+};
+
+/// A single display monitor attached to the system.
+#[derive(Clone, Debug)]
+pub struct Monitor {
+    /// The monitor's bounds in virtual-screen coordinates, covering its
+    /// entire display area.
+    bounds: Rect2D<i32>,
+    /// The monitor's work area in virtual-screen coordinates, i.e. its
+    /// bounds excluding the taskbar and other docked UI.
+    work_area: Rect2D<i32>,
+    /// The device name Windows uses to identify this monitor (e.g.
+    /// `\\.\DISPLAY1`).
+    device_name: String,
+    /// The monitor's DPI, used to derive its scale factor.
+    dpi: DPI,
+    /// Whether this is the system's primary monitor.
+    primary: bool,
+}
+
+impl Monitor {
+    /// The monitor's bounds in virtual-screen coordinates, covering its
+    /// entire display area.
+    pub const fn bounds(&self) -> Rect2D<i32> {
+        self.bounds
+    }
+
+    /// The monitor's work area in virtual-screen coordinates, i.e. its
+    /// bounds excluding the taskbar and other docked UI.
+    pub const fn work_area(&self) -> Rect2D<i32> {
+        self.work_area
+    }
+
+    /// The monitor's display resolution, i.e. the size of [`Self::bounds`].
+    pub fn resolution(&self) -> Size2D<i32> {
+        Size2D::new(self.bounds.width(), self.bounds.height())
+    }
+
+    /// The device name Windows uses to identify this monitor (e.g.
+    /// `\\.\DISPLAY1`).
+    pub fn device_name(&self) -> &str {
+        &self.device_name
+    }
+
+    /// The monitor's DPI, used to derive [`Self::scale_factor`].
+    pub const fn dpi(&self) -> DPI {
+        self.dpi
+    }
+
+    /// The monitor's scale factor, e.g. `1.5` for 144 dpi (150%).
+    pub fn scale_factor(&self) -> f32 {
+        f32::from(self.dpi) / 96.0
+    }
+
+    /// Whether this is the system's primary monitor.
+    pub const fn primary(&self) -> bool {
+        self.primary
+    }
+
+    /// Enumerates every monitor currently attached to the system.
+    pub fn all() -> Result<Vec<Self>> {
+        let mut monitors: Vec<Self> = Vec::new();
+
+        unsafe {
+            EnumDisplayMonitors(
+                HDC::default(),
+                None,
+                Some(Self::enum_proc),
+                LPARAM(&mut monitors as *mut Vec<Self> as isize),
+            );
+        }
+
+        Ok(monitors)
+    }
+
+    /// Returns the monitor that the given window is currently on, or the
+    /// nearest monitor if the window is entirely off-screen.
+    pub fn containing(hwnd: HWND) -> Result<Self> {
+        let hmonitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+        Self::from_hmonitor(hmonitor)
+    }
+
+    /// Returns the system's primary monitor, i.e. the one containing the
+    /// taskbar and the origin of virtual-screen coordinates.
+    pub fn primary() -> Result<Self> {
+        let hmonitor = unsafe { MonitorFromPoint(POINT::default(), MONITOR_DEFAULTTOPRIMARY) };
+        Self::from_hmonitor(hmonitor)
+    }
+
+    fn from_hmonitor(hmonitor: HMONITOR) -> Result<Self> {
+        let mut info = MONITORINFOEXW::default();
+        info.monitorInfo.cbSize = ::std::mem::size_of::<MONITORINFOEXW>() as u32;
+
+        chk!(bool; GetMonitorInfoW(hmonitor, &mut info as *mut MONITORINFOEXW as *mut _))?;
+
+        let device_name =
+            unsafe { U16CStr::from_ptr_str(info.szDevice.as_ptr()) }.to_string_lossy();
+
+        let (mut dpi_x, mut dpi_y) = (0u32, 0u32);
+        // Fall back to 96 dpi (100%) if per-monitor DPI can't be read.
+        let dpi = match unsafe { GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) }
+        {
+            Ok(()) => DPI::from(dpi_x as f32),
+            Err(_) => DPI::from(96.0),
+        };
+
+        Ok(Self {
+            bounds: to_rect(info.monitorInfo.rcMonitor),
+            work_area: to_rect(info.monitorInfo.rcWork),
+            device_name,
+            dpi,
+            primary: (info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY) != 0,
+        })
+    }
+
+    extern "system" fn enum_proc(
+        hmonitor: HMONITOR,
+        _hdc: HDC,
+        _rect: *mut RECT,
+        lparam: LPARAM,
+    ) -> BOOL {
+        let monitors = unsafe { &mut *(lparam.0 as *mut Vec<Self>) };
+
+        if let Ok(monitor) = Self::from_hmonitor(hmonitor) {
+            monitors.push(monitor);
+        }
+
+        BOOL(1)
+    }
+}
+
+fn to_rect(rect: RECT) -> Rect2D<i32> {
+    Rect2D::new(rect.left, rect.top, rect.right, rect.bottom)
+}
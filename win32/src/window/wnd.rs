@@ -2,21 +2,111 @@
 
 use crate::{
     errors::*,
-    input::keyboard::Keyboard,
+    input::{keyboard::Keyboard, mouse::Mouse},
     invoke::chk,
     types::*,
-    window::{Theme, WindowInner, DPI},
+    window::{Cursor, Event, Monitor, Theme, WindowInner, DPI},
 };
 
 use ::std::{ops::DerefMut, rc::Rc};
 use ::tracing::{debug, error};
 use ::widestring::U16CString;
-use ::win_geom::d2::Size2D;
+use ::win_geom::d2::{Point2D, Size2D};
 use ::windows::{
     core::PCWSTR,
-    Win32::{Foundation::HWND, UI::WindowsAndMessaging::SetWindowTextW},
+    Win32::{
+        Foundation::{HWND, LPARAM, WPARAM},
+        UI::WindowsAndMessaging::SetWindowTextW,
+    },
 };
 
+/// The outcome of a user-installed [`Window::on_message`] handler: whether
+/// the message should still be forwarded on to this crate's built-in
+/// handling and, ultimately, `DefWindowProcW`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NextMessageAction {
+    /// Let the message continue to this crate's built-in handling (and, if
+    /// unhandled there too, `DefWindowProcW`).
+    Forward,
+    /// Treat the message as fully handled; skip both this crate's built-in
+    /// handling and `DefWindowProcW`.
+    DontForward,
+}
+
+/// Extended creation options for a [`Window`].
+///
+/// Defaults to a plain, opaque top-level window, placed wherever Windows
+/// chooses, using the default system arrow cursor. Use
+/// [`WindowStyle::composition`] to opt into the extended window style
+/// (`WS_EX_NOREDIRECTIONBITMAP`) required for a window to be composited
+/// transparently via DirectComposition, [`Self::on_monitor`] /
+/// [`Self::at_position`] to control initial placement, and
+/// [`Self::with_cursor`] to register the window class with a custom cursor
+/// resource instead of the arrow.
+#[derive(Clone, Debug, Default)]
+pub struct WindowStyle {
+    /// Whether the window should be created without a redirection bitmap
+    /// (`WS_EX_NOREDIRECTIONBITMAP`), which is required for windows that will
+    /// be drawn to with a DirectComposition swap chain.
+    pub(super) composition: bool,
+    /// The monitor the window should be centered on, if any. Ignored if
+    /// [`Self::position`] is also set.
+    pub(super) monitor: Option<Monitor>,
+    /// The exact virtual-screen position the window's top-left corner should
+    /// be created at, if any. Takes priority over [`Self::monitor`].
+    pub(super) position: Option<Point2D<i32>>,
+    /// A custom cursor resource to register the window class with, in place
+    /// of the default `IDC_ARROW`. Distinct windows of differing
+    /// `cursor_id`s are registered under distinct Win32 window classes, the
+    /// same way `icon_id` already works.
+    pub(super) cursor_id: Option<ResourceId>,
+    /// Whether the window should omit `WS_THICKFRAME`/`WS_MAXIMIZEBOX`, so it
+    /// cannot be resized or maximized by the user. Windows are resizable by
+    /// default.
+    pub(super) fixed_size: bool,
+}
+
+impl WindowStyle {
+    /// Creates a [`WindowStyle`] suitable for a window which will be drawn to
+    /// via a DirectComposition swap chain render target.
+    pub fn composition() -> Self {
+        Self {
+            composition: true,
+            ..Default::default()
+        }
+    }
+
+    /// Opens the window centered on the given monitor.
+    pub fn on_monitor(mut self, monitor: Monitor) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Opens the window with its top-left corner at the given virtual-screen
+    /// position, overriding [`Self::on_monitor`] if both are set.
+    pub fn at_position(mut self, position: Point2D<i32>) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Registers the window class with `cursor_id` as its default cursor
+    /// resource, instead of the system arrow. To change the cursor at
+    /// runtime without a custom resource, use
+    /// [`Window::set_cursor`](super::Window::set_cursor) instead.
+    pub fn with_cursor(mut self, cursor_id: ResourceId) -> Self {
+        self.cursor_id = Some(cursor_id);
+        self
+    }
+
+    /// Prevents the user from resizing or maximizing the window. Has no
+    /// effect on [`Window::set_dimension`], which can still resize the
+    /// window programmatically.
+    pub fn fixed_size(mut self) -> Self {
+        self.fixed_size = true;
+        self
+    }
+}
+
 /// A rusty wrapper around Win32 window class.
 ///
 /// A [Window] is `!Sync + !Send` as Win32 windows must be controlled by the
@@ -34,9 +124,25 @@ impl Window {
         title: &str,
         icon_id: Option<ResourceId>,
         theme: Theme,
+    ) -> Result<Self> {
+        Self::with_style(size, title, icon_id, theme, WindowStyle::default())
+    }
+
+    /// Construct and display a new window with a non-default [`WindowStyle`].
+    ///
+    /// Use [`WindowStyle::composition`] when the window will be drawn to via
+    /// a DirectComposition-backed render target (see
+    /// [`::d2d::D2DFactory::make_composition_render_target`]) and needs
+    /// per-pixel transparency.
+    pub fn with_style(
+        size: Size2D<i32>,
+        title: &str,
+        icon_id: Option<ResourceId>,
+        theme: Theme,
+        style: WindowStyle,
     ) -> Result<Self> {
         debug!(wnd_title = %title, "Creating window");
-        WindowInner::new(size, title, icon_id, theme).map(|inner| Self { inner })
+        WindowInner::new(size, title, icon_id, theme, style).map(|inner| Self { inner })
     }
 
     /// The size of the client area of our Win32 window. The window chrome
@@ -63,8 +169,35 @@ impl Window {
     }
 
     /// Returns the dots per inch (dpi) value for the window.
+    ///
+    /// This is the value most recently observed, either when the window was
+    /// created or from the last `WM_DPICHANGED` message, so it stays correct
+    /// as the window is dragged between monitors with different scale
+    /// factors.
     pub fn dpi(&self) -> DPI {
-        DPI::detect(self.hwnd())
+        self.inner.dpi()
+    }
+
+    /// Returns the ratio between this window's current DPI and the Windows
+    /// default of 96, e.g. `1.5` at 150% scaling. Shorthand for
+    /// `self.dpi().scale_factor()`, for callers converting between logical
+    /// and physical coordinates without caring about the raw DPI value.
+    pub fn scale_factor(&self) -> f32 {
+        self.dpi().scale_factor()
+    }
+
+    /// Returns the monitor the window is currently displayed on (i.e. the
+    /// monitor with the greatest overlap with the window's bounds).
+    pub fn current_monitor(&self) -> Result<Monitor> {
+        Monitor::containing(self.hwnd())
+    }
+
+    /// Returns whether the window's scale factor has changed (e.g. because
+    /// it was dragged to a monitor with a different DPI) since this was last
+    /// called, and immediately clears the flag. [`Self::dpi`] already
+    /// reflects the new value by the time this returns `true`.
+    pub fn clear_scale_factor_changed(&mut self) -> bool {
+        self.inner.clear_scale_factor_changed()
     }
 
     /// Returns whether the window has requested to close, and immediately
@@ -82,17 +215,119 @@ impl Window {
         self.inner.clear_redraw_request()
     }
 
+    /// Returns whether the system theme has changed since this was last
+    /// called, and immediately clears the flag. The window's title bar has
+    /// already been re-themed by the time this returns `true`; callers should
+    /// use it to know when to rebuild their own Direct2D content to match.
+    pub fn clear_theme_changed(&mut self) -> bool {
+        self.inner.clear_theme_changed()
+    }
+
     /// Reads the keyboard state. A read lock is held during this process, so
     /// the reference must be dropped for further keyboard input to be handled.
     pub fn keyboard(&self) -> impl DerefMut<Target = Keyboard> + '_ {
         self.inner.keyboard()
     }
 
+    /// Reads the mouse state. A read lock is held during this process, so
+    /// the reference must be dropped for further mouse input to be handled.
+    pub fn mouse(&self) -> impl DerefMut<Target = Mouse> + '_ {
+        self.inner.mouse()
+    }
+
+    /// Opts this window into file drag-and-drop notifications, delivered as
+    /// [`Event::HoveredFile`]/[`Event::HoveredFileCancelled`]/
+    /// [`Event::DroppedFile`].
+    ///
+    /// The calling thread must already hold a
+    /// [`ComLibraryHandle`](crate::proc::ComLibraryHandle).
+    pub fn enable_file_drop(&self) -> Result<()> {
+        self.inner.enable_file_drop(Rc::downgrade(&self.inner))
+    }
+
+    /// Opts this window into high-frequency, unbuffered keyboard and mouse
+    /// input, delivered as
+    /// [`Event::RawKeyInput`]/[`Event::RawMouseInput`] alongside the
+    /// ordinary [`Event::KeyboardInput`]/[`Event::MouseInput`]/
+    /// [`Event::CursorMoved`] path. Suitable for FPS-style camera control,
+    /// which needs the device's raw, unaccelerated motion stream rather
+    /// than the window-message API's coalesced, cursor-accelerated one.
+    pub fn enable_raw_input(&self) -> Result<()> {
+        self.inner.enable_raw_input()
+    }
+
+    /// Drains all [`Event`]s translated from Win32 messages since this was
+    /// last called, in the order they occurred.
+    ///
+    /// Most applications should prefer driving their message loop through
+    /// [`crate::event_loop::EventLoop`], which calls this automatically;
+    /// this is exposed directly for callers that pump their own message
+    /// loop (as [`clear_close_request`](Self::clear_close_request) and its
+    /// siblings already are).
+    pub fn drain_events(&self) -> Vec<Event> {
+        self.inner.drain_events()
+    }
+
     /// Set the window title.
     pub fn set_title(&self, title: &str) -> Result<()> {
         let string = U16CString::from_str_truncate(title);
         chk!(bool; SetWindowTextW(self.hwnd(), PCWSTR::from_raw(string.as_ptr()))).map(|_| ())
     }
+
+    /// Sets the cursor shown while the pointer is over the window's client
+    /// area, re-applying it immediately if the pointer is there now.
+    ///
+    /// For a custom cursor resource baked into the window class itself (so
+    /// it applies before any of our code runs), use
+    /// [`WindowStyle::with_cursor`] instead.
+    pub fn set_cursor(&self, cursor: Cursor) -> Result<()> {
+        self.inner.set_cursor(cursor)
+    }
+
+    /// Shows or hides the cursor while it is over this window.
+    pub fn set_cursor_visible(&self, visible: bool) {
+        self.inner.set_cursor_visible(visible)
+    }
+
+    /// Captures mouse input and confines the cursor to the window's client
+    /// area (`true`), or releases both (`false`).
+    pub fn set_cursor_grab(&self, grab: bool) -> Result<()> {
+        self.inner.set_cursor_grab(grab)
+    }
+
+    /// Installs `handler` to intercept `umsg` before this crate's own
+    /// handling of it, e.g. for hit-testing, custom chrome, tray icon
+    /// messages, or timers. Replaces any handler previously installed for
+    /// the same `umsg`.
+    ///
+    /// Return [`NextMessageAction::Forward`] to let the message continue to
+    /// this crate's built-in handling (and then `DefWindowProcW`) as usual,
+    /// or [`NextMessageAction::DontForward`] to treat it as fully handled.
+    pub fn on_message(
+        &self,
+        umsg: u32,
+        handler: impl Fn(HWND, WPARAM, LPARAM) -> NextMessageAction + 'static,
+    ) {
+        self.inner.set_message_handler(umsg, handler);
+    }
+
+    /// Resizes the window so its client area becomes `size`, keeping its
+    /// current position. [`Self::size`] (and any observer of
+    /// [`Event::Resized`]) reflects the new value once `WM_SIZE` is
+    /// dispatched, which `SetWindowPos` does synchronously.
+    pub fn set_dimension(&self, size: Size2D<i32>) -> Result<()> {
+        self.inner.set_dimension(size)
+    }
+
+    /// Switches between windowed and borderless-fullscreen mode.
+    ///
+    /// `Some(monitor)` takes the window fullscreen on `monitor`, covering its
+    /// entire bounds; `None` restores the window's placement from just
+    /// before it last went fullscreen. A no-op if the window is already
+    /// windowed.
+    pub fn set_fullscreen(&self, monitor: Option<Monitor>) -> Result<()> {
+        self.inner.set_fullscreen(monitor)
+    }
 }
 
 impl Drop for Window {